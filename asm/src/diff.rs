@@ -0,0 +1,1242 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::Function;
+
+/// the instruction sets whose registers and operands [`Diff`] understands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Arch {
+    X86_64,
+    Aarch64,
+    Riscv64,
+    /// anything else, wasm32 included, which is compared line by line
+    Other,
+}
+
+impl Arch {
+    /// the instruction set of a target triple
+    pub fn from_triple(triple: &str) -> Arch {
+        match triple.split('-').next().unwrap_or_default() {
+            "x86_64" => Arch::X86_64,
+            "aarch64" | "arm64" => Arch::Aarch64,
+            arch if arch.starts_with("riscv64") => Arch::Riscv64,
+            _ => Arch::Other,
+        }
+    }
+}
+
+/// how close two functions are, from closest to farthest
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Similarity {
+    /// the same normalized assembly
+    Identical,
+    /// the same instructions in the same order, but in other registers
+    Renamed,
+    /// the same instructions in another order, none of them moved past one it
+    /// depends on
+    Reordered,
+    Different,
+}
+
+/// one line of a [`Diff`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Line {
+    /// in both functions, in the same place
+    Same(String),
+    /// only in the left function
+    Left(String),
+    /// only in the right function
+    Right(String),
+    /// where the left function has an instruction that the right one has
+    /// somewhere else
+    MovedFrom(String),
+    /// where the right function has it
+    MovedTo(String),
+}
+
+/// two functions' assembly, compared instruction by instruction
+///
+/// Each instruction is labelled with what it computes rather than with the
+/// registers it computes it in: the label of `add w9, w8, w9` is made of
+/// `add`, the operand widths, and the labels of whatever last wrote `w8` and
+/// `w9`, in either order since `add` is commutative. Loads and stores are
+/// also labelled with the earlier stores they may overlap, and nothing is
+/// moved across a label, branch or call. Instructions with the same label
+/// compute the same thing, so if every instruction in one function has a
+/// partner in the other, the two can differ only in register allocation and
+/// in the order of independent instructions.
+///
+/// The left function is shown in the right one's registers wherever an
+/// instruction has a partner, so that only the real differences remain.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Diff {
+    pub left: String,
+    pub right: String,
+    pub similarity: Similarity,
+    /// `(left, right)` names of registers that hold the same values, in order
+    /// of first appearance in the left function
+    ///
+    /// Only a consistent renaming is listed: a left register whose values the
+    /// right function keeps in more than one register, or in a register that
+    /// also holds another left register's values, is left out, though its
+    /// lines are still shown in the right function's registers.
+    pub renamed: Vec<(String, String)>,
+    pub lines: Vec<Line>,
+}
+
+impl Diff {
+    /// compare two functions compiled for `arch`
+    pub fn new(arch: Arch, left: &Function, right: &Function) -> Diff {
+        let left_code = Code::new(arch, left);
+        let right_code = Code::new(arch, right);
+
+        let mut partners: HashMap<u64, VecDeque<usize>> = HashMap::new();
+        for (j, label) in right_code.labels.iter().enumerate() {
+            partners.entry(*label).or_default().push_back(j);
+        }
+        let matches: Vec<Option<usize>> = left_code
+            .labels
+            .iter()
+            .map(|label| partners.get_mut(label).and_then(VecDeque::pop_front))
+            .collect();
+
+        // each value the left function computes, in the register the right
+        // function computes it in
+        let mut registers = HashMap::new();
+        for (i, j) in matches.iter().enumerate() {
+            let Some(j) = *j else { continue };
+            let (ours, theirs) = (&left_code.instructions[i], &right_code.instructions[j]);
+            for (t, register) in ours.registers.iter().enumerate() {
+                if register.writes {
+                    registers.insert((i, t), theirs.registers[t].family.clone());
+                }
+            }
+        }
+        // every left register shown in a right one, and the families of both
+        let mut shown = Vec::new();
+        let left_lines: Vec<String> = left_code
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(i, instruction)| {
+                instruction.render(|t| {
+                    let register = &instruction.registers[t];
+                    let original = &instruction.text[register.start..register.end];
+                    let Some(family) =
+                        left_code.values[i][t].and_then(|value| registers.get(&value))
+                    else {
+                        return original.to_string();
+                    };
+                    let name = arch.name(family, register.view);
+                    shown.push((
+                        (original.to_string(), name.clone()),
+                        (&register.family, family),
+                    ));
+                    name
+                })
+            })
+            .collect();
+        // a left register is renamed only if all its values are in the same
+        // right register, and that one holds no other left register's values
+        let mut targets: HashMap<&String, BTreeSet<&String>> = HashMap::new();
+        let mut sources: HashMap<&String, BTreeSet<&String>> = HashMap::new();
+        for (_, (ours, theirs)) in &shown {
+            targets.entry(ours).or_default().insert(theirs);
+            sources.entry(theirs).or_default().insert(ours);
+        }
+        let mut renamed = Vec::new();
+        for (pair, (ours, theirs)) in &shown {
+            if pair.0 != pair.1
+                && targets[ours].len() == 1
+                && sources[theirs].len() == 1
+                && !renamed.contains(pair)
+            {
+                renamed.push(pair.clone());
+            }
+        }
+        let right_lines: Vec<&str> = right_code
+            .instructions
+            .iter()
+            .map(|instruction| instruction.text.as_str())
+            .collect();
+
+        let all_matched = left_code.labels.len() == right_code.labels.len()
+            && matches.iter().all(Option::is_some);
+        let in_order = matches.windows(2).all(|pair| pair[0] < pair[1]);
+        let similarity = if left_code.text() == right_code.text() {
+            Similarity::Identical
+        } else if all_matched && in_order {
+            Similarity::Renamed
+        } else if all_matched {
+            Similarity::Reordered
+        } else {
+            Similarity::Different
+        };
+
+        let mut right_matched = vec![false; right_lines.len()];
+        for j in matches.iter().flatten() {
+            right_matched[*j] = true;
+        }
+        let same = |i: usize, j: usize| match matches[i] {
+            Some(partner) => partner == j,
+            None => !right_matched[j] && left_lines[i] == right_lines[j],
+        };
+        let lines = diff(left_lines.len(), right_lines.len(), same)
+            .into_iter()
+            .map(|step| match step {
+                Step::Both(j) => Line::Same(right_lines[j].to_string()),
+                Step::Left(i) if matches[i].is_some() => Line::MovedFrom(left_lines[i].clone()),
+                Step::Left(i) => Line::Left(left_lines[i].clone()),
+                Step::Right(j) if right_matched[j] => Line::MovedTo(right_lines[j].to_string()),
+                Step::Right(j) => Line::Right(right_lines[j].to_string()),
+            })
+            .collect();
+
+        Diff {
+            left: left.name.clone(),
+            right: right.name.clone(),
+            similarity,
+            renamed,
+            lines,
+        }
+    }
+}
+
+impl fmt::Display for Similarity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Similarity::Identical => "identical",
+            Similarity::Renamed => "the same instructions in other registers",
+            Similarity::Reordered => {
+                "the same instructions in another order, none moved past one it depends on"
+            }
+            Similarity::Different => "different",
+        })
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Same(line) => write!(f, " {line}"),
+            Line::Left(line) => write!(f, "-{line}"),
+            Line::Right(line) => write!(f, "+{line}"),
+            Line::MovedFrom(line) => write!(f, "<{line}"),
+            Line::MovedTo(line) => write!(f, ">{line}"),
+        }
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "--- {}", self.left)?;
+        writeln!(f, "+++ {}", self.right)?;
+        if !self.renamed.is_empty() {
+            let renamed: Vec<_> = self
+                .renamed
+                .iter()
+                .map(|(left, right)| format!("{left} -> {right}"))
+                .collect();
+            writeln!(f, "registers: {}", renamed.join(", "))?;
+        }
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        if self.similarity != Similarity::Different {
+            return writeln!(f, "{}", self.similarity);
+        }
+        let count = |side: fn(&Line) -> bool| self.lines.iter().filter(|line| side(line)).count();
+        writeln!(
+            f,
+            "different: {} only on the left, {} only on the right",
+            count(|line| matches!(line, Line::Left(_))),
+            count(|line| matches!(line, Line::Right(_))),
+        )
+    }
+}
+
+/// a function's instructions, each labelled with what it computes
+struct Code {
+    instructions: Vec<Instruction>,
+    labels: Vec<u64>,
+    /// for each register of each instruction, the instruction and register
+    /// that wrote the value it refers to, if that was in this function and
+    /// since the last barrier
+    values: Vec<Vec<Option<(usize, usize)>>>,
+}
+
+impl Code {
+    fn new(arch: Arch, function: &Function) -> Code {
+        // the normalized text has renumbered labels and demangled symbols;
+        // its first line is the function's own label
+        let instructions: Vec<Instruction> = function
+            .normalized()
+            .lines()
+            .skip(1)
+            .filter(|line| !line.starts_with('#'))
+            .map(|line| Instruction::parse(arch, line))
+            .collect();
+
+        let mut state = State::default();
+        let mut labels = Vec::new();
+        let mut values = Vec::new();
+        for (i, instruction) in instructions.iter().enumerate() {
+            let (label, refs) = match instruction.kind {
+                Kind::Compute => state.compute(i, instruction),
+                kind => state.barrier(arch, kind, instruction),
+            };
+            labels.push(label);
+            values.push(refs);
+        }
+        Code {
+            instructions,
+            labels,
+            values,
+        }
+    }
+
+    fn text(&self) -> Vec<&str> {
+        self.instructions
+            .iter()
+            .map(|instruction| instruction.text.as_str())
+            .collect()
+    }
+}
+
+/// the labels of the values in each register, as a function runs
+#[derive(Default)]
+struct State {
+    /// the label of the last barrier, or `0` at the start of the function
+    epoch: u64,
+    /// registers written since the last barrier: the label of the value, and
+    /// the instruction and register that wrote it
+    current: HashMap<String, (u64, (usize, usize))>,
+    /// stores since the last barrier, with where they store
+    stores: Vec<(u64, Place)>,
+}
+
+/// where a load or store goes, as far as can be told
+#[derive(Clone, Copy)]
+struct Place {
+    base: Option<u64>,
+    offset: Option<i64>,
+    size: Option<u64>,
+}
+
+const FLAGS: &str = "flags";
+
+impl State {
+    fn value(&self, family: &str) -> (u64, Option<(usize, usize)>) {
+        match self.current.get(family) {
+            Some((label, written)) => (*label, Some(*written)),
+            None => (hash(("in", self.epoch, family)), None),
+        }
+    }
+
+    fn compute(
+        &mut self,
+        i: usize,
+        instruction: &Instruction,
+    ) -> (u64, Vec<Option<(usize, usize)>>) {
+        let mut inputs: Vec<Option<u64>> = instruction
+            .registers
+            .iter()
+            .map(|register| register.reads.then(|| self.value(&register.family).0))
+            .collect();
+        if let Some((a, b)) = instruction.sources()
+            && inputs[b] < inputs[a]
+        {
+            inputs.swap(a, b);
+        }
+        let flags = instruction.reads_flags.then(|| self.value(FLAGS).0);
+        let place = instruction.access.as_ref().map(|access| Place {
+            base: access
+                .base
+                .map(|t| self.value(&instruction.registers[t].family).0),
+            offset: access.offset,
+            size: access.size,
+        });
+        let overlapping: Vec<u64> = match place {
+            Some(place) => {
+                let mut labels: Vec<u64> = self
+                    .stores
+                    .iter()
+                    .filter(|(_, stored)| stored.overlaps(&place))
+                    .map(|(label, _)| *label)
+                    .collect();
+                labels.sort_unstable();
+                labels
+            }
+            None => Vec::new(),
+        };
+        let label = hash((
+            &instruction.template,
+            &inputs,
+            flags,
+            overlapping,
+            self.epoch,
+        ));
+
+        let refs = instruction
+            .registers
+            .iter()
+            .enumerate()
+            .map(|(t, register)| {
+                if register.writes {
+                    Some((i, t))
+                } else if register.reads {
+                    self.value(&register.family).1
+                } else {
+                    None
+                }
+            })
+            .collect();
+        let copied = instruction
+            .copy
+            .then(|| inputs.iter().flatten().next().copied())
+            .flatten();
+        for (t, register) in instruction.registers.iter().enumerate() {
+            if register.writes {
+                let value = copied.unwrap_or_else(|| hash((label, t)));
+                self.current
+                    .insert(register.family.clone(), (value, (i, t)));
+            }
+        }
+        if instruction.writes_flags {
+            self.current
+                .insert(FLAGS.to_string(), (hash((label, FLAGS)), (i, usize::MAX)));
+        }
+        if let (Some(place), Some(access)) = (place, &instruction.access)
+            && access.stores
+        {
+            self.stores.push((label, place));
+        }
+        (label, refs)
+    }
+
+    /// whether the function returns its result through memory: on x86_64 the
+    /// caller passes the address in `rdi`, and gets it back in `rax`
+    fn returns_memory(&self, arch: Arch) -> bool {
+        arch == Arch::X86_64 && self.value("rax").0 == hash(("in", 0u64, "rdi"))
+    }
+
+    /// a label, branch, call or return: it may read any register or memory,
+    /// and everything after it depends on it
+    fn barrier(
+        &mut self,
+        arch: Arch,
+        kind: Kind,
+        instruction: &Instruction,
+    ) -> (u64, Vec<Option<(usize, usize)>>) {
+        let mut reads: BTreeSet<String> = match kind {
+            Kind::Call => arch.call_reads(),
+            Kind::Return => arch.return_reads(self.returns_memory(arch)),
+            _ => self.current.keys().cloned().collect(),
+        };
+        reads.extend(
+            instruction
+                .registers
+                .iter()
+                .map(|register| register.family.clone()),
+        );
+        let reads: Vec<(String, u64)> = reads
+            .into_iter()
+            .map(|family| {
+                let label = self.value(&family).0;
+                (family, label)
+            })
+            .collect();
+        let mut stores: Vec<u64> = self.stores.iter().map(|(label, _)| *label).collect();
+        stores.sort_unstable();
+        let label = hash((&instruction.text, reads, stores, self.epoch));
+
+        self.epoch = label;
+        self.current.clear();
+        self.stores.clear();
+        (label, vec![None; instruction.registers.len()])
+    }
+}
+
+impl Place {
+    /// `false` only if both are at known offsets from the same address and
+    /// their bytes do not overlap
+    fn overlaps(&self, other: &Place) -> bool {
+        match (self, other) {
+            (
+                Place {
+                    base: Some(base),
+                    offset: Some(offset),
+                    size: Some(size),
+                },
+                Place {
+                    base: Some(other_base),
+                    offset: Some(other_offset),
+                    size: Some(other_size),
+                },
+            ) if base == other_base => {
+                *offset < other_offset + *other_size as i64 && *other_offset < offset + *size as i64
+            }
+            _ => true,
+        }
+    }
+}
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// one line of a function, with the registers and memory it reads and writes
+struct Instruction {
+    /// the normalized line: a label, or a tab and the instruction
+    text: String,
+    /// `text` with each register replaced by its width, which is all two
+    /// instructions computing the same thing need to have in common
+    template: String,
+    registers: Vec<Register>,
+    kind: Kind,
+    reads_flags: bool,
+    writes_flags: bool,
+    access: Option<Access>,
+    /// `op d, a, b` is `op d, b, a`
+    commutative: bool,
+    /// copies one whole register to another, so the copy has the same label
+    copy: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    /// reads and writes only its registers, the flags and its memory operand
+    Compute,
+    Call,
+    Return,
+    /// a label, a branch, or an instruction that is not understood
+    Barrier,
+}
+
+/// a register named in an instruction
+struct Register {
+    /// the name's byte range in the instruction's text
+    start: usize,
+    end: usize,
+    /// the architectural register, e.g. `rax` for `eax` or `x8` for `w8`
+    family: String,
+    /// which part of it is named, e.g. `32` for `eax` or `w` for `w8`
+    view: &'static str,
+    operand: usize,
+    /// part of a memory operand's address
+    address: bool,
+    reads: bool,
+    writes: bool,
+}
+
+/// the memory operand of a load or store
+struct Access {
+    /// the index into `registers` of the base address
+    base: Option<usize>,
+    /// bytes from the base, where that is a number
+    offset: Option<i64>,
+    size: Option<u64>,
+    stores: bool,
+}
+
+impl Instruction {
+    fn parse(arch: Arch, line: &str) -> Instruction {
+        let mut instruction = Instruction {
+            text: line.to_string(),
+            template: line.to_string(),
+            registers: Vec::new(),
+            kind: Kind::Barrier,
+            reads_flags: false,
+            writes_flags: false,
+            access: None,
+            commutative: false,
+            copy: false,
+        };
+        let trimmed = line.trim();
+        if trimmed.ends_with(':') || arch == Arch::Other {
+            return instruction;
+        }
+        let (mnemonic, rest) = trimmed
+            .split_once(char::is_whitespace)
+            .unwrap_or((trimmed, ""));
+        let operands = operands(line, line.len() - rest.len());
+        for (operand, &(start, end)) in operands.iter().enumerate() {
+            instruction.scan(arch, operand, start, end);
+        }
+        let memory: Vec<bool> = operands
+            .iter()
+            .map(|&(start, end)| line[start..end].contains(['(', '[']))
+            .collect();
+        instruction.kind = match arch {
+            Arch::X86_64 => instruction.x86_64(mnemonic, &operands, &memory),
+            Arch::Aarch64 => instruction.aarch64(mnemonic, &operands, &memory),
+            Arch::Riscv64 => instruction.riscv64(mnemonic, &operands, &memory),
+            Arch::Other => Kind::Barrier,
+        };
+        if instruction.kind == Kind::Compute {
+            instruction.template =
+                instruction.render(|t| format!("{{{}}}", instruction.registers[t].view));
+        }
+        instruction
+    }
+
+    /// find the registers in the operand at `start..end`
+    fn scan(&mut self, arch: Arch, operand: usize, start: usize, end: usize) {
+        let text = &self.text[start..end];
+        let mut depth = 0;
+        let mut token_start = None;
+        for (offset, c) in text.char_indices().chain([(text.len(), ' ')]) {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                token_start.get_or_insert(offset);
+                continue;
+            }
+            if let Some(token_start) = token_start.take() {
+                let token = &text[token_start..offset];
+                let before = text[..token_start].chars().next_back();
+                let prefixed = match arch {
+                    Arch::X86_64 => before == Some('%'),
+                    _ => !matches!(before, Some('.' | '$' | ':' | '@' | '#' | '%')),
+                };
+                if let Some((family, view)) = prefixed.then(|| arch.register(token)).flatten() {
+                    self.registers.push(Register {
+                        start: start + token_start,
+                        end: start + offset,
+                        family,
+                        view,
+                        operand,
+                        address: depth > 0,
+                        reads: depth > 0,
+                        writes: false,
+                    });
+                }
+            }
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    /// mark the registers of `operand` that are not part of an address
+    fn set(&mut self, operand: usize, reads: bool, writes: bool) {
+        for register in &mut self.registers {
+            if register.operand == operand && !register.address {
+                register.reads |= reads;
+                register.writes |= writes;
+            }
+        }
+    }
+
+    /// the register that is the whole of `operand`
+    fn register(&self, operand: usize) -> Option<&Register> {
+        self.registers
+            .iter()
+            .find(|register| register.operand == operand && !register.address)
+    }
+
+    /// whether the instruction is just two whole registers of width `view`
+    fn copies(&self, view: &str) -> bool {
+        self.access.is_none()
+            && self.registers.len() == 2
+            && self
+                .registers
+                .iter()
+                .all(|register| register.view == view && !register.address)
+    }
+
+    /// the registers of the two operands a commutative instruction may swap
+    fn sources(&self) -> Option<(usize, usize)> {
+        let operand = |operand| {
+            self.registers
+                .iter()
+                .position(|register| register.operand == operand && !register.address)
+        };
+        let (a, b) = (operand(1)?, operand(2)?);
+        (self.commutative && self.registers[a].view == self.registers[b].view).then_some((a, b))
+    }
+
+    /// the text with the `t`th register replaced by `name(t)`
+    fn render(&self, mut name: impl FnMut(usize) -> String) -> String {
+        let mut text = String::with_capacity(self.text.len());
+        let mut end = 0;
+        for (t, register) in self.registers.iter().enumerate() {
+            text.push_str(&self.text[end..register.start]);
+            text.push_str(&name(t));
+            end = register.end;
+        }
+        text.push_str(&self.text[end..]);
+        text
+    }
+
+    /// AT&T syntax: the destination is the last operand
+    fn x86_64(&mut self, mnemonic: &str, operands: &[(usize, usize)], memory: &[bool]) -> Kind {
+        const ARITHMETIC: &[&str] = &[
+            "add", "sub", "and", "or", "xor", "shl", "shr", "sar", "rol", "ror", "imul", "adc",
+            "sbb", "inc", "dec", "neg", "not", "pxor", "xorps", "xorpd", "andps", "andpd", "orps",
+            "orpd",
+        ];
+        if mnemonic.starts_with("call") {
+            return Kind::Call;
+        }
+        if mnemonic.starts_with("ret") {
+            return Kind::Return;
+        }
+        let n = operands.len();
+        if n == 0 {
+            return Kind::Barrier;
+        }
+        let destination = n - 1;
+        let narrow = self
+            .register(destination)
+            .is_some_and(|register| matches!(register.view, "8" | "16"));
+        let arithmetic = ARITHMETIC.iter().find(|op| {
+            mnemonic
+                .strip_prefix(**op)
+                .is_some_and(|suffix| matches!(suffix, "" | "b" | "w" | "l" | "q"))
+        });
+
+        if mnemonic.starts_with("mov") || mnemonic.starts_with("lea") {
+            for operand in 0..destination {
+                self.set(operand, true, false);
+            }
+            self.set(destination, narrow, true);
+            self.copy = mnemonic == "movq" && self.copies("64");
+        } else if mnemonic.starts_with("cmov") {
+            self.reads_flags = true;
+            for operand in 0..n {
+                self.set(operand, true, operand == destination);
+            }
+        } else if mnemonic.starts_with("set") && n == 1 {
+            self.reads_flags = true;
+            self.set(destination, narrow, true);
+        } else if (mnemonic.starts_with("cmp") || mnemonic.starts_with("test"))
+            && n == 2
+            && !mnemonic.starts_with("cmpxchg")
+        {
+            self.writes_flags = true;
+            self.set(0, true, false);
+            self.set(1, true, false);
+        } else if let Some(&op) = arithmetic {
+            if op == "imul" && n == 1 {
+                return Kind::Barrier;
+            }
+            // `xorl %eax, %eax` only writes `eax`
+            let zeroing = matches!(op, "xor" | "sub" | "pxor" | "xorps" | "xorpd")
+                && n == 2
+                && !memory[0]
+                && !memory[1]
+                && self
+                    .register(0)
+                    .zip(self.register(1))
+                    .is_some_and(|(source, destination)| source.family == destination.family);
+            let overwrites = zeroing || (op == "imul" && n == 3);
+            for operand in 0..destination {
+                self.set(operand, !zeroing, false);
+            }
+            self.set(destination, !overwrites, true);
+            self.reads_flags = matches!(op, "adc" | "sbb");
+            self.writes_flags = !matches!(op, "not" | "pxor" | "xorps" | "xorpd")
+                && !op.ends_with("ps")
+                && !op.ends_with("pd");
+        } else {
+            return Kind::Barrier;
+        }
+
+        let Some(operand) = memory.iter().position(|&memory| memory) else {
+            return Kind::Compute;
+        };
+        if mnemonic.starts_with("lea") {
+            return Kind::Compute;
+        }
+        let (start, end) = operands[operand];
+        let text = &self.text[start..end];
+        let (displacement, inside) = text.split_once('(').unwrap_or((text, ""));
+        let parts: Vec<&str> = inside.trim_end_matches(')').split(',').collect();
+        let base = self
+            .registers
+            .iter()
+            .position(|register| register.operand == operand && register.address)
+            .filter(|&t| parts.len() == 1 && self.registers[t].family != "rip");
+        let size = if let Some(width) = mnemonic
+            .strip_prefix("movz")
+            .or_else(|| mnemonic.strip_prefix("movs").filter(|rest| rest.len() == 2))
+            .and_then(|widths| widths.chars().next())
+        {
+            suffix_size(width)
+        } else if let Some(register) = (0..n)
+            .filter(|&other| other != operand)
+            .find_map(|other| self.register(other))
+        {
+            view_size(register.view)
+        } else {
+            mnemonic.chars().next_back().and_then(suffix_size)
+        };
+        self.access = Some(Access {
+            base,
+            offset: parse_offset(displacement),
+            size,
+            stores: operand == destination
+                && !mnemonic.starts_with("cmp")
+                && !mnemonic.starts_with("test"),
+        });
+        Kind::Compute
+    }
+
+    /// the destination is the first operand, except for stores and compares
+    fn aarch64(&mut self, mnemonic: &str, operands: &[(usize, usize)], memory: &[bool]) -> Kind {
+        const COMPUTE: &[&str] = &[
+            "mov", "movz", "movn", "mvn", "add", "adds", "sub", "subs", "neg", "negs", "and",
+            "ands", "orr", "orn", "eor", "eon", "bic", "bics", "lsl", "lsr", "asr", "ror", "mul",
+            "madd", "msub", "mneg", "smull", "umull", "smulh", "umulh", "udiv", "sdiv", "ubfx",
+            "sbfx", "ubfiz", "sbfiz", "uxtb", "uxth", "sxtb", "sxth", "sxtw", "fmov", "adr",
+            "adrp",
+        ];
+        const CONDITIONAL: &[&str] = &[
+            "csel", "csinc", "csinv", "csneg", "cset", "csetm", "cinc", "cinv", "cneg", "adc",
+            "adcs", "sbc", "sbcs", "ngc",
+        ];
+        const LOADS: &[&str] = &[
+            "ldr", "ldrb", "ldrh", "ldrsb", "ldrsh", "ldrsw", "ldur", "ldurb", "ldurh", "ldursb",
+            "ldursh", "ldursw", "ldp", "ldpsw",
+        ];
+        const STORES: &[&str] = &["str", "strb", "strh", "stur", "sturb", "sturh", "stp"];
+
+        if matches!(mnemonic, "bl" | "blr") {
+            return Kind::Call;
+        }
+        if mnemonic == "ret" {
+            return Kind::Return;
+        }
+        let n = operands.len();
+        let (loads, stores) = (LOADS.contains(&mnemonic), STORES.contains(&mnemonic));
+        if loads || stores {
+            let Some(operand) = memory.iter().position(|&memory| memory) else {
+                return Kind::Barrier;
+            };
+            let (start, end) = operands[operand];
+            let text = self.text[start..end].trim().to_string();
+            // pre- and post-indexing write the base register back
+            if text.ends_with('!') || operand + 1 != n {
+                return Kind::Barrier;
+            }
+            for other in 0..operand {
+                self.set(other, stores, loads);
+            }
+            let parts: Vec<&str> = text
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .map(str::trim)
+                .collect();
+            let offset = match parts[..] {
+                [_] => Some(0),
+                [_, offset] => offset.strip_prefix('#').and_then(parse_offset),
+                _ => None,
+            };
+            let width = match mnemonic.trim_start_matches("ld").trim_start_matches("st") {
+                "rb" | "rsb" | "urb" | "ursb" => Some(1),
+                "rh" | "rsh" | "urh" | "ursh" => Some(2),
+                "rsw" | "ursw" | "psw" => Some(4),
+                _ => self
+                    .register(0)
+                    .and_then(|register| view_size(register.view)),
+            };
+            let count = if mnemonic.contains('p') { 2 } else { 1 };
+            self.access = Some(Access {
+                base: self
+                    .registers
+                    .iter()
+                    .position(|register| register.operand == operand),
+                offset: if parts.len() <= 2 { offset } else { None },
+                size: width.map(|width| width * count),
+                stores,
+            });
+            return Kind::Compute;
+        }
+
+        if matches!(mnemonic, "cmp" | "cmn" | "tst" | "ccmp" | "ccmn") {
+            self.writes_flags = true;
+            self.reads_flags = mnemonic.starts_with("cc");
+            for operand in 0..n {
+                self.set(operand, true, false);
+            }
+            return Kind::Compute;
+        }
+        let conditional = CONDITIONAL.contains(&mnemonic);
+        if !conditional
+            && !COMPUTE.contains(&mnemonic)
+            && !matches!(mnemonic, "movk" | "bfi" | "bfxil")
+        {
+            return Kind::Barrier;
+        }
+        if memory.contains(&true) || n == 0 {
+            return Kind::Barrier;
+        }
+        self.reads_flags = conditional;
+        self.writes_flags = mnemonic.ends_with('s');
+        self.commutative = n == 3
+            && matches!(
+                mnemonic,
+                "add" | "adds" | "and" | "ands" | "orr" | "eor" | "mul" | "smulh" | "umulh"
+            );
+        self.set(0, matches!(mnemonic, "movk" | "bfi" | "bfxil"), true);
+        for operand in 1..n {
+            self.set(operand, true, false);
+        }
+        self.copy = mnemonic == "mov" && self.copies("x");
+        Kind::Compute
+    }
+
+    /// the destination is the first operand, except for stores
+    fn riscv64(&mut self, mnemonic: &str, operands: &[(usize, usize)], memory: &[bool]) -> Kind {
+        const COMPUTE: &[&str] = &[
+            "li", "lui", "auipc", "mv", "not", "neg", "negw", "add", "addw", "addi", "addiw",
+            "sub", "subw", "and", "andi", "or", "ori", "xor", "xori", "sll", "slli", "sllw",
+            "slliw", "srl", "srli", "srlw", "srliw", "sra", "srai", "sraw", "sraiw", "slt", "slti",
+            "sltu", "sltiu", "seqz", "snez", "sltz", "sgtz", "sext.b", "sext.h", "sext.w",
+            "zext.b", "zext.h", "zext.w", "mul", "mulw", "mulh", "mulhu", "div", "divu", "divw",
+            "divuw", "rem", "remu", "remw", "remuw",
+        ];
+        const LOADS: &[(&str, u64)] = &[
+            ("lb", 1),
+            ("lbu", 1),
+            ("lh", 2),
+            ("lhu", 2),
+            ("lw", 4),
+            ("lwu", 4),
+            ("ld", 8),
+            ("flw", 4),
+            ("fld", 8),
+        ];
+        const STORES: &[(&str, u64)] = &[
+            ("sb", 1),
+            ("sh", 2),
+            ("sw", 4),
+            ("sd", 8),
+            ("fsw", 4),
+            ("fsd", 8),
+        ];
+
+        if matches!(mnemonic, "call" | "jal" | "jalr") {
+            return Kind::Call;
+        }
+        if mnemonic == "ret" {
+            return Kind::Return;
+        }
+        let n = operands.len();
+        let load = LOADS.iter().find(|(op, _)| *op == mnemonic);
+        let store = STORES.iter().find(|(op, _)| *op == mnemonic);
+        if let Some(&(_, size)) = load.or(store) {
+            if n != 2 || !memory[1] {
+                return Kind::Barrier;
+            }
+            self.set(0, store.is_some(), load.is_some());
+            let (start, end) = operands[1];
+            let text = &self.text[start..end];
+            let displacement = text.split_once('(').map_or(text, |(before, _)| before);
+            self.access = Some(Access {
+                base: self
+                    .registers
+                    .iter()
+                    .position(|register| register.operand == 1),
+                offset: parse_offset(displacement),
+                size: Some(size),
+                stores: store.is_some(),
+            });
+            return Kind::Compute;
+        }
+        if !COMPUTE.contains(&mnemonic) || memory.contains(&true) || n == 0 {
+            return Kind::Barrier;
+        }
+        self.set(0, false, true);
+        for operand in 1..n {
+            self.set(operand, true, false);
+        }
+        self.copy = mnemonic == "mv" && self.copies("x");
+        self.commutative = n == 3
+            && matches!(
+                mnemonic,
+                "add" | "addw" | "and" | "or" | "xor" | "mul" | "mulw" | "mulh" | "mulhu"
+            );
+        Kind::Compute
+    }
+}
+
+/// the byte ranges of the comma-separated operands of `line`, from `start`
+fn operands(line: &str, start: usize) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut depth = 0;
+    let mut from = start;
+    for (offset, c) in line[start..].char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                ranges.push((from, start + offset));
+                from = start + offset + 1;
+            }
+            _ => {}
+        }
+    }
+    if !line[from..].trim().is_empty() {
+        ranges.push((from, line.len()));
+    }
+    ranges
+}
+
+fn parse_offset(text: &str) -> Option<i64> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, text),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => i64::from_str_radix(hex, 16).ok()?,
+        None if digits.is_empty() => 0,
+        None => digits.parse().ok()?,
+    };
+    Some(if negative { -value } else { value })
+}
+
+/// bytes in an x86 size suffix
+fn suffix_size(suffix: char) -> Option<u64> {
+    match suffix {
+        'b' => Some(1),
+        'w' => Some(2),
+        'l' => Some(4),
+        'q' => Some(8),
+        _ => None,
+    }
+}
+
+/// bytes in a view of a register
+fn view_size(view: &str) -> Option<u64> {
+    match view {
+        "8" | "b" => Some(1),
+        "16" | "h" => Some(2),
+        "32" | "w" | "s" => Some(4),
+        "64" | "x" | "d" => Some(8),
+        "xmm" | "q" => Some(16),
+        "ymm" => Some(32),
+        "zmm" => Some(64),
+        _ => None,
+    }
+}
+
+/// x86_64's general-purpose registers, by width: 8, 16, 32 and 64 bits
+const X86_64_REGISTERS: [[&str; 4]; 16] = [
+    ["al", "ax", "eax", "rax"],
+    ["bl", "bx", "ebx", "rbx"],
+    ["cl", "cx", "ecx", "rcx"],
+    ["dl", "dx", "edx", "rdx"],
+    ["sil", "si", "esi", "rsi"],
+    ["dil", "di", "edi", "rdi"],
+    ["bpl", "bp", "ebp", "rbp"],
+    ["spl", "sp", "esp", "rsp"],
+    ["r8b", "r8w", "r8d", "r8"],
+    ["r9b", "r9w", "r9d", "r9"],
+    ["r10b", "r10w", "r10d", "r10"],
+    ["r11b", "r11w", "r11d", "r11"],
+    ["r12b", "r12w", "r12d", "r12"],
+    ["r13b", "r13w", "r13d", "r13"],
+    ["r14b", "r14w", "r14d", "r14"],
+    ["r15b", "r15w", "r15d", "r15"],
+];
+const X86_64_WIDTHS: [&str; 4] = ["8", "16", "32", "64"];
+
+const RISCV64_REGISTERS: &[&str] = &[
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "t3", "t4", "t5", "t6", "s0", "s1", "s2",
+    "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "a0", "a1", "a2", "a3", "a4", "a5",
+    "a6", "a7",
+];
+
+impl Arch {
+    /// the register family and view a token names, if it is a register
+    fn register(self, token: &str) -> Option<(String, &'static str)> {
+        let numbered = |prefix: &str, last: u32| {
+            token
+                .strip_prefix(prefix)
+                .filter(|n| !n.starts_with('0') || *n == "0")
+                .and_then(|n| n.parse::<u32>().ok())
+                .filter(|&n| n <= last)
+        };
+        match self {
+            Arch::X86_64 => {
+                for row in &X86_64_REGISTERS {
+                    if let Some(width) = row.iter().position(|name| *name == token) {
+                        return Some((row[3].to_string(), X86_64_WIDTHS[width]));
+                    }
+                }
+                for view in ["xmm", "ymm", "zmm"] {
+                    if let Some(n) = numbered(view, 31) {
+                        return Some((format!("xmm{n}"), view));
+                    }
+                }
+                (token == "rip").then(|| ("rip".to_string(), "64"))
+            }
+            Arch::Aarch64 => {
+                match token {
+                    "sp" => return Some(("sp".to_string(), "x")),
+                    "wsp" => return Some(("sp".to_string(), "w")),
+                    "xzr" => return Some(("xzr".to_string(), "x")),
+                    "wzr" => return Some(("xzr".to_string(), "w")),
+                    _ => {}
+                }
+                for view in ["w", "x"] {
+                    if let Some(n) = numbered(view, 30) {
+                        return Some((format!("x{n}"), view));
+                    }
+                }
+                for view in ["b", "h", "s", "d", "q", "v"] {
+                    if let Some(n) = numbered(view, 31) {
+                        return Some((format!("v{n}"), view));
+                    }
+                }
+                None
+            }
+            Arch::Riscv64 => {
+                let token = if token == "fp" { "s0" } else { token };
+                if RISCV64_REGISTERS.contains(&token) {
+                    return Some((token.to_string(), "x"));
+                }
+                let float = ["ft", "fs", "fa"]
+                    .iter()
+                    .any(|prefix| numbered(prefix, 11).is_some());
+                float.then(|| (token.to_string(), "f"))
+            }
+            Arch::Other => None,
+        }
+    }
+
+    /// the name of the `view` of register `family`
+    fn name(self, family: &str, view: &str) -> String {
+        match self {
+            Arch::X86_64 => {
+                if let Some(n) = family.strip_prefix("xmm") {
+                    return format!("{view}{n}");
+                }
+                let width = X86_64_WIDTHS.iter().position(|width| *width == view);
+                X86_64_REGISTERS
+                    .iter()
+                    .find(|row| row[3] == family)
+                    .zip(width)
+                    .map_or_else(|| family.to_string(), |(row, width)| row[width].to_string())
+            }
+            Arch::Aarch64 => match (family, view) {
+                ("sp", "w") => "wsp".to_string(),
+                ("xzr", view) => format!("{view}zr"),
+                _ => format!("{view}{}", &family[1..]),
+            },
+            Arch::Riscv64 | Arch::Other => family.to_string(),
+        }
+    }
+
+    /// what a call may read: the argument registers, the callee-saved ones it
+    /// has to preserve, and the stack pointer
+    fn call_reads(self) -> BTreeSet<String> {
+        match self {
+            Arch::X86_64 => [
+                "rdi", "rsi", "rdx", "rcx", "r8", "r9", "rbx", "rbp", "r12", "r13", "r14", "r15",
+                "rsp",
+            ]
+            .map(String::from)
+            .into_iter()
+            .chain((0..8).map(|n| format!("xmm{n}")))
+            .collect(),
+            Arch::Aarch64 => (0..=8)
+                .chain(19..=29)
+                .map(|n| format!("x{n}"))
+                .chain((0..16).map(|n| format!("v{n}")))
+                .chain(["sp".to_string()])
+                .collect(),
+            Arch::Riscv64 => (0..8)
+                .map(|n| format!("a{n}"))
+                .chain((0..8).map(|n| format!("fa{n}")))
+                .chain((0..12).map(|n| format!("s{n}")))
+                .chain((0..12).map(|n| format!("fs{n}")))
+                .chain(["sp", "gp", "tp"].map(String::from))
+                .collect(),
+            Arch::Other => BTreeSet::new(),
+        }
+    }
+
+    /// what a return reads: the return registers, and the callee-saved ones
+    /// and the return address; a result returned through `memory` leaves
+    /// only its address in a register
+    fn return_reads(self, memory: bool) -> BTreeSet<String> {
+        match self {
+            Arch::X86_64 => [
+                "rax", "rdx", "xmm0", "xmm1", "rbx", "rbp", "r12", "r13", "r14", "r15", "rsp",
+            ]
+            .into_iter()
+            .filter(|register| !(memory && matches!(*register, "rdx" | "xmm0" | "xmm1")))
+            .map(String::from)
+            .collect(),
+            Arch::Aarch64 => [0, 1]
+                .into_iter()
+                .chain(19..=30)
+                .map(|n| format!("x{n}"))
+                .chain((0..4).chain(8..16).map(|n| format!("v{n}")))
+                .chain(["sp".to_string()])
+                .collect(),
+            Arch::Riscv64 => ["a0", "a1", "fa0", "fa1", "ra", "sp", "gp", "tp"]
+                .map(String::from)
+                .into_iter()
+                .chain((0..12).map(|n| format!("s{n}")))
+                .chain((0..12).map(|n| format!("fs{n}")))
+                .collect(),
+            Arch::Other => BTreeSet::new(),
+        }
+    }
+}
+
+/// one step through two sequences
+enum Step {
+    /// the right function's index of a line in both
+    Both(usize),
+    Left(usize),
+    Right(usize),
+}
+
+// the same longest-common-subsequence walk as the golden-file diff, over
+// instruction pairs that `same` says match
+fn diff(n: usize, m: usize, same: impl Fn(usize, usize) -> bool) -> Vec<Step> {
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if same(i, j) {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut steps = Vec::new();
+    while i < n || j < m {
+        if i < n && j < m && same(i, j) {
+            steps.push(Step::Both(j));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            steps.push(Step::Left(i));
+            i += 1;
+        } else {
+            steps.push(Step::Right(j));
+            j += 1;
+        }
+    }
+    steps
+}
@@ -0,0 +1,309 @@
+use std::env;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{Error, IrListing, Listing, Report, Result};
+
+/// the compiler that produced a listing
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Toolchain {
+    /// host target triple, e.g. `x86_64-unknown-linux-gnu`
+    pub host: String,
+    /// `rustc --version`
+    pub rustc: String,
+    /// `cargo --version`
+    pub cargo: String,
+}
+
+impl Toolchain {
+    /// ask `rustc` and `cargo` which versions they are
+    pub fn detect() -> Result<Toolchain> {
+        let verbose = run(Command::new(rustc()).arg("-vV"))?;
+        let rustc = verbose.lines().next().unwrap_or_default().to_string();
+        let host = verbose
+            .lines()
+            .find_map(|line| line.strip_prefix("host: "))
+            .unwrap_or_default()
+            .to_string();
+        let cargo = run(Command::new(cargo()).arg("--version"))?
+            .trim()
+            .to_string();
+        Ok(Toolchain { host, rustc, cargo })
+    }
+}
+
+/// the targets whose assembly the tooling is known to read
+///
+/// [`Listing`] understands the ELF, Mach-O and wasm flavours of assembly that
+/// these compile to; [`IrListing`] does not depend on the target at all.
+pub const TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "aarch64-unknown-linux-gnu",
+    "aarch64-apple-darwin",
+    "riscv64gc-unknown-linux-gnu",
+    "wasm32-unknown-unknown",
+];
+
+/// `[profile.release]` settings to build with instead of the workspace's
+///
+/// There is no LTO setting. The assembly is emitted for the library itself,
+/// before anything is linked, so `lto` would not change it; and the
+/// artifacts that do run LTO export none of the library's functions, so it
+/// would throw them all away.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Profile {
+    /// `0`, `1`, `2`, `3`, `s` or `z`
+    pub opt_level: String,
+    /// `codegen-units = 1` rather than the default
+    pub single_codegen_unit: bool,
+}
+
+impl Profile {
+    /// every `opt-level` cargo accepts
+    pub const OPT_LEVELS: [&str; 6] = ["0", "1", "2", "3", "s", "z"];
+
+    /// `opt-level = opt_level`, with the default codegen units
+    pub fn new(opt_level: impl Into<String>) -> Profile {
+        Profile {
+            opt_level: opt_level.into(),
+            single_codegen_unit: false,
+        }
+    }
+
+    pub fn single_codegen_unit(mut self, single_codegen_unit: bool) -> Profile {
+        self.single_codegen_unit = single_codegen_unit;
+        self
+    }
+}
+
+/// `O3`, `O2+cgu1`, …; also the name of the profile's target directory
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "O{}", self.opt_level)?;
+        if self.single_codegen_unit {
+            f.write_str("+cgu1")?;
+        }
+        Ok(())
+    }
+}
+
+/// a release build of one package in the workspace, emitting assembly
+///
+/// Each build gets its own target directory under `target/records-asm` so it
+/// neither clobbers nor is confused by a normal `cargo build`, and a build
+/// with a [`Profile`] gets a subdirectory of that.
+pub struct Build {
+    workspace: PathBuf,
+    package: String,
+    profile: Option<Profile>,
+    target: Option<String>,
+}
+
+impl Build {
+    /// build `records-in-rust` from the workspace rooted at `workspace`
+    pub fn new(workspace: impl Into<PathBuf>) -> Build {
+        Build {
+            workspace: workspace.into(),
+            package: "records-in-rust".to_string(),
+            profile: None,
+            target: None,
+        }
+    }
+
+    /// build a different package in the workspace
+    pub fn package(mut self, package: impl Into<String>) -> Build {
+        self.package = package.into();
+        self
+    }
+
+    /// build with `profile` instead of the workspace's release profile
+    pub fn profile(mut self, profile: Profile) -> Build {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// cross-compile for `target` instead of the host
+    ///
+    /// Only the target's standard library is needed: the library is never
+    /// linked, so no cross linker is.
+    pub fn target(mut self, target: impl Into<String>) -> Build {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// the target being cross-compiled for, if any
+    pub fn triple(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    /// `true` if there is a standard library to compile against for the
+    /// target, which is always the case for the host
+    pub fn target_installed(&self) -> Result<bool> {
+        let Some(target) = &self.target else {
+            return Ok(true);
+        };
+        let libdir =
+            run(Command::new(rustc()).args(["--print", "target-libdir", "--target", target]))?;
+        Ok(Path::new(libdir.trim()).is_dir())
+    }
+
+    /// the library's crate name, as it appears in symbol paths
+    pub fn crate_name(&self) -> String {
+        self.package.replace('-', "_")
+    }
+
+    /// `cargo rustc --release -- --emit asm`, returning the `.s` file
+    pub fn emit_asm(&self) -> Result<String> {
+        self.emit("asm", "s")
+    }
+
+    /// `cargo rustc --release -- --emit llvm-ir`, returning the `.ll` file
+    pub fn emit_ir(&self) -> Result<String> {
+        self.emit("llvm-ir", "ll")
+    }
+
+    /// emit the assembly and parse it
+    pub fn listing(&self) -> Result<Listing> {
+        Ok(Listing::parse(&self.emit_asm()?))
+    }
+
+    /// emit the LLVM IR and parse it
+    pub fn ir(&self) -> Result<IrListing> {
+        Ok(IrListing::parse(&self.emit_ir()?))
+    }
+
+    /// emit the assembly and the LLVM IR from one build, and parse both
+    pub fn listings(&self) -> Result<(Listing, IrListing)> {
+        self.rustc("asm,llvm-ir")?;
+        let asm = self.read_output("s")?;
+        let ir = self.read_output("ll")?;
+        Ok((Listing::parse(&asm), IrListing::parse(&ir)))
+    }
+
+    /// emit the assembly and extract `names` (paths relative to the crate)
+    pub fn report<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Result<Report> {
+        let toolchain = Toolchain::detect()?;
+        let mut report = Report::extract(toolchain, &self.listing()?, &self.crate_name(), names)?;
+        if let Some(target) = &self.target {
+            report.target = target.clone();
+        }
+        Ok(report)
+    }
+
+    fn target_dir(&self) -> PathBuf {
+        let dir = self.workspace.join("target").join("records-asm");
+        match &self.profile {
+            Some(profile) => dir.join(profile.to_string()),
+            None => dir,
+        }
+    }
+
+    fn emit(&self, emit: &str, extension: &'static str) -> Result<String> {
+        self.rustc(emit)?;
+        self.read_output(extension)
+    }
+
+    fn rustc(&self, emit: &str) -> Result<()> {
+        if !self.target_installed()? {
+            let target = self.target.clone().unwrap_or_default();
+            return Err(Error::TargetNotInstalled(target));
+        }
+        let mut command = Command::new(cargo());
+        command
+            .current_dir(&self.workspace)
+            .args(["rustc", "--release", "--lib", "--package", &self.package])
+            .arg("--target-dir")
+            .arg(self.target_dir());
+        if let Some(target) = &self.target {
+            command.args(["--target", target]);
+        }
+        command.args(["--", "--emit", emit]);
+        if let Some(profile) = &self.profile {
+            command
+                .env("CARGO_PROFILE_RELEASE_OPT_LEVEL", &profile.opt_level)
+                .env("CARGO_PROFILE_RELEASE_LTO", "false");
+            if profile.single_codegen_unit {
+                command.env("CARGO_PROFILE_RELEASE_CODEGEN_UNITS", "1");
+            } else {
+                command.env_remove("CARGO_PROFILE_RELEASE_CODEGEN_UNITS");
+            }
+        }
+        run(&mut command)?;
+        Ok(())
+    }
+
+    fn read_output(&self, extension: &'static str) -> Result<String> {
+        let mut deps = self.target_dir();
+        if let Some(target) = &self.target {
+            deps.push(target);
+        }
+        let deps = deps.join("release").join("deps");
+        let output = find_output(&deps, &self.crate_name(), extension)?;
+        Ok(fs::read_to_string(output)?)
+    }
+}
+
+fn rustc() -> OsString {
+    env::var_os("RUSTC").unwrap_or_else(|| "rustc".into())
+}
+
+fn cargo() -> OsString {
+    env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
+
+fn run(command: &mut Command) -> Result<String> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(Error::Command {
+            command: format!("{command:?}"),
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn outputs(dir: &Path, crate_name: &str, extension: &str) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let prefix = format!("{crate_name}-");
+    let mut found = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let matches = path.extension().is_some_and(|e| e == extension)
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix));
+        if matches {
+            found.push(path);
+        }
+    }
+    Ok(found)
+}
+
+// cargo names the output after a hash of the build configuration and leaves
+// old outputs behind, and when nothing changed it does not rerun rustc at all,
+// so the newest file is the one for this build
+fn find_output(dir: &Path, crate_name: &str, extension: &'static str) -> Result<PathBuf> {
+    let mut newest = None;
+    for path in outputs(dir, crate_name, extension)? {
+        let modified = fs::metadata(&path)?.modified()?;
+        if newest.as_ref().is_none_or(|(time, _)| modified > *time) {
+            newest = Some((modified, path));
+        }
+    }
+    newest.map(|(_, path)| path).ok_or_else(|| Error::NoOutput {
+        dir: dir.to_path_buf(),
+        extension,
+    })
+}
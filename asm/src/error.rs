@@ -0,0 +1,65 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+/// everything that can go wrong while emitting or extracting assembly
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// a `cargo` or `rustc` invocation exited unsuccessfully
+    Command {
+        command: String,
+        status: ExitStatus,
+        stderr: String,
+    },
+    /// the compiler succeeded but left no output file behind
+    NoOutput {
+        dir: PathBuf,
+        extension: &'static str,
+    },
+    /// a requested function is not in the listing
+    MissingFunction(String),
+    /// the standard library for a target triple is not installed, so nothing
+    /// can be compiled for it
+    TargetNotInstalled(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Command {
+                command,
+                status,
+                stderr,
+            } => write!(f, "`{command}` failed ({status}):\n{stderr}"),
+            Error::NoOutput { dir, extension } => {
+                write!(f, "no `.{extension}` file was emitted in {}", dir.display())
+            }
+            Error::MissingFunction(name) => write!(f, "no function named `{name}` in the listing"),
+            Error::TargetNotInstalled(target) => write!(
+                f,
+                "the standard library for `{target}` is not installed; \
+                 try `rustup target add {target}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
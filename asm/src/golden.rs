@@ -0,0 +1,132 @@
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{Report, Result};
+
+/// checked-in, normalized assembly for one target triple
+///
+/// Each function is kept in `<dir>/<triple>/<name>.s`, in the form produced by
+/// [`Function::normalized`](crate::Function::normalized). A name's `::`
+/// separators are written as `.`, so `lens::update` is kept in `lens.update.s`.
+pub struct Golden {
+    dir: PathBuf,
+}
+
+/// a function whose assembly no longer matches its golden file
+#[derive(Debug)]
+pub struct Mismatch {
+    pub name: String,
+    /// `None` when there is no golden file for the function yet
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+impl Golden {
+    /// the golden files for `triple` under `root`
+    pub fn new(root: impl Into<PathBuf>, triple: &str) -> Golden {
+        Golden {
+            dir: root.into().join(triple),
+        }
+    }
+
+    /// `false` if nothing has been blessed for this triple yet
+    pub fn exists(&self) -> bool {
+        self.dir.is_dir()
+    }
+
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.s", name.replace("::", ".")))
+    }
+
+    /// compare every function in `report` against its golden file
+    pub fn check(&self, report: &Report) -> Result<Vec<Mismatch>> {
+        let mut mismatches = Vec::new();
+        for extract in &report.functions {
+            let path = self.path(&extract.name);
+            let expected = fs::read_to_string(&path).ok();
+            let actual = extract.function.normalized();
+            if expected.as_deref() != Some(actual.as_str()) {
+                mismatches.push(Mismatch {
+                    name: extract.name.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(mismatches)
+    }
+
+    /// overwrite the golden files with `report`, removing any for functions
+    /// that are no longer in it
+    pub fn bless(&self, report: &Report) -> Result<()> {
+        if self.dir.exists() {
+            for entry in fs::read_dir(&self.dir)? {
+                let path = entry?.path();
+                if path.extension().is_some_and(|e| e == "s") {
+                    fs::remove_file(path)?;
+                }
+            }
+        }
+        fs::create_dir_all(&self.dir)?;
+        for extract in &report.functions {
+            let path = self.path(&extract.name);
+            fs::write(path, extract.function.normalized())?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(expected) = &self.expected else {
+            writeln!(f, "{}: no golden file", self.name)?;
+            return write!(f, "{}", self.actual);
+        };
+        writeln!(f, "{}: assembly changed", self.name)?;
+        writeln!(f, "--- golden")?;
+        writeln!(f, "+++ actual")?;
+        let expected: Vec<_> = expected.lines().collect();
+        let actual: Vec<_> = self.actual.lines().collect();
+        for line in diff(&expected, &actual) {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+// a plain longest-common-subsequence line diff; listings are a few dozen
+// lines, so the quadratic table is fine
+fn diff(expected: &[&str], actual: &[&str]) -> Vec<String> {
+    let (n, m) = (expected.len(), actual.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            lines.push(format!(" {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("-{}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+{}", actual[j]));
+            j += 1;
+        }
+    }
+    lines
+}
@@ -0,0 +1,399 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use rustc_demangle::try_demangle;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// one function from an LLVM IR (`.ll`) file
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct IrFunction {
+    /// demangled path without the hash
+    pub name: String,
+    pub symbol: String,
+    pub params: Vec<Param>,
+    /// instructions between `{` and `}`, as emitted
+    pub body: Vec<String>,
+    /// set when the function is an `alias` of another; `body` and `params`
+    /// are then the other function's
+    pub alias_of: Option<String>,
+}
+
+/// a pointer or value parameter of an [`IrFunction`]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Param {
+    /// the SSA name, without the `%`
+    pub name: String,
+    /// `true` for the hidden return slot (`sret`) of a function returning a
+    /// struct too large for registers
+    pub sret: bool,
+}
+
+/// every Rust function in a `.ll` file
+#[derive(Clone, Debug, Default)]
+pub struct IrListing {
+    functions: Vec<IrFunction>,
+}
+
+impl IrListing {
+    pub fn parse(ir: &str) -> IrListing {
+        let mut functions = Vec::new();
+        let mut aliases = Vec::new();
+        let mut current: Option<IrFunction> = None;
+
+        for line in ir.lines() {
+            if let Some(function) = current.as_mut() {
+                if line.starts_with('}') {
+                    functions.extend(current.take());
+                } else if !line.trim().is_empty() && !line.trim_start().starts_with(';') {
+                    function.body.push(line.to_string());
+                }
+            } else if let Some(function) = define(line) {
+                current = Some(function);
+            } else if let Some(alias) = alias(line) {
+                aliases.push(alias);
+            }
+        }
+
+        for (symbol, target) in aliases {
+            let Some(aliased) = functions.iter().find(|f| f.symbol == target) else {
+                continue;
+            };
+            functions.push(IrFunction {
+                name: demangle(&symbol),
+                symbol,
+                alias_of: Some(aliased.name.clone()),
+                ..aliased.clone()
+            });
+        }
+        IrListing { functions }
+    }
+
+    pub fn functions(&self) -> &[IrFunction] {
+        &self.functions
+    }
+
+    /// look up a function by its demangled path
+    pub fn function(&self, name: &str) -> Option<&IrFunction> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+}
+
+// The article decides whether a second struct was created by looking at which
+// register the stores go through: `x0` is the record that was passed in, `x8`
+// is the caller's return slot. The same question can be answered without
+// knowing anything about arm64 by asking LLVM which _pointer_ each store goes
+// through.
+
+/// where a function writes its result
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Verdict {
+    /// every write goes through the record that was passed in, or memory it
+    /// points to
+    InPlace,
+    /// the result is built directly in the caller's return slot
+    SecondStruct,
+    /// the record that was passed in is updated, then copied to the return slot
+    InPlaceThenCopied,
+    /// both the record that was passed in and the return slot are written, but
+    /// the return slot is not a copy of the record
+    Both,
+    /// nothing is written to memory; the result comes back in registers
+    Registers,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::InPlace => "in-place",
+            Verdict::SecondStruct => "second struct",
+            Verdict::InPlaceThenCopied => "in-place, then copied",
+            Verdict::Both => "in-place and second struct",
+            Verdict::Registers => "in registers",
+        })
+    }
+}
+
+/// what an [`IrFunction`] does with memory
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Analysis {
+    pub name: String,
+    pub verdict: Verdict,
+    /// the function has an `sret` return slot
+    pub sret: bool,
+    /// stores (or copies) through a non-`sret` pointer parameter, or a pointer
+    /// loaded from one
+    pub writes_input: bool,
+    /// stores (or copies) through the `sret` parameter
+    pub writes_return_slot: bool,
+    /// stores to the function's own stack (`alloca`)
+    pub writes_stack: bool,
+    /// calls `llvm.memcpy` or `llvm.memmove`
+    pub memcpy: bool,
+    /// uses vector types (`<4 x i32>`), i.e. was auto-vectorized
+    pub vectorized: bool,
+    /// functions called that are not LLVM intrinsics, demangled
+    pub calls: Vec<String>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Root {
+    Input,
+    ReturnSlot,
+    Stack,
+    Unknown,
+}
+
+impl IrFunction {
+    /// follow every store and copy back to the pointer it was derived from
+    pub fn analyze(&self) -> Analysis {
+        let mut roots: HashMap<String, Root> = self
+            .params
+            .iter()
+            .map(|param| {
+                let root = if param.sret {
+                    Root::ReturnSlot
+                } else {
+                    Root::Input
+                };
+                (param.name.clone(), root)
+            })
+            .collect();
+        // A loop's `phi` can name a pointer defined further down, so keep
+        // propagating until nothing changes.
+        loop {
+            let known = roots.len();
+            for line in &self.body {
+                if let Some((result, root)) = derived_pointer(&roots, line.trim()) {
+                    roots.insert(result, root);
+                }
+            }
+            if roots.len() == known {
+                break;
+            }
+        }
+
+        let mut writes = Vec::new();
+        let mut memcpy = false;
+        let mut copies_input = false;
+        let mut vectorized = false;
+        let mut calls = Vec::new();
+
+        for line in &self.body {
+            let line = line.trim();
+            if line.contains(" x ") && line.contains('<') {
+                vectorized |= vector_type(line);
+            }
+            if let Some(store) = line.strip_prefix("store ") {
+                if let Some(pointer) = pointer_operands(store).last() {
+                    writes.push(root_of(&roots, pointer));
+                }
+            } else if let Some(callee) = callee(line) {
+                if callee.starts_with("llvm.memcpy") || callee.starts_with("llvm.memmove") {
+                    memcpy = true;
+                    let operands = pointer_operands(line);
+                    if let Some(destination) = operands.first() {
+                        let destination = root_of(&roots, destination);
+                        let source = operands.get(1).map(|source| root_of(&roots, source));
+                        copies_input |=
+                            destination == Root::ReturnSlot && source == Some(Root::Input);
+                        writes.push(destination);
+                    }
+                } else if callee.starts_with("llvm.memset") {
+                    if let Some(destination) = pointer_operands(line).first() {
+                        writes.push(root_of(&roots, destination));
+                    }
+                } else if !callee.starts_with("llvm.") {
+                    calls.push(demangle(callee));
+                }
+            }
+        }
+
+        let writes_input = writes.contains(&Root::Input);
+        let writes_return_slot = writes.contains(&Root::ReturnSlot);
+        let verdict = match (writes_input, writes_return_slot) {
+            (true, true) if copies_input => Verdict::InPlaceThenCopied,
+            (true, true) => Verdict::Both,
+            (false, true) => Verdict::SecondStruct,
+            (true, false) => Verdict::InPlace,
+            (false, false) => Verdict::Registers,
+        };
+        Analysis {
+            name: self.name.clone(),
+            verdict,
+            sret: self.params.iter().any(|param| param.sret),
+            writes_input,
+            writes_return_slot,
+            writes_stack: writes.contains(&Root::Stack),
+            memcpy,
+            vectorized,
+            calls,
+        }
+    }
+}
+
+// the root of a pointer computed from other pointers: an offset into one
+// (`getelementptr`), one loaded out of one (a `Vec`'s buffer, say, which the
+// struct holding the `Vec` owns), or a choice between several (`phi`,
+// `select`)
+fn derived_pointer(roots: &HashMap<String, Root>, line: &str) -> Option<(String, Root)> {
+    let (result, instruction) = line.split_once(" = ")?;
+    let result = result.trim_start_matches('%').to_string();
+    if instruction.starts_with("alloca") {
+        return Some((result, Root::Stack));
+    }
+    let sources = if instruction.starts_with("getelementptr") || instruction.starts_with("load ptr")
+    {
+        pointer_operands(instruction).into_iter().take(1).collect()
+    } else if instruction.starts_with("phi ptr") {
+        instruction
+            .split('[')
+            .skip(1)
+            .filter_map(|incoming| incoming.trim().strip_prefix('%'))
+            .filter_map(|incoming| incoming.split(',').next())
+            .map(str::to_string)
+            .collect()
+    } else if instruction.starts_with("select") && instruction.contains(", ptr ") {
+        pointer_operands(instruction)
+    } else {
+        return None;
+    };
+    let known: Vec<Root> = sources
+        .iter()
+        .filter_map(|source| roots.get(source).copied())
+        .collect();
+    let root = *known.first()?;
+    if known.iter().any(|other| *other != root) {
+        return Some((result, Root::Unknown));
+    }
+    match roots.get(&result) {
+        Some(existing) if *existing == root => None,
+        _ => Some((result, root)),
+    }
+}
+
+fn root_of(roots: &HashMap<String, Root>, pointer: &str) -> Root {
+    roots.get(pointer).copied().unwrap_or(Root::Unknown)
+}
+
+fn demangle(symbol: &str) -> String {
+    format!("{:#}", rustc_demangle::demangle(symbol))
+}
+
+// `@foo` or `@"foo"`
+fn global(token: &str) -> Option<String> {
+    let name = token.strip_prefix('@')?;
+    let name = name.trim_matches('"');
+    let end = name
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '$')))
+        .unwrap_or(name.len());
+    Some(name[..end].to_string())
+}
+
+fn define(line: &str) -> Option<IrFunction> {
+    let rest = line.strip_prefix("define ")?;
+    let at = rest.find('@')?;
+    let symbol = global(&rest[at..])?;
+    try_demangle(&symbol).ok()?;
+    let open = at + rest[at..].find('(')?;
+    let params = split_top_level(&rest[open + 1..])
+        .into_iter()
+        .filter_map(|param| {
+            let name = param.split_whitespace().last()?.strip_prefix('%')?;
+            Some(Param {
+                name: name.to_string(),
+                sret: param.contains("sret("),
+            })
+        })
+        .collect();
+    Some(IrFunction {
+        name: demangle(&symbol),
+        symbol,
+        params,
+        body: Vec::new(),
+        alias_of: None,
+    })
+}
+
+fn alias(line: &str) -> Option<(String, String)> {
+    let (symbol, rest) = line.split_once(" = ")?;
+    if !rest.contains(" alias ") {
+        return None;
+    }
+    let symbol = global(symbol)?;
+    let target = global(&rest[rest.rfind('@')?..])?;
+    try_demangle(&symbol).ok()?;
+    Some((symbol, target))
+}
+
+// the parameter list of a `define`, up to its closing parenthesis, split on
+// the commas that are not inside attributes like `initializes((0, 12))`
+fn split_top_level(params: &str) -> Vec<&str> {
+    let mut depth = 0;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' if depth > 0 => depth -= 1,
+            ')' => {
+                parts.push(&params[start..i]);
+                return parts;
+            }
+            ',' if depth == 0 => {
+                parts.push(&params[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts
+}
+
+// the SSA names of every `ptr %x` operand, in order
+fn pointer_operands(instruction: &str) -> Vec<String> {
+    let mut operands = Vec::new();
+    let mut rest = instruction;
+    while let Some(at) = rest.find("ptr ") {
+        rest = &rest[at + 4..];
+        let operand = rest
+            .split_whitespace()
+            .find(|token| !is_attribute(token))
+            .unwrap_or_default();
+        if let Some(name) = operand.strip_prefix('%') {
+            let end = name.find([',', ')']).unwrap_or(name.len());
+            operands.push(name[..end].to_string());
+        }
+    }
+    operands
+}
+
+// parameter attributes that can sit between `ptr` and the operand in a call
+fn is_attribute(token: &str) -> bool {
+    !token.starts_with('%') && !token.starts_with('@') && !token.starts_with("null")
+}
+
+// the function a `call` or an `invoke` (a call that can unwind) calls
+fn callee(line: &str) -> Option<&str> {
+    if !line.contains("call ") && !line.contains("invoke ") {
+        return None;
+    }
+    let at = line.find(" @")?;
+    let name = &line[at + 2..];
+    let name = name.trim_start_matches('"');
+    let end = name.find(['(', '"']).unwrap_or(name.len());
+    Some(&name[..end])
+}
+
+fn vector_type(line: &str) -> bool {
+    line.match_indices('<').any(|(i, _)| {
+        let rest = &line[i + 1..];
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        digits > 0 && rest[digits..].starts_with(" x ")
+    })
+}
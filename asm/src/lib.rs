@@ -0,0 +1,38 @@
+//! Emit, extract and report the assembly of the `records-in-rust` update
+//! strategies.
+//!
+//! The article in `src/lib.md` was written by running
+//! `cargo rustc --release -- --emit asm` and copying functions out of the `.s`
+//! file by hand. This crate does the same thing mechanically: [`Build`] runs
+//! the compiler, [`Listing`] splits the output into demangled functions, and
+//! [`Report`] picks out the functions we care about. [`Golden`] keeps a
+//! normalized copy of a report under version control so a compiler upgrade
+//! that changes the code shows up as a failing test.
+//!
+//! [`IrListing`] does the same for LLVM IR, where [`IrFunction::analyze`] can
+//! tell whether a function updates its record in place or writes a second
+//! struct, independently of the target's register conventions.
+//!
+//! [`Diff`] compares two functions' assembly instruction by instruction,
+//! telling register renaming and the reordering of independent instructions
+//! apart from changes to what they compute.
+//!
+//! [`literate`] works on the article itself, replacing its hand-copied
+//! listings with freshly emitted ones.
+
+mod diff;
+mod emit;
+mod error;
+mod golden;
+mod ir;
+mod listing;
+pub mod literate;
+mod report;
+
+pub use diff::{Arch, Diff, Line, Similarity};
+pub use emit::{Build, Profile, TARGETS, Toolchain};
+pub use error::{Error, Result};
+pub use golden::{Golden, Mismatch};
+pub use ir::{Analysis, IrFunction, IrListing, Param, Verdict};
+pub use listing::{Function, Listing};
+pub use report::{Extract, Report};
@@ -0,0 +1,211 @@
+use rustc_demangle::try_demangle;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// one function from an assembly listing
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Function {
+    /// demangled path without the hash, e.g. `records_in_rust::update_record_no_refs`
+    pub name: String,
+    /// the mangled symbol, as it appears in the label
+    pub symbol: String,
+    /// lines between the label and the end of the function, as emitted
+    pub body: Vec<String>,
+    /// set when the compiler merged this function into another identical one
+    /// (`symbol = other`); `body` is then the other function's body
+    pub alias_of: Option<String>,
+}
+
+impl Function {
+    /// the instructions in the body, without directives, labels or comments
+    pub fn instructions(&self) -> impl Iterator<Item = &str> {
+        self.body
+            .iter()
+            .map(|line| strip_comment(line).trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('.') && !line.ends_with(':'))
+    }
+
+    /// the function in a form that only changes when its code does
+    ///
+    /// The symbol hash, directives and comments are dropped, symbols the body
+    /// refers to are demangled, and local labels (`.LBB3_2`) are renumbered in
+    /// order of appearance, since their numbers depend on where the function
+    /// sits in the file.
+    pub fn normalized(&self) -> String {
+        let mut labels = Vec::new();
+        let mut text = format!("{}:\n", self.name);
+        if let Some(target) = &self.alias_of {
+            text.push_str(&format!("# same code as {target}\n"));
+        }
+        for line in &self.body {
+            let line = strip_comment(line).trim();
+            let is_label = line.ends_with(':');
+            if line.is_empty() || (line.starts_with('.') && !is_label) {
+                continue;
+            }
+            let line = normalize_symbols(line, &mut labels);
+            if is_label {
+                text.push_str(&line);
+            } else {
+                text.push('\t');
+                text.push_str(&line);
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// every Rust function in a `.s` file, in the order they were emitted
+#[derive(Clone, Debug, Default)]
+pub struct Listing {
+    functions: Vec<Function>,
+}
+
+impl Listing {
+    /// split an assembly file into functions
+    ///
+    /// A function starts at a label that demangles as a Rust symbol and ends
+    /// at the first of `.Lfunc_endN:` (ELF), `.cfi_endproc` (Mach-O, where
+    /// there is no end label), `end_function` (wasm) or the next function.
+    pub fn parse(asm: &str) -> Listing {
+        let mut functions = Vec::new();
+        let mut aliases = Vec::new();
+        let mut current: Option<Function> = None;
+
+        for line in asm.lines() {
+            if let Some((symbol, target)) = alias(line) {
+                functions.extend(current.take());
+                aliases.push((symbol, target));
+            } else if let Some(symbol) = label(line) {
+                functions.extend(current.take());
+                current = Some(Function {
+                    name: demangle(symbol),
+                    symbol: symbol.to_string(),
+                    body: Vec::new(),
+                    alias_of: None,
+                });
+            } else if let Some(function) = current.as_mut() {
+                if ends_function(line) {
+                    functions.extend(current.take());
+                } else if !line.trim().is_empty() {
+                    function.body.push(line.to_string());
+                }
+            }
+        }
+        functions.extend(current);
+
+        for (symbol, target) in aliases {
+            let body = functions
+                .iter()
+                .find(|function| function.symbol == target)
+                .map(|function| function.body.clone())
+                .unwrap_or_default();
+            functions.push(Function {
+                name: demangle(symbol),
+                symbol: symbol.to_string(),
+                body,
+                alias_of: Some(demangle(target)),
+            });
+        }
+        Listing { functions }
+    }
+
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// look up a function by its demangled path
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+}
+
+fn demangle(symbol: &str) -> String {
+    format!("{:#}", rustc_demangle::demangle(symbol))
+}
+
+fn is_rust_symbol(symbol: &str) -> bool {
+    try_demangle(symbol).is_ok()
+}
+
+fn strip_comment(line: &str) -> &str {
+    // `# ` on x86 and wasm, `;` on Mach-O arm64, `//` on aarch64 ELF and
+    // RISC-V; a bare `#` is an arm64 immediate, not a comment
+    let end = ["# ", ";", "//"]
+        .iter()
+        .filter_map(|marker| line.find(marker))
+        .min()
+        .unwrap_or(line.len());
+    let line = &line[..end];
+    if line.trim_start().starts_with('#') {
+        ""
+    } else {
+        line
+    }
+}
+
+fn label(line: &str) -> Option<&str> {
+    if line.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let symbol = strip_comment(line).trim_end().strip_suffix(':')?;
+    is_rust_symbol(symbol).then_some(symbol)
+}
+
+fn alias(line: &str) -> Option<(&str, &str)> {
+    let line = strip_comment(line).trim();
+    let (symbol, target) = match line.strip_prefix(".set") {
+        Some(rest) => rest.split_once(',')?,
+        None => line.split_once('=')?,
+    };
+    let (symbol, target) = (symbol.trim(), target.trim());
+    (is_rust_symbol(symbol) && is_rust_symbol(target)).then_some((symbol, target))
+}
+
+fn ends_function(line: &str) -> bool {
+    let line = line.trim();
+    line == ".cfi_endproc"
+        || line == "end_function"
+        || (line.ends_with(':') && line.trim_start_matches('.').starts_with("Lfunc_end"))
+}
+
+fn is_symbol_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '$')
+}
+
+fn is_local_label(token: &str) -> bool {
+    let name = token.strip_prefix('.').unwrap_or(token);
+    name.strip_prefix('L')
+        .or_else(|| name.strip_prefix("ltmp"))
+        .is_some_and(|rest| rest.ends_with(|c: char| c.is_ascii_digit()))
+}
+
+fn normalize_symbols(line: &str, labels: &mut Vec<String>) -> String {
+    let mut normalized = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(is_symbol_char) {
+        normalized.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest.find(|c| !is_symbol_char(c)).unwrap_or(rest.len());
+        let token = &rest[..end];
+        if is_local_label(token) {
+            let index = match labels.iter().position(|label| label == token) {
+                Some(index) => index,
+                None => {
+                    labels.push(token.to_string());
+                    labels.len() - 1
+                }
+            };
+            normalized.push_str(&format!(".L{index}"));
+        } else if is_rust_symbol(token) {
+            normalized.push_str(&demangle(token));
+        } else {
+            normalized.push_str(token);
+        }
+        rest = &rest[end..];
+    }
+    normalized.push_str(rest);
+    normalized
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+
+use rustc_demangle::try_demangle;
+
+use crate::{Error, Listing, Result, Toolchain};
+
+/// a fenced code block in a markdown document
+#[derive(Clone, Debug)]
+pub struct Block {
+    /// the info string after the opening fence, e.g. `asm`
+    pub info: String,
+    /// index of the first line inside the fence
+    pub start: usize,
+    /// index of the closing fence
+    pub end: usize,
+}
+
+/// every fenced code block in `markdown`, in order
+pub fn blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut open: Option<(String, usize)> = None;
+    for (i, line) in markdown.lines().enumerate() {
+        let Some(info) = line.trim_start().strip_prefix("```") else {
+            continue;
+        };
+        match open.take() {
+            None => open = Some((info.trim().to_string(), i + 1)),
+            Some((info, start)) => blocks.push(Block {
+                info,
+                start,
+                end: i,
+            }),
+        }
+    }
+    blocks
+}
+
+/// the mangled symbol an ```` ```asm ```` block is labelled with, if any
+///
+/// The label is either the function's own, `symbol:`, or the line that makes
+/// the symbol an alias of an identical function, `symbol = other`.
+pub fn label(lines: &[&str]) -> Option<String> {
+    let first = lines.first()?.trim();
+    let symbol = match first.split_once('=') {
+        Some((symbol, _)) => symbol.trim(),
+        None => first.strip_suffix(':')?,
+    };
+    try_demangle(symbol).ok()?;
+    Some(symbol.to_string())
+}
+
+/// an ```` ```asm ```` block whose label names a different function than the
+/// Rust block above it defines
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mislabel {
+    /// 1-based line of the label in the markdown
+    pub line: usize,
+    /// the label's demangled name, without the crate path
+    pub label: String,
+    /// the last function in the closest ```` ```rust ```` block above, if any
+    pub function: Option<String>,
+}
+
+/// every labelled ```` ```asm ```` block in `markdown` that does not show the
+/// function defined right above it
+///
+/// Listings are copied out of a `.s` file by hand, so a block can end up under
+/// the wrong function; the mangled label is the only place that says which
+/// function it really is.
+pub fn check(markdown: &str) -> Vec<Mislabel> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut function = None;
+    let mut mislabels = Vec::new();
+    for block in blocks(markdown) {
+        let contents = &lines[block.start..block.end];
+        if block.info == "rust" {
+            function = contents.iter().rev().find_map(|line| defined(line));
+            continue;
+        }
+        if block.info != "asm" {
+            continue;
+        }
+        let Some(symbol) = label(contents) else {
+            continue;
+        };
+        let path = format!("{:#}", rustc_demangle::demangle(&symbol));
+        let name = path.rsplit("::").next().unwrap_or(&path);
+        if function.as_deref() != Some(name) {
+            mislabels.push(Mislabel {
+                line: block.start + 1,
+                label: name.to_string(),
+                function: function.clone(),
+            });
+        }
+    }
+    mislabels
+}
+
+impl fmt::Display for Mislabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.function {
+            Some(function) => write!(
+                f,
+                "line {}: listing of `{}` shown under `{function}`",
+                self.line, self.label
+            ),
+            None => write!(
+                f,
+                "line {}: listing of `{}` has no Rust function above it",
+                self.line, self.label
+            ),
+        }
+    }
+}
+
+// the name of the function a line of Rust starts defining, if it does
+fn defined(line: &str) -> Option<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let at = words.iter().position(|word| *word == "fn")?;
+    let qualified = words[..at]
+        .iter()
+        .all(|word| word.starts_with("pub") || matches!(*word, "const" | "async" | "unsafe"));
+    if !qualified {
+        return None;
+    }
+    let name = words.get(at + 1)?;
+    let end = name
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(name.len());
+    Some(name[..end].to_string())
+}
+
+/// rewrite `markdown` with freshly emitted assembly
+///
+/// Every ```` ```asm ```` block that starts with a Rust symbol's label is
+/// replaced by that function from `listing`, looked up by demangled name so
+/// the symbol hash and platform prefix do not matter. A function the compiler
+/// merged into another is replaced by its alias line, `symbol = other`, as
+/// the Mach-O assembler writes it, since it has no code of its own. Blocks
+/// without a label, like a two-line excerpt, are left alone.
+///
+/// The untyped block holding the output of `cargo --version` gets
+/// `toolchain`'s version.
+pub fn regenerate(markdown: &str, listing: &Listing, toolchain: &Toolchain) -> Result<String> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut copied = 0;
+    for block in blocks(markdown) {
+        let contents = &lines[block.start..block.end];
+        let replacement = if block.info == "asm" {
+            match label(contents) {
+                Some(symbol) => Some(function(listing, &symbol)?),
+                None => None,
+            }
+        } else if block.info.is_empty() && is_version_header(contents) {
+            Some(version_header(contents, toolchain))
+        } else {
+            None
+        };
+        if let Some(replacement) = replacement {
+            output.extend(
+                lines[copied..block.start]
+                    .iter()
+                    .map(|line| line.to_string()),
+            );
+            output.extend(replacement);
+            copied = block.end;
+        }
+    }
+    output.extend(lines[copied..].iter().map(|line| line.to_string()));
+
+    let mut regenerated = output.join("\n");
+    if markdown.ends_with('\n') {
+        regenerated.push('\n');
+    }
+    Ok(regenerated)
+}
+
+fn function(listing: &Listing, symbol: &str) -> Result<Vec<String>> {
+    let name = format!("{:#}", rustc_demangle::demangle(symbol));
+    let function = listing
+        .function(&name)
+        .ok_or(Error::MissingFunction(name))?;
+    if let Some(target) = &function.alias_of {
+        let target = listing
+            .function(target)
+            .ok_or_else(|| Error::MissingFunction(target.clone()))?;
+        return Ok(vec![format!("{} = {}", function.symbol, target.symbol)]);
+    }
+    let mut lines = vec![format!("{}:", function.symbol)];
+    lines.extend(function.body.iter().cloned());
+    // ELF listings end at `.Lfunc_end`, before the matching `.cfi_endproc`
+    let cfi = function
+        .body
+        .iter()
+        .any(|line| line.trim() == ".cfi_startproc");
+    if cfi {
+        lines.push("\t.cfi_endproc".to_string());
+    }
+    Ok(lines)
+}
+
+// the block the article fills with `cargo --version` output
+fn is_version_header(lines: &[&str]) -> bool {
+    lines.first().is_some_and(|line| line.starts_with("cargo "))
+}
+
+fn version_header(lines: &[&str], toolchain: &Toolchain) -> Vec<String> {
+    let mut header = vec![toolchain.cargo.clone()];
+    header.extend(lines[1..].iter().map(|line| line.to_string()));
+    header
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{Error, Function, Listing, Result, Toolchain};
+
+/// the assembly of a chosen set of functions, and the compiler that emitted it
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Report {
+    pub toolchain: Toolchain,
+    /// the triple the assembly is for; the toolchain's host unless it was
+    /// cross-compiled
+    pub target: String,
+    pub functions: Vec<Extract>,
+}
+
+/// one function's assembly, as it appears in a [`Report`]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Extract {
+    /// the name the function was asked for by, relative to its crate
+    pub name: String,
+    pub function: Function,
+    /// `function.body` without directives, labels or comments
+    pub instructions: Vec<String>,
+}
+
+impl Report {
+    /// pull `names` (paths relative to `crate_name`) out of `listing`, which
+    /// was compiled for the toolchain's host
+    pub fn extract<'a>(
+        toolchain: Toolchain,
+        listing: &Listing,
+        crate_name: &str,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Report> {
+        let functions = names
+            .into_iter()
+            .map(|name| {
+                let path = format!("{crate_name}::{name}");
+                let function = listing
+                    .function(&path)
+                    .ok_or(Error::MissingFunction(path))?
+                    .clone();
+                let instructions = function.instructions().map(str::to_string).collect();
+                Ok(Extract {
+                    name: name.to_string(),
+                    function,
+                    instructions,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Report {
+            target: toolchain.host.clone(),
+            toolchain,
+            functions,
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# {}", self.target)?;
+        writeln!(f, "# {}", self.toolchain.rustc)?;
+        writeln!(f, "# {}", self.toolchain.cargo)?;
+        for extract in &self.functions {
+            writeln!(f)?;
+            writeln!(f, "## {}", extract.name)?;
+            if let Some(target) = &extract.function.alias_of {
+                writeln!(f, "# same code as {target}")?;
+            }
+            writeln!(f, "{}:", extract.function.symbol)?;
+            for line in &extract.function.body {
+                writeln!(f, "{line}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,259 @@
+use records_asm::{Arch, Diff, Function, Line, Similarity};
+
+fn function(name: &str, body: &str) -> Function {
+    Function {
+        name: format!("records_in_rust::{name}"),
+        symbol: name.to_string(),
+        body: body.lines().map(str::to_string).collect(),
+        alias_of: None,
+    }
+}
+
+const WITH_PTRS: &str = "\
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	add	w8, w8, #1
+	add	w9, w8, w9
+	stp	w9, w8, [x0]
+	eor	w9, w10, #0x1
+	strb	w9, [x0, #8]
+	ret
+";
+
+const WITH_REFS: &str = "\
+	ldp	w9, w10, [x0]
+	ldrb	w8, [x0, #8]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	strb	w8, [x0, #8]
+	add	w8, w10, w9
+	stp	w8, w9, [x0]
+	ret
+";
+
+fn aarch64(left: &str, right: &str) -> Diff {
+    Diff::new(
+        Arch::Aarch64,
+        &function("left", left),
+        &function("right", right),
+    )
+}
+
+#[test]
+fn triples_name_their_instruction_set() {
+    assert_eq!(Arch::from_triple("x86_64-unknown-linux-gnu"), Arch::X86_64);
+    assert_eq!(Arch::from_triple("aarch64-apple-darwin"), Arch::Aarch64);
+    assert_eq!(
+        Arch::from_triple("riscv64gc-unknown-linux-gnu"),
+        Arch::Riscv64
+    );
+    assert_eq!(Arch::from_triple("wasm32-unknown-unknown"), Arch::Other);
+}
+
+#[test]
+fn a_function_is_identical_to_itself() {
+    let diff = aarch64(WITH_PTRS, WITH_PTRS);
+    assert_eq!(diff.similarity, Similarity::Identical);
+    assert!(diff.renamed.is_empty());
+    assert!(diff.lines.iter().all(|line| matches!(line, Line::Same(_))));
+}
+
+#[test]
+fn other_registers_are_renamed() {
+    let swapped = WITH_PTRS.replace("w8", "w11").replace("w10", "w8");
+    let diff = aarch64(&swapped, WITH_PTRS);
+    assert_eq!(diff.similarity, Similarity::Renamed);
+    assert_eq!(
+        diff.renamed,
+        [
+            ("w11".to_string(), "w8".to_string()),
+            ("w8".to_string(), "w10".to_string())
+        ]
+    );
+    assert!(diff.lines.iter().all(|line| matches!(line, Line::Same(_))));
+}
+
+// the article's "nearly identical code": the byte is toggled and stored
+// before the sum instead of after it, in other registers
+#[test]
+fn independent_instructions_are_reordered() {
+    let diff = aarch64(WITH_PTRS, WITH_REFS);
+    assert_eq!(diff.similarity, Similarity::Reordered);
+    assert!(
+        diff.lines
+            .contains(&Line::MovedFrom("\tstrb\tw8, [x0, #8]".to_string()))
+    );
+    assert!(
+        diff.lines
+            .contains(&Line::MovedTo("\tstrb\tw8, [x0, #8]".to_string()))
+    );
+    assert!(
+        !diff
+            .lines
+            .iter()
+            .any(|line| matches!(line, Line::Left(_) | Line::Right(_)))
+    );
+    // `w9` holds both the sum and the byte, which the right function keeps
+    // in `w8` along with the second field: only `w8` is renamed throughout
+    assert_eq!(diff.renamed, [("w8".to_string(), "w9".to_string())]);
+}
+
+// the caller gets the address back in `rax`, so whatever is left in `rdx`
+// is not returned
+#[test]
+fn a_result_returned_through_memory_is_all_in_memory() {
+    let lenses = function(
+        "lens::update_record_with_lenses_no_refs",
+        "\
+	movq	%rdi, %rax
+	movzbl	8(%rsi), %ecx
+	movl	(%rsi), %edx
+	xorb	$1, %cl
+	incl	%edx
+	movl	4(%rsi), %esi
+	addl	%edx, %esi
+	movl	%esi, (%rdi)
+	movl	%edx, 4(%rdi)
+	movb	%cl, 8(%rdi)
+	retq
+",
+    );
+    let no_refs = function(
+        "update_record_no_refs",
+        "\
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	movzbl	8(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movl	4(%rsi), %esi
+	addl	%ecx, %esi
+	movl	%esi, (%rdi)
+	movl	%ecx, 4(%rdi)
+	movb	%dl, 8(%rdi)
+	retq
+",
+    );
+    let diff = Diff::new(Arch::X86_64, &lenses, &no_refs);
+    assert_eq!(diff.similarity, Similarity::Reordered);
+    assert_eq!(
+        diff.renamed,
+        [
+            ("ecx".to_string(), "edx".to_string()),
+            ("edx".to_string(), "ecx".to_string()),
+            ("cl".to_string(), "dl".to_string()),
+        ]
+    );
+
+    let value = no_refs.body.join("\n").replace("%rdi, %rax", "%rsi, %rax");
+    let value = function("value", &value);
+    assert_eq!(
+        Diff::new(Arch::X86_64, &lenses, &value).similarity,
+        Similarity::Different
+    );
+}
+
+#[test]
+fn overlapping_stores_keep_their_order() {
+    let word_first = "\tstr\tw1, [x0]\n\tstrb\tw2, [x0, #3]\n\tret\n";
+    let byte_first = "\tstrb\tw2, [x0, #3]\n\tstr\tw1, [x0]\n\tret\n";
+    assert_eq!(
+        aarch64(word_first, byte_first).similarity,
+        Similarity::Different
+    );
+
+    let apart = "\tstrb\tw2, [x0, #4]\n\tstr\tw1, [x0]\n\tret\n";
+    let together = "\tstr\tw1, [x0]\n\tstrb\tw2, [x0, #4]\n\tret\n";
+    assert_eq!(aarch64(apart, together).similarity, Similarity::Reordered);
+}
+
+#[test]
+fn loads_do_not_move_past_stores_they_overlap() {
+    let load_first = "\tldr\tw8, [x0, #4]\n\tstr\tw1, [x0, #4]\n\tstr\tw8, [x0]\n\tret\n";
+    let store_first = "\tstr\tw1, [x0, #4]\n\tldr\tw8, [x0, #4]\n\tstr\tw8, [x0]\n\tret\n";
+    assert_eq!(
+        aarch64(load_first, store_first).similarity,
+        Similarity::Different
+    );
+}
+
+#[test]
+fn nothing_moves_past_a_call() {
+    let before = "\tadd\tw19, w19, #1\n\tbl\tfoo\n\tret\n";
+    let after = "\tbl\tfoo\n\tadd\tw19, w19, #1\n\tret\n";
+    assert_eq!(aarch64(before, after).similarity, Similarity::Different);
+}
+
+#[test]
+fn a_different_computation_is_shown_in_the_right_registers() {
+    let shadowed_vars = function(
+        "update_record_with_shadowed_vars",
+        "\
+	movl	(%rdi), %eax
+	incl	%eax
+	movl	4(%rdi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, (%rdi)
+	movl	%eax, 4(%rdi)
+	xorb	$1, 8(%rdi)
+	retq
+",
+    );
+    let mut_tmp_var = function(
+        "update_record_with_mut_tmp_var",
+        "\
+	movl	(%rdi), %eax
+	movzbl	8(%rdi), %ecx
+	notb	%cl
+	andb	$1, %cl
+	incl	%eax
+	movl	4(%rdi), %edx
+	addl	%eax, %edx
+	movl	%edx, (%rdi)
+	movl	%eax, 4(%rdi)
+	movb	%cl, 8(%rdi)
+	retq
+",
+    );
+    let diff = Diff::new(Arch::X86_64, &shadowed_vars, &mut_tmp_var);
+    assert_eq!(diff.similarity, Similarity::Different);
+    assert_eq!(diff.renamed, [("ecx".to_string(), "edx".to_string())]);
+    let changed: Vec<_> = diff
+        .lines
+        .iter()
+        .filter(|line| !matches!(line, Line::Same(_)))
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(
+        changed,
+        [
+            "+\tmovzbl\t8(%rdi), %ecx",
+            "+\tnotb\t%cl",
+            "+\tandb\t$1, %cl",
+            "-\txorb\t$1, 8(%rdi)",
+            "+\tmovb\t%cl, 8(%rdi)",
+        ]
+    );
+    assert!(
+        diff.to_string()
+            .ends_with("different: 1 only on the left, 4 only on the right\n")
+    );
+}
+
+// if the `xor` read its register, the two would start from different inputs
+#[test]
+fn a_zeroing_xor_reads_nothing() {
+    let ecx = function("ecx", "\txorl\t%ecx, %ecx\n\tmovl\t%ecx, (%rdi)\n\tretq\n");
+    let r8d = function("r8d", "\txorl\t%r8d, %r8d\n\tmovl\t%r8d, (%rdi)\n\tretq\n");
+    let diff = Diff::new(Arch::X86_64, &ecx, &r8d);
+    assert_eq!(diff.similarity, Similarity::Renamed);
+    assert_eq!(diff.renamed, [("ecx".to_string(), "r8d".to_string())]);
+}
+
+#[test]
+fn other_instruction_sets_are_compared_line_by_line() {
+    let left = function("left", "\tlocal.get\t0\n\tlocal.get\t1\n\ti32.add\n");
+    let right = function("right", "\tlocal.get\t1\n\tlocal.get\t0\n\ti32.add\n");
+    let diff = Diff::new(Arch::Other, &left, &right);
+    assert_eq!(diff.similarity, Similarity::Different);
+}
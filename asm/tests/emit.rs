@@ -0,0 +1,20 @@
+use records_asm::Profile;
+
+#[test]
+fn profiles_are_named_after_their_settings() {
+    assert_eq!(Profile::new("3").to_string(), "O3");
+    assert_eq!(
+        Profile::new("s").single_codegen_unit(true).to_string(),
+        "Os+cgu1"
+    );
+}
+
+#[test]
+fn every_opt_level_gets_its_own_name() {
+    let mut names: Vec<String> = Profile::OPT_LEVELS
+        .iter()
+        .map(|level| Profile::new(*level).to_string())
+        .collect();
+    names.dedup();
+    assert_eq!(names.len(), Profile::OPT_LEVELS.len());
+}
@@ -0,0 +1,140 @@
+use records_asm::{IrListing, Verdict};
+
+const IR: &str = r#"
+@_ZN15records_in_rust21update_mut_record_mut17hb4cc31dc71d9b522E = unnamed_addr alias void (ptr, ptr), ptr @_ZN15records_in_rust17update_record_mut17h4d123c456c4d42d5E
+
+define void @_ZN15records_in_rust17update_record_mut17h4d123c456c4d42d5E(ptr dead_on_unwind noalias noundef writable writeonly sret([12 x i8]) align 4 captures(none) dereferenceable(12) initializes((0, 12)) %_0, ptr dead_on_return noalias noundef align 4 captures(none) dereferenceable(12) %record) unnamed_addr #1 {
+start:
+  %0 = getelementptr inbounds nuw i8, ptr %record, i64 8
+  %1 = load i8, ptr %0, align 4, !range !150, !noundef !10
+  %2 = xor i8 %1, 1
+  store i8 %2, ptr %0, align 4
+  tail call void @llvm.memcpy.p0.p0.i64(ptr noundef nonnull align 4 dereferenceable(12) %_0, ptr noundef nonnull align 4 dereferenceable(12) %record, i64 12, i1 false)
+  ret void
+}
+
+define void @_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E(ptr dead_on_unwind noalias noundef writable writeonly sret([12 x i8]) align 4 captures(none) dereferenceable(12) initializes((0, 9)) %record, ptr dead_on_return noalias noundef readonly align 4 captures(none) dereferenceable(12) %record1) unnamed_addr #1 {
+start:
+  %_5 = load i32, ptr %record1, align 4, !noundef !10
+  %0 = getelementptr inbounds nuw i8, ptr %record, i64 4
+  store i32 %_5, ptr %0, align 4
+  ret void
+}
+
+define void @_ZN15records_in_rust23update_record_with_refs17h9b3011bdb3c1a37cE(ptr noalias noundef align 4 captures(none) dereferenceable(12) %record) unnamed_addr #1 {
+start:
+  %0 = getelementptr inbounds nuw i8, ptr %record, i64 8
+  %1 = load i8, ptr %0, align 4, !range !150, !noundef !10
+  %2 = xor i8 %1, 1
+  store i8 %2, ptr %0, align 4
+  ret void
+}
+
+define void @_ZN15records_in_rust17update_and_return17h5e1f0b2c3d4a6978E(ptr sret([12 x i8]) align 4 %_0, ptr align 4 %record) unnamed_addr #1 {
+start:
+  %result = alloca [12 x i8], align 4
+  store i32 1, ptr %record, align 4
+  store i32 2, ptr %result, align 4
+  call void @llvm.memcpy.p0.p0.i64(ptr align 4 %_0, ptr align 4 %result, i64 12, i1 false)
+  ret void
+}
+
+define void @_ZN15records_in_rust16update_then_drop17h7a6b5c4d3e2f1089E(ptr align 4 %record) unnamed_addr #1 personality ptr @rust_eh_personality {
+start:
+  invoke void @_ZN15records_in_rust13toggle_record17h1f2e3d4c5b6a7980E(ptr align 4 %record)
+          to label %exit unwind label %cleanup
+
+cleanup:
+  %0 = landingpad { ptr, i32 }
+          cleanup
+  resume { ptr, i32 } %0
+
+exit:
+  ret void
+}
+
+define void @_ZN15records_in_rust3soa11RecordBatch13increment_all17h0c5d3a1f6e2b7c48E(ptr noalias noundef align 8 captures(none) dereferenceable(72) %self) unnamed_addr #1 {
+start:
+  %0 = getelementptr inbounds nuw i8, ptr %self, i64 8
+  %buffer = load ptr, ptr %0, align 8, !nonnull !3, !noundef !3
+  br label %loop
+
+loop:
+  %a = phi ptr [ %buffer, %start ], [ %next, %loop ]
+  %1 = load <4 x i32>, ptr %a, align 4
+  %2 = add <4 x i32> %1, splat (i32 1)
+  store <4 x i32> %2, ptr %a, align 4
+  %next = getelementptr inbounds nuw i8, ptr %a, i64 16
+  %done = icmp eq ptr %next, %buffer
+  br i1 %done, label %exit, label %loop
+
+exit:
+  ret void
+}
+"#;
+
+fn verdict(name: &str) -> Verdict {
+    IrListing::parse(IR)
+        .function(&format!("records_in_rust::{name}"))
+        .unwrap()
+        .analyze()
+        .verdict
+}
+
+#[test]
+fn stores_through_the_argument_are_in_place() {
+    assert_eq!(verdict("update_record_with_refs"), Verdict::InPlace);
+}
+
+#[test]
+fn stores_through_sret_are_a_second_struct() {
+    assert_eq!(verdict("update_record_no_refs"), Verdict::SecondStruct);
+}
+
+#[test]
+fn memcpy_into_sret_after_updating_the_argument_is_a_copy() {
+    let listing = IrListing::parse(IR);
+    let analysis = listing
+        .function("records_in_rust::update_record_mut")
+        .unwrap()
+        .analyze();
+    assert_eq!(analysis.verdict, Verdict::InPlaceThenCopied);
+    assert!(analysis.memcpy);
+}
+
+#[test]
+fn memcpy_into_sret_from_elsewhere_is_not_a_copy_of_the_argument() {
+    let listing = IrListing::parse(IR);
+    let analysis = listing
+        .function("records_in_rust::update_and_return")
+        .unwrap()
+        .analyze();
+    assert_eq!(analysis.verdict, Verdict::Both);
+    assert!(analysis.writes_stack);
+}
+
+#[test]
+fn invoked_functions_are_calls() {
+    let listing = IrListing::parse(IR);
+    let analysis = listing
+        .function("records_in_rust::update_then_drop")
+        .unwrap()
+        .analyze();
+    assert_eq!(analysis.calls, ["records_in_rust::toggle_record"]);
+}
+
+#[test]
+fn aliases_are_analyzed_as_their_target() {
+    assert_eq!(verdict("update_mut_record_mut"), Verdict::InPlaceThenCopied);
+}
+
+#[test]
+fn stores_into_a_buffer_the_argument_owns_are_in_place() {
+    let listing = IrListing::parse(IR);
+    let analysis = listing
+        .function("records_in_rust::soa::RecordBatch::increment_all")
+        .unwrap()
+        .analyze();
+    assert_eq!(analysis.verdict, Verdict::InPlace);
+    assert!(analysis.vectorized);
+}
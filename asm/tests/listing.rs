@@ -0,0 +1,81 @@
+use records_asm::Listing;
+
+const ELF_X86_64: &str = "\
+	.section	.text._ZN15records_in_rust21update_record_no_refs17he370b401918081e9E,\"ax\",@progbits
+	.globl	_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E
+	.p2align	4
+	.type	_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E,@function
+_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E:
+	.cfi_startproc
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	movzbl	8(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movl	4(%rsi), %esi
+	addl	%ecx, %esi
+	movl	%esi, (%rdi)
+	movl	%ecx, 4(%rdi)
+	movb	%dl, 8(%rdi)
+	retq
+.Lfunc_end1:
+	.size	_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E, .Lfunc_end1-_ZN15records_in_rust21update_record_no_refs17he370b401918081e9E
+	.cfi_endproc
+
+	.globl	_ZN15records_in_rust21update_mut_record_mut17hb4cc31dc71d9b522E
+	.type	_ZN15records_in_rust21update_mut_record_mut17hb4cc31dc71d9b522E,@function
+_ZN15records_in_rust21update_mut_record_mut17hb4cc31dc71d9b522E = _ZN15records_in_rust21update_record_no_refs17he370b401918081e9E
+	.ident	\"rustc version 1.95.0 (59807616e 2026-04-14)\"
+";
+
+const MACHO_ARM64: &str = "\
+	.globl	__ZN15records_in_rust23update_record_with_refs17h80c99250a4a3f79fE
+	.p2align	2
+__ZN15records_in_rust23update_record_with_refs17h80c99250a4a3f79fE:
+	.cfi_startproc
+	ldrb	w8, [x0, #8]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #8]
+	ldp	w8, w9, [x0]
+	add	w8, w8, w9
+	add	w8, w8, #1
+	stp	w8, w8, [x0]
+	ret
+	.cfi_endproc
+";
+
+#[test]
+fn elf_functions_end_at_the_func_end_label() {
+    let listing = Listing::parse(ELF_X86_64);
+    let function = listing
+        .function("records_in_rust::update_record_no_refs")
+        .unwrap();
+    assert_eq!(function.body.first().unwrap().trim(), ".cfi_startproc");
+    assert_eq!(function.body.last().unwrap().trim(), "retq");
+    assert_eq!(function.instructions().count(), 11);
+    assert_eq!(function.alias_of, None);
+}
+
+#[test]
+fn aliases_share_the_body_of_their_target() {
+    let listing = Listing::parse(ELF_X86_64);
+    let alias = listing
+        .function("records_in_rust::update_mut_record_mut")
+        .unwrap();
+    assert_eq!(
+        alias.alias_of.as_deref(),
+        Some("records_in_rust::update_record_no_refs")
+    );
+    assert_eq!(alias.instructions().count(), 11);
+}
+
+#[test]
+fn mach_o_arm64_immediates_are_not_comments() {
+    let listing = Listing::parse(MACHO_ARM64);
+    let function = listing
+        .function("records_in_rust::update_record_with_refs")
+        .unwrap();
+    let instructions: Vec<_> = function.instructions().collect();
+    assert_eq!(instructions.len(), 8);
+    assert_eq!(instructions[0], "ldrb\tw8, [x0, #8]");
+}
@@ -0,0 +1,90 @@
+use records_asm::literate::{self, Mislabel};
+use records_asm::{Listing, Toolchain};
+
+const ARTICLE: &str = "\
+```rust
+#[inline(never)]
+pub fn update_record_with_refs(record: &mut Record) {
+    toggle_record(record);
+}
+```
+
+```asm
+__ZN15records_in_rust23update_record_with_refs17h80c99250a4a3f79fE:
+	ret
+```
+
+An excerpt without a label is not checked.
+
+```asm
+mov	w11, #1
+```
+
+```rust
+pub fn update_record_with_shadowed_vars(record: &mut Record) {
+    let tmp = *record;
+    *record = tmp;
+}
+```
+
+```asm
+__ZN15records_in_rust30update_record_with_mut_tmp_var17hb6e407b831178dc9E:
+	ret
+```
+";
+
+#[test]
+fn listing_under_the_wrong_function_is_reported() {
+    assert_eq!(
+        literate::check(ARTICLE),
+        vec![Mislabel {
+            line: 27,
+            label: "update_record_with_mut_tmp_var".to_string(),
+            function: Some("update_record_with_shadowed_vars".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn listing_without_rust_above_it_is_reported() {
+    let article =
+        "```asm\n_ZN15records_in_rust17update_record_mut17h90ee354aab9a591eE:\n\tret\n```\n";
+    let mislabels = literate::check(article);
+    assert_eq!(mislabels.len(), 1);
+    assert_eq!(mislabels[0].function, None);
+}
+
+const MERGED: &str = "\
+__ZN15records_in_rust31update_record_with_minimal_vars17h1bc5b1920fe2a2daE:
+	.cfi_startproc
+	ret
+	.cfi_endproc
+	.globl	__ZN15records_in_rust32update_record_with_shadowed_vars17hfa96001bfb05d1eeE
+__ZN15records_in_rust32update_record_with_shadowed_vars17hfa96001bfb05d1eeE = __ZN15records_in_rust31update_record_with_minimal_vars17h1bc5b1920fe2a2daE
+";
+
+#[test]
+fn merged_function_is_listed_as_its_alias() {
+    let article = "\
+```rust
+pub fn update_record_with_shadowed_vars(record: &mut Record) {
+}
+```
+
+```asm
+__ZN15records_in_rust32update_record_with_shadowed_vars17h0000000000000000E:
+	ret
+```
+";
+    let toolchain = Toolchain {
+        host: "aarch64-apple-darwin".to_string(),
+        rustc: "rustc 1.95.0".to_string(),
+        cargo: "cargo 1.95.0".to_string(),
+    };
+    let regenerated = literate::regenerate(article, &Listing::parse(MERGED), &toolchain).unwrap();
+    assert!(regenerated.contains(
+        "```asm\n__ZN15records_in_rust32update_record_with_shadowed_vars17hfa96001bfb05d1eeE = \
+         __ZN15records_in_rust31update_record_with_minimal_vars17h1bc5b1920fe2a2daE\n```"
+    ));
+    assert_eq!(literate::check(&regenerated), []);
+}
@@ -0,0 +1,85 @@
+//! Time every registered strategy, or the ones named on the command line.
+//!
+//! ```sh
+//! cargo bench --bench strategies -- [--iterations N] [--samples N] [--inputs N]
+//!     [--distribution zero|small|large] [--seed N] [--inlined] [NAME...]
+//! ```
+//!
+//! `--inlined` times the harnesses from `records_in_rust::inlined` instead of
+//! the strategies. The first one timed is the baseline the others are
+//! compared to.
+
+use std::env;
+use std::error::Error;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use records_in_rust::bench::{self, Config, Report};
+use records_in_rust::inlined::HARNESSES;
+use records_in_rust::registry::STRATEGIES;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    match run(env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut config = Config::default();
+    let mut names = Vec::new();
+    let mut candidates = STRATEGIES;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // `cargo bench` passes this to every bench target
+            "--bench" => {}
+            "--iterations" => config.iterations = value(&arg, args.next())?,
+            "--samples" => config.samples = value(&arg, args.next())?,
+            "--inputs" => config.inputs = value(&arg, args.next())?,
+            "--distribution" => config.distribution = value(&arg, args.next())?,
+            "--seed" => config.seed = value(&arg, args.next())?,
+            "--inlined" => candidates = HARNESSES,
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`").into()),
+            _ => names.push(arg),
+        }
+    }
+    if config.iterations == 0 || config.samples == 0 || config.inputs == 0 {
+        return Err("--iterations, --samples and --inputs must be positive".into());
+    }
+
+    let strategies = if names.is_empty() {
+        candidates.iter().collect()
+    } else {
+        names
+            .iter()
+            .map(|name| {
+                let found = candidates.iter().find(|strategy| strategy.name == name);
+                found.ok_or(format!("no strategy named `{name}`"))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?
+    };
+    let measurements = bench::run(&strategies, &config);
+    print!(
+        "{}",
+        Report {
+            config: &config,
+            measurements: &measurements,
+        }
+    );
+    Ok(())
+}
+
+/// the value following `flag`
+fn value<T>(flag: &str, value: Option<String>) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Error + 'static,
+{
+    let value = value.ok_or(format!("`{flag}` needs a value"))?;
+    Ok(value.parse()?)
+}
@@ -1,3 +1,5 @@
 extern crate tango;
 
-fn main() { tango::process_root().unwrap() }
+fn main() {
+    tango::process_root().unwrap()
+}
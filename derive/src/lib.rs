@@ -0,0 +1,135 @@
+//! `#[derive(Functional)]`: the article's functional updaters for any struct.
+//!
+//! The article updates a `Record` by building a new one with struct update
+//! syntax:
+//!
+//! ```text
+//! fn get_toggled_record(record: Record) -> Record {
+//!     Record {
+//!         c: !record.c,
+//!         ..record
+//!     }
+//! }
+//! ```
+//!
+//! and shows that this compiles to the same code as assigning the field in
+//! place. Deriving `Functional` writes that kind of function for every field
+//! of a struct with named fields. For a field `a: T` it generates
+//!
+//! * `with_a(self, a: T) -> Self`, replacing the field,
+//! * `map_a(self, f: impl FnOnce(T) -> T) -> Self`, replacing the field with a
+//!   function of its old value,
+//! * `set_a(&mut self, a: T) -> &mut Self` and
+//!   `modify_a(&mut self, f: impl FnOnce(&mut T)) -> &mut Self`, their in-place
+//!   counterparts, which return the struct again so they can be chained.
+//!
+//! The methods have the visibility of the struct, not of the field: deriving
+//! `Functional` is a decision to let the struct's users update every field.
+//! Mark a field `#[functional(skip)]` to leave it out.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Field, Fields, parse_macro_input};
+
+#[proc_macro_derive(Functional, attributes(functional))]
+pub fn derive_functional(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "`Functional` needs a struct with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`Functional` can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut methods = Vec::new();
+    for field in fields {
+        if !skipped(field)? {
+            methods.push(updaters(&input.vis, field));
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+fn updaters(vis: &syn::Visibility, field: &Field) -> TokenStream2 {
+    let name = field.ident.as_ref().expect("named field");
+    let ty = &field.ty;
+    // `r#type` becomes `with_type`, not `with_r#type`
+    let bare = name.to_string();
+    let bare = bare.trim_start_matches("r#");
+    let with = format_ident!("with_{bare}");
+    let map = format_ident!("map_{bare}");
+    let set = format_ident!("set_{bare}");
+    let modify = format_ident!("modify_{bare}");
+    quote! {
+        #[inline]
+        #[must_use]
+        #vis fn #with(self, #name: #ty) -> Self {
+            Self { #name, ..self }
+        }
+
+        #[inline]
+        #[must_use]
+        #vis fn #map(self, f: impl FnOnce(#ty) -> #ty) -> Self {
+            Self {
+                #name: f(self.#name),
+                ..self
+            }
+        }
+
+        #[inline]
+        #vis fn #set(&mut self, #name: #ty) -> &mut Self {
+            self.#name = #name;
+            self
+        }
+
+        #[inline]
+        #vis fn #modify(&mut self, f: impl FnOnce(&mut #ty)) -> &mut Self {
+            f(&mut self.#name);
+            self
+        }
+    }
+}
+
+// `#[functional(skip)]`
+fn skipped(field: &Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("functional") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
@@ -0,0 +1,76 @@
+use records_derive::Functional;
+
+#[derive(Functional, Copy, Clone, Debug, PartialEq)]
+struct Record {
+    a: u32,
+    b: u32,
+    c: bool,
+}
+
+const RECORD: Record = Record {
+    a: 1,
+    b: 2,
+    c: false,
+};
+
+#[test]
+fn with_replaces_one_field() {
+    assert_eq!(
+        RECORD.with_b(7),
+        Record {
+            a: 1,
+            b: 7,
+            c: false
+        }
+    );
+}
+
+#[test]
+fn map_matches_the_article_s_get_toggled_record() {
+    let toggled = Record {
+        c: !RECORD.c,
+        ..RECORD
+    };
+    assert_eq!(RECORD.map_c(|c| !c), toggled);
+}
+
+#[test]
+fn mutable_counterparts_chain() {
+    let mut record = RECORD;
+    record
+        .set_a(10)
+        .modify_b(|b| *b += 1)
+        .modify_c(|c| *c = !*c);
+    assert_eq!(
+        record,
+        Record {
+            a: 10,
+            b: 3,
+            c: true
+        }
+    );
+}
+
+#[derive(Functional, Debug, PartialEq)]
+struct Named<T: Clone> {
+    name: String,
+    tags: Vec<T>,
+    #[functional(skip)]
+    #[allow(dead_code)]
+    id: u64,
+}
+
+#[test]
+fn works_for_generic_structs_that_are_not_copy() {
+    let named = Named {
+        name: "a".to_string(),
+        tags: vec![1],
+        id: 3,
+    };
+    let named = named.map_name(|name| name + "b").map_tags(|mut tags| {
+        tags.push(2);
+        tags
+    });
+    assert_eq!(named.name, "ab");
+    assert_eq!(named.tags, [1, 2]);
+}
@@ -0,0 +1,152 @@
+//@ # Updating many records at once
+//@
+//@ The article updates one record per call, behind `#[inline(never)]`, so the
+//@ assembly shows exactly one update. Real code updates a whole slice of
+//@ records in a loop, and in a loop the compiler has a new option: process
+//@ several records per instruction with SIMD. If the functional style hides
+//@ the memory layout from the optimizer anywhere, this is where it would show.
+//@
+//@ Every strategy from the article gets two batch versions here:
+//@
+//@ * `update_all_*(&mut [Record])` updates a slice in place,
+//@ * `map_all_*(Vec<Record>) -> Vec<Record>` consumes a vector and returns the
+//@   updated one. `into_iter().map().collect()` reuses the allocation, so this
+//@   is also an in-place loop underneath.
+//@
+//@ The per-record step of each is the body of the strategy it is named after.
+//@ The strategies themselves cannot be called in the loop: they are
+//@ `#[inline(never)]`, and a call per record would rule out vectorizing.
+//@ [`inlined`](crate::inlined) uses the same steps in other contexts.
+
+use crate::{
+    Record, accumulate_record, get_accumulated_record, get_incremented_record, get_toggled_record,
+    increment_record, toggle_record,
+};
+
+pub(crate) fn with_refs(record: &mut Record) {
+    toggle_record(record);
+    increment_record(record);
+    accumulate_record(record);
+}
+
+pub(crate) fn with_ptrs(record: &mut Record) {
+    *record = get_toggled_record(*record);
+    *record = get_incremented_record(*record);
+    *record = get_accumulated_record(*record);
+}
+
+pub(crate) fn with_minimal_vars(record: &mut Record) {
+    *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
+}
+
+pub(crate) fn with_shadowed_vars(record: &mut Record) {
+    let tmp = *record;
+    let tmp = get_toggled_record(tmp);
+    let tmp = get_incremented_record(tmp);
+    let tmp = get_accumulated_record(tmp);
+    *record = tmp;
+}
+
+pub(crate) fn with_mut_tmp_var(record: &mut Record) {
+    let mut tmp = *record;
+    tmp = get_toggled_record(tmp);
+    tmp = get_incremented_record(tmp);
+    tmp = get_accumulated_record(tmp);
+    *record = tmp;
+}
+
+pub(crate) fn no_refs(record: Record) -> Record {
+    let mut record = get_toggled_record(record);
+    record = get_incremented_record(record);
+    record = get_accumulated_record(record);
+    record
+}
+
+pub(crate) fn record_mut(record: Record) -> Record {
+    let mut record = record;
+    toggle_record(&mut record);
+    increment_record(&mut record);
+    accumulate_record(&mut record);
+    record
+}
+
+pub(crate) fn mut_record_mut(mut record: Record) -> Record {
+    toggle_record(&mut record);
+    increment_record(&mut record);
+    accumulate_record(&mut record);
+    record
+}
+
+//@ ## The loops
+//@
+//@ An in-place step is lifted into `map_all` by updating the record it was
+//@ given and returning it; a by-value step is lifted into `update_all` by
+//@ assigning its result back through the reference.
+
+macro_rules! batches {
+    ($($strategy:literal: $kind:ident $step:ident => $update_all:ident, $map_all:ident;)*) => {
+        $(
+            #[inline(never)]
+            pub fn $update_all(records: &mut [Record]) {
+                for record in records {
+                    batches!(@update $kind $step record);
+                }
+            }
+
+            #[inline(never)]
+            pub fn $map_all(records: Vec<Record>) -> Vec<Record> {
+                records
+                    .into_iter()
+                    .map(|record| batches!(@map $kind $step record))
+                    .collect()
+            }
+        )*
+
+        /// the batch versions of every strategy, in registry order
+        pub static BATCHES: &[Batch] = &[
+            $(
+                Batch {
+                    strategy: $strategy,
+                    update_all_name: concat!("batch::", stringify!($update_all)),
+                    update_all: $update_all,
+                    map_all_name: concat!("batch::", stringify!($map_all)),
+                    map_all: $map_all,
+                },
+            )*
+        ];
+    };
+    (@update in_place $step:ident $record:ident) => { $step($record) };
+    (@update by_value $step:ident $record:ident) => { *$record = $step(*$record) };
+    (@map in_place $step:ident $record:ident) => {{
+        let mut record = $record;
+        $step(&mut record);
+        record
+    }};
+    (@map by_value $step:ident $record:ident) => { $step($record) };
+}
+
+/// the two batch versions of one strategy
+pub struct Batch {
+    /// the [registry](crate::registry) name of the single-record strategy
+    pub strategy: &'static str,
+    /// the path of `update_all` in the crate
+    pub update_all_name: &'static str,
+    pub update_all: fn(&mut [Record]),
+    /// the path of `map_all` in the crate
+    pub map_all_name: &'static str,
+    pub map_all: fn(Vec<Record>) -> Vec<Record>,
+}
+
+batches! {
+    "update_record_with_refs": in_place with_refs => update_all_with_refs, map_all_with_refs;
+    "update_record_with_ptrs": in_place with_ptrs => update_all_with_ptrs, map_all_with_ptrs;
+    "update_record_with_minimal_vars":
+        in_place with_minimal_vars => update_all_with_minimal_vars, map_all_with_minimal_vars;
+    "update_record_with_shadowed_vars":
+        in_place with_shadowed_vars => update_all_with_shadowed_vars, map_all_with_shadowed_vars;
+    "update_record_with_mut_tmp_var":
+        in_place with_mut_tmp_var => update_all_with_mut_tmp_var, map_all_with_mut_tmp_var;
+    "update_record_no_refs": by_value no_refs => update_all_no_refs, map_all_no_refs;
+    "update_record_mut": by_value record_mut => update_all_mut, map_all_mut;
+    "update_mut_record_mut": by_value mut_record_mut => update_all_mut_record_mut, map_all_mut_record_mut;
+}
@@ -0,0 +1,318 @@
+//@ # Timing the strategies
+//@
+//@ Reading the assembly says what the compiler did; it does not say whether
+//@ it matters. This module times each strategy, and `cargo bench` runs it over
+//@ every registered one:
+//@
+//@ ```sh
+//@ cargo bench --bench strategies -- --distribution large --samples 51 update_record_no_refs
+//@ ```
+//@
+//@ Each sample times `iterations` calls, cycling through a fixed set of
+//@ generated inputs, and records the mean nanoseconds per call. Every input
+//@ and every result goes through [`black_box`], so the optimizer can neither
+//@ precompute the calls nor drop them. The strategies take turns sample by
+//@ sample, so a change in clock speed halfway through a run hits them all
+//@ alike.
+
+use std::error::Error;
+use std::fmt;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::Instant;
+
+use crate::Record;
+use crate::equivalence::{MAX_FIELD, SplitMix64};
+use crate::registry::Strategy;
+
+//@ ## Inputs
+//@
+//@ The fields the strategies add are plain `u32`s, so the values should not
+//@ matter to the timing. The distributions are there to check that.
+
+/// where the fields of the generated records come from
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Distribution {
+    /// every record is `(0, 0, false)`
+    Zero,
+    /// `a` and `b` at most 255
+    #[default]
+    Small,
+    /// `a` and `b` up to [`MAX_FIELD`], the largest that cannot overflow
+    Large,
+}
+
+impl Distribution {
+    /// `count` records drawn from this distribution
+    pub fn records(self, count: usize, seed: u64) -> Vec<Record> {
+        let max_field = match self {
+            Distribution::Zero => return vec![Record::default(); count],
+            Distribution::Small => 255,
+            Distribution::Large => MAX_FIELD,
+        };
+        let mut rng = SplitMix64 {
+            state: seed,
+            max_field,
+        };
+        (0..count).map(|_| rng.record()).collect()
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Distribution::Zero => "zero",
+            Distribution::Small => "small",
+            Distribution::Large => "large",
+        })
+    }
+}
+
+/// a distribution name that is not `zero`, `small` or `large`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseDistributionError {
+    pub name: String,
+}
+
+impl fmt::Display for ParseDistributionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown distribution `{}`; expected `zero`, `small` or `large`",
+            self.name
+        )
+    }
+}
+
+impl Error for ParseDistributionError {}
+
+impl FromStr for Distribution {
+    type Err = ParseDistributionError;
+
+    fn from_str(s: &str) -> Result<Distribution, ParseDistributionError> {
+        match s.trim() {
+            "zero" => Ok(Distribution::Zero),
+            "small" => Ok(Distribution::Small),
+            "large" => Ok(Distribution::Large),
+            name => Err(ParseDistributionError {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+//@ ## Measuring
+
+/// how long and on what to time each strategy
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// calls per sample
+    pub iterations: u64,
+    /// samples per strategy
+    pub samples: usize,
+    /// distinct records the calls cycle through
+    pub inputs: usize,
+    pub distribution: Distribution,
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            iterations: 100_000,
+            samples: 31,
+            inputs: 1024,
+            distribution: Distribution::default(),
+            seed: 0,
+        }
+    }
+}
+
+/// the samples taken of one strategy
+#[derive(Clone, Debug)]
+pub struct Measurement {
+    pub name: &'static str,
+    /// mean nanoseconds per call, one per sample, in the order taken
+    pub samples: Vec<f64>,
+}
+
+impl Measurement {
+    pub fn summary(&self) -> Summary {
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(f64::total_cmp);
+        Summary {
+            p5: percentile(&sorted, 0.05),
+            median: percentile(&sorted, 0.5),
+            p95: percentile(&sorted, 0.95),
+        }
+    }
+}
+
+/// nanoseconds per call at three percentiles of a [`Measurement`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub p5: f64,
+    pub median: f64,
+    pub p95: f64,
+}
+
+/// the `p`th quantile of `sorted`, interpolating between neighbours
+///
+/// `p` is between 0 and 1, and `sorted` must not be empty.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64)
+}
+
+/// mean nanoseconds per call of `strategy` over `iterations` calls
+pub fn time(strategy: &Strategy, inputs: &[Record], iterations: u64) -> f64 {
+    let start = Instant::now();
+    for &record in inputs.iter().cycle().take(iterations as usize) {
+        black_box(strategy.apply(black_box(record)));
+    }
+    start.elapsed().as_nanos() as f64 / iterations as f64
+}
+
+/// time every one of `strategies`, taking turns, after one warm-up sample each
+pub fn run(strategies: &[&Strategy], config: &Config) -> Vec<Measurement> {
+    let inputs = config.distribution.records(config.inputs, config.seed);
+    for strategy in strategies {
+        time(strategy, &inputs, config.iterations);
+    }
+    let mut measurements: Vec<Measurement> = strategies
+        .iter()
+        .map(|strategy| Measurement {
+            name: strategy.name,
+            samples: Vec::with_capacity(config.samples),
+        })
+        .collect();
+    for _ in 0..config.samples {
+        for (strategy, measurement) in strategies.iter().zip(&mut measurements) {
+            let sample = time(strategy, &inputs, config.iterations);
+            measurement.samples.push(sample);
+        }
+    }
+    measurements
+}
+
+//@ ## Comparing
+//@
+//@ Two medians always differ a little. Whether the difference is more than
+//@ noise is decided with a Mann–Whitney U test, which assumes nothing about
+//@ the shape of the distributions: timings have a long tail to the right, so
+//@ a t-test's normality assumption would not hold. With a few dozen samples
+//@ the normal approximation of U is accurate enough.
+
+/// `|z|` above which a difference is significant, two-sided, at p < 0.01
+pub const SIGNIFICANT_Z: f64 = 2.576;
+
+/// how a strategy's samples compare to a baseline's
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// the strategy's median over the baseline's
+    pub ratio: f64,
+    /// the Mann–Whitney U statistic, standardized; positive when the
+    /// strategy is the slower one
+    pub z: f64,
+}
+
+impl Comparison {
+    pub fn is_significant(&self) -> bool {
+        self.z.abs() > SIGNIFICANT_Z
+    }
+}
+
+/// compare `samples` to `baseline`; neither may be empty
+pub fn compare(baseline: &[f64], samples: &[f64]) -> Comparison {
+    let mut all: Vec<(f64, bool)> = baseline
+        .iter()
+        .map(|&sample| (sample, false))
+        .chain(samples.iter().map(|&sample| (sample, true)))
+        .collect();
+    all.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    // tied samples share the mean of the ranks they span
+    let mut rank_sum = 0.0;
+    let mut start = 0;
+    while start < all.len() {
+        let end = start
+            + all[start..]
+                .iter()
+                .take_while(|x| x.0 == all[start].0)
+                .count();
+        let rank = (start + 1 + end) as f64 / 2.0;
+        rank_sum += rank * all[start..end].iter().filter(|x| x.1).count() as f64;
+        start = end;
+    }
+
+    let (n1, n2) = (baseline.len() as f64, samples.len() as f64);
+    let u = rank_sum - n2 * (n2 + 1.0) / 2.0;
+    let sd = (n1 * n2 * (n1 + n2 + 1.0) / 12.0).sqrt();
+    let z = if sd > 0.0 {
+        (u - n1 * n2 / 2.0) / sd
+    } else {
+        0.0
+    };
+
+    let median = |samples: &[f64]| {
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        percentile(&sorted, 0.5)
+    };
+    Comparison {
+        ratio: median(samples) / median(baseline),
+        z,
+    }
+}
+
+//@ ## Reporting
+
+/// a table of [`Measurement`]s, each compared to the first
+pub struct Report<'a> {
+    pub config: &'a Config,
+    pub measurements: &'a [Measurement],
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(baseline) = self.measurements.first() else {
+            return Ok(());
+        };
+        writeln!(
+            f,
+            "{} inputs, {} samples of {} calls, ns per call",
+            self.config.distribution, self.config.samples, self.config.iterations
+        )?;
+        let width = self.measurements.iter().map(|m| m.name.len()).max();
+        let width = width.unwrap_or_default();
+        writeln!(
+            f,
+            "{:<width$} {:>8} {:>8} {:>8}  vs {}",
+            "strategy", "p5", "median", "p95", baseline.name
+        )?;
+        for measurement in self.measurements {
+            let summary = measurement.summary();
+            write!(
+                f,
+                "{:<width$} {:>8.3} {:>8.3} {:>8.3}",
+                measurement.name, summary.p5, summary.median, summary.p95
+            )?;
+            if measurement.name != baseline.name {
+                let comparison = compare(&baseline.samples, &measurement.samples);
+                let verdict = if comparison.is_significant() {
+                    "significant"
+                } else {
+                    "noise"
+                };
+                write!(
+                    f,
+                    "  {:.3}× (z = {:+.2}, {verdict})",
+                    comparison.ratio, comparison.z
+                )?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
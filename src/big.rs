@@ -0,0 +1,182 @@
+//@ # Bigger records
+//@
+//@ The article concludes that the functional style is free, on a struct of
+//@ twelve bytes. Twelve bytes travel in registers, so there is nothing to
+//@ copy in the first place. Somewhere between that and a struct of kilobytes,
+//@ passing a record by value has to start going through memory, and the
+//@ question is whether the compiler still updates it in place when it does.
+//@
+//@ [`BigRecord`] is the article's `Record` with an `[u64; N]` payload beside
+//@ `a`, `b` and `c`. The operations never touch the payload; it only has to
+//@ come along. The strategies mirror the two by-value ones from the article,
+//@ `update_record_no_refs` and `update_mut_record_mut`.
+
+use crate::Record;
+
+/// the article's record, carrying `N` more `u64`s
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigRecord<const N: usize> {
+    a: u32,
+    b: u32,
+    c: bool,
+    payload: [u64; N],
+}
+
+impl<const N: usize> BigRecord<N> {
+    pub const fn new(a: u32, b: u32, c: bool, payload: [u64; N]) -> BigRecord<N> {
+        BigRecord { a, b, c, payload }
+    }
+
+    pub const fn a(&self) -> u32 {
+        self.a
+    }
+
+    pub const fn b(&self) -> u32 {
+        self.b
+    }
+
+    pub const fn c(&self) -> bool {
+        self.c
+    }
+
+    pub const fn payload(&self) -> &[u64; N] {
+        &self.payload
+    }
+}
+
+/// a record with an all-zero payload
+impl<const N: usize> From<Record> for BigRecord<N> {
+    fn from(record: Record) -> BigRecord<N> {
+        BigRecord::new(record.a(), record.b(), record.c(), [0; N])
+    }
+}
+
+/// drops the payload
+impl<const N: usize> From<BigRecord<N>> for Record {
+    fn from(record: BigRecord<N>) -> Record {
+        Record::new(record.a, record.b, record.c)
+    }
+}
+
+//@ ## Operations
+
+pub fn toggle_record<const N: usize>(record: &mut BigRecord<N>) {
+    record.c = !record.c;
+}
+
+pub fn increment_record<const N: usize>(record: &mut BigRecord<N>) {
+    record.a = record.a + 1;
+}
+
+pub fn accumulate_record<const N: usize>(record: &mut BigRecord<N>) {
+    let a = record.a;
+    record.a = record.a + record.b;
+    record.b = a;
+}
+
+pub fn get_toggled_record<const N: usize>(record: BigRecord<N>) -> BigRecord<N> {
+    BigRecord {
+        c: !record.c,
+        ..record
+    }
+}
+
+pub fn get_incremented_record<const N: usize>(record: BigRecord<N>) -> BigRecord<N> {
+    BigRecord {
+        a: record.a + 1,
+        ..record
+    }
+}
+
+pub fn get_accumulated_record<const N: usize>(record: BigRecord<N>) -> BigRecord<N> {
+    BigRecord {
+        a: record.a + record.b,
+        b: record.a,
+        ..record
+    }
+}
+
+//@ ## Strategies
+//@
+//@ As in [`generic`](crate::generic), the strategies are `#[inline]` and each
+//@ size below gets its own `#[inline(never)]` copies, which `cargo xtask asm`
+//@ and `cargo xtask ir` include. The payload length doubles from nothing, the
+//@ article's record with a different alignment, up to 128 `u64`s, a kilobyte.
+//@
+//@ On x86_64 both strategies already take the record through memory and
+//@ write a second one with no payload at all, and from a payload of two
+//@ `u64`s the IR copies the untouched part with a `memcpy`. LLVM expands
+//@ small copies into moves, so a `call memcpy` only shows up in the assembly
+//@ of `update_mut_record_mut` from a payload of 16 and of
+//@ `update_record_no_refs` from 32. How many bytes that is depends on the
+//@ target's alignment of `u64`.
+
+#[inline]
+pub fn update_record_no_refs<const N: usize>(record: BigRecord<N>) -> BigRecord<N> {
+    let mut record = get_toggled_record(record);
+    record = get_incremented_record(record);
+    record = get_accumulated_record(record);
+    record
+}
+
+#[inline]
+pub fn update_mut_record_mut<const N: usize>(mut record: BigRecord<N>) -> BigRecord<N> {
+    toggle_record(&mut record);
+    increment_record(&mut record);
+    accumulate_record(&mut record);
+    record
+}
+
+macro_rules! sizes {
+    ($($(#[$doc:meta])* $name:ident: $n:literal;)*) => {
+        $(
+            $(#[$doc])*
+            pub mod $name {
+                pub type BigRecord = super::BigRecord<$n>;
+
+                #[inline(never)]
+                pub fn update_record_no_refs(record: BigRecord) -> BigRecord {
+                    super::update_record_no_refs(record)
+                }
+
+                #[inline(never)]
+                pub fn update_mut_record_mut(record: BigRecord) -> BigRecord {
+                    super::update_mut_record_mut(record)
+                }
+            }
+        )*
+
+        /// the payload length of each size, with the module that holds it, smallest first
+        pub const SIZES: &[(usize, &str)] = &[$(($n, concat!("big::", stringify!($name)))),*];
+
+        /// both strategies at every payload size, so the tooling can show the
+        /// size at which each one starts to copy
+        pub const FUNCTIONS: &[&str] = &[
+            $(
+                concat!("big::", stringify!($name), "::update_record_no_refs"),
+                concat!("big::", stringify!($name), "::update_mut_record_mut"),
+            )*
+        ];
+    };
+}
+
+sizes! {
+    /// no payload
+    n0: 0;
+    /// one `u64`
+    n1: 1;
+    /// two `u64`s
+    n2: 2;
+    /// four `u64`s
+    n4: 4;
+    /// eight `u64`s
+    n8: 8;
+    /// 16 `u64`s
+    n16: 16;
+    /// 32 `u64`s
+    n32: 32;
+    /// 64 `u64`s
+    n64: 64;
+    /// 128 `u64`s
+    n128: 128;
+}
@@ -0,0 +1,154 @@
+//@ # Fast-forwarding an update
+//@
+//@ Each of the three operations is an affine map on the pair `(a, b)`, plus a
+//@ flip of `c`:
+//@
+//@ | operation    | `a`     | `b` | `c`  |
+//@ |--------------|---------|-----|------|
+//@ | `toggle`     | `a`     | `b` | `!c` |
+//@ | `increment`  | `a + 1` | `b` | `c`  |
+//@ | `accumulate` | `a + b` | `a` | `c`  |
+//@
+//@ Affine maps compose into affine maps, so any sequence of these operations,
+//@ however long, collapses into a single [`Affine`]: a 2×2 matrix, an offset,
+//@ and whether `c` flips. Composing a map with itself by repeated squaring
+//@ gives its `n`th power in O(log n) compositions, which is how
+//@ [`fast_forward`] runs `update_record_no_refs` a billion times without
+//@ looping a billion times.
+//@
+//@ All the arithmetic wraps. Multiplication and addition modulo 2³² are still
+//@ associative, so the fused map computes exactly what the
+//@ [`wrapping`](crate::overflow::wrapping) strategies compute one step at a
+//@ time. The article's own strategies would have overflowed long before.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::Record;
+use crate::op::RecordOp;
+
+/// `(a, b) ↦ m·(a, b) + t` and `c ↦ c ^ flip`, modulo 2³²
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Affine {
+    /// row-major: `a' = m[0][0]·a + m[0][1]·b + t[0]`
+    pub m: [[u32; 2]; 2],
+    pub t: [u32; 2],
+    pub flip: bool,
+}
+
+/// a [`RecordOp::Custom`] step, which has no affine form
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NotAffine {
+    /// the step's position in the sequence being fused
+    pub index: usize,
+}
+
+impl fmt::Display for NotAffine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "step {} is a custom operation and cannot be fused",
+            self.index
+        )
+    }
+}
+
+impl Error for NotAffine {}
+
+impl Affine {
+    /// leaves every record unchanged
+    pub const IDENTITY: Affine = Affine {
+        m: [[1, 0], [0, 1]],
+        t: [0, 0],
+        flip: false,
+    };
+
+    pub const TOGGLE: Affine = Affine {
+        flip: true,
+        ..Affine::IDENTITY
+    };
+
+    pub const INCREMENT: Affine = Affine {
+        t: [1, 0],
+        ..Affine::IDENTITY
+    };
+
+    pub const ACCUMULATE: Affine = Affine {
+        m: [[1, 1], [1, 0]],
+        ..Affine::IDENTITY
+    };
+
+    /// the affine form of one step, if it has one
+    pub fn of(op: RecordOp) -> Option<Affine> {
+        match op {
+            RecordOp::Toggle => Some(Affine::TOGGLE),
+            RecordOp::Increment => Some(Affine::INCREMENT),
+            RecordOp::Accumulate => Some(Affine::ACCUMULATE),
+            RecordOp::Custom(_) => None,
+        }
+    }
+
+    /// one map that applies `ops` in order
+    pub fn fuse(ops: impl IntoIterator<Item = RecordOp>) -> Result<Affine, NotAffine> {
+        ops.into_iter()
+            .enumerate()
+            .try_fold(Affine::IDENTITY, |fused, (index, op)| {
+                let step = Affine::of(op).ok_or(NotAffine { index })?;
+                Ok(fused.then(step))
+            })
+    }
+
+    /// this map followed by `next`
+    pub fn then(self, next: Affine) -> Affine {
+        let [[p, q], [r, s]] = next.m;
+        let [[w, x], [y, z]] = self.m;
+        let [t0, t1] = self.t;
+        Affine {
+            m: [
+                [dot(p, w, q, y), dot(p, x, q, z)],
+                [dot(r, w, s, y), dot(r, x, s, z)],
+            ],
+            t: [
+                dot(p, t0, q, t1).wrapping_add(next.t[0]),
+                dot(r, t0, s, t1).wrapping_add(next.t[1]),
+            ],
+            flip: self.flip ^ next.flip,
+        }
+    }
+
+    /// this map applied `n` times in a row
+    pub fn pow(self, mut n: u64) -> Affine {
+        let mut result = Affine::IDENTITY;
+        let mut square = self;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.then(square);
+            }
+            square = square.then(square);
+            n >>= 1;
+        }
+        result
+    }
+
+    pub fn apply(self, record: Record) -> Record {
+        let [[p, q], [r, s]] = self.m;
+        Record {
+            a: dot(p, record.a, q, record.b).wrapping_add(self.t[0]),
+            b: dot(r, record.a, s, record.b).wrapping_add(self.t[1]),
+            c: record.c ^ self.flip,
+        }
+    }
+}
+
+// `x·y + z·w`, wrapping
+fn dot(x: u32, y: u32, z: u32, w: u32) -> u32 {
+    x.wrapping_mul(y).wrapping_add(z.wrapping_mul(w))
+}
+
+/// `record` after `n` rounds of the article's update, with wrapping arithmetic
+pub fn fast_forward(record: Record, n: u64) -> Record {
+    let round = Affine::TOGGLE
+        .then(Affine::INCREMENT)
+        .then(Affine::ACCUMULATE);
+    round.pow(n).apply(record)
+}
@@ -0,0 +1,210 @@
+//@ # Wider fields
+//@
+//@ Two `u32`s and a `bool` fit in a pair of registers, so it is no great
+//@ surprise that the functional strategies cost nothing: a record passed by
+//@ value never has to touch memory at all. The interesting question is what
+//@ happens when it does not fit.
+//@
+//@ The article declares its record as [`GenericRecord`], with the type of `a`
+//@ and `b` left open, and only ever uses the `u32` instance, [`Record`]. Any
+//@ type implementing [`Number`] will do: every primitive integer from `u8` to
+//@ `u128`, signed or not, and [`Wrapping`] of each. With `u64` numbers a
+//@ record is 24 bytes and is returned through memory; with `u128` it is 48.
+//@
+//@ The article's operations are already written for any [`Number`], so the
+//@ strategies below call them as they are, and each strategy is the
+//@ article's, word for word. [`Record::widen`] turns one of the article's
+//@ records into a wider one. The `u32` instance of each strategy compiles to
+//@ the same code as the article's.
+
+use std::num::Wrapping;
+use std::ops::Add;
+
+use crate::{
+    Record, accumulate_record, get_accumulated_record, get_incremented_record, get_toggled_record,
+    increment_record, toggle_record,
+};
+
+/// a type that `a` and `b` can have: it adds, and it has a one
+pub trait Number: Copy + Add<Output = Self> {
+    const ONE: Self;
+}
+
+macro_rules! numbers {
+    ($($t:ty)*) => {
+        $(
+            impl Number for $t {
+                const ONE: $t = 1;
+            }
+
+            impl Number for Wrapping<$t> {
+                const ONE: Wrapping<$t> = Wrapping(1);
+            }
+        )*
+    };
+}
+
+numbers! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+pub use crate::GenericRecord;
+
+impl Record {
+    /// the same record with wider numbers
+    pub fn widen<T: Number + From<u32>>(self) -> GenericRecord<T> {
+        GenericRecord::new(self.a.into(), self.b.into(), self.c)
+    }
+}
+
+//@ ## Strategies
+//@
+//@ A generic function has no machine code of its own, and every instance of
+//@ it has the same path, so the strategies here are `#[inline]` and the
+//@ modules below give each field type its own `#[inline(never)]` copies to
+//@ read. `cargo xtask asm` and `cargo xtask ir` include
+//@ `update_record_with_refs`, `update_record_no_refs` and `update_record_mut`
+//@ for each of them.
+
+#[inline]
+pub fn update_record_with_refs<T: Number>(record: &mut GenericRecord<T>) {
+    toggle_record(record);
+    increment_record(record);
+    accumulate_record(record);
+}
+
+#[inline]
+pub fn update_record_with_ptrs<T: Number>(record: &mut GenericRecord<T>) {
+    *record = get_toggled_record(*record);
+    *record = get_incremented_record(*record);
+    *record = get_accumulated_record(*record);
+}
+
+#[inline]
+pub fn update_record_with_minimal_vars<T: Number>(record: &mut GenericRecord<T>) {
+    *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
+}
+
+#[inline]
+pub fn update_record_with_shadowed_vars<T: Number>(record: &mut GenericRecord<T>) {
+    let tmp = *record;
+    let tmp = get_toggled_record(tmp);
+    let tmp = get_incremented_record(tmp);
+    let tmp = get_accumulated_record(tmp);
+    *record = tmp;
+}
+
+#[inline]
+pub fn update_record_with_mut_tmp_var<T: Number>(record: &mut GenericRecord<T>) {
+    let mut tmp = *record;
+    tmp = get_toggled_record(tmp);
+    tmp = get_incremented_record(tmp);
+    tmp = get_accumulated_record(tmp);
+    *record = tmp;
+}
+
+#[inline]
+pub fn update_record_no_refs<T: Number>(record: GenericRecord<T>) -> GenericRecord<T> {
+    let mut record = get_toggled_record(record);
+    record = get_incremented_record(record);
+    record = get_accumulated_record(record);
+    record
+}
+
+#[inline]
+pub fn update_record_mut<T: Number>(record: GenericRecord<T>) -> GenericRecord<T> {
+    let mut record = record;
+    toggle_record(&mut record);
+    increment_record(&mut record);
+    accumulate_record(&mut record);
+    record
+}
+
+#[inline]
+pub fn update_mut_record_mut<T: Number>(mut record: GenericRecord<T>) -> GenericRecord<T> {
+    toggle_record(&mut record);
+    increment_record(&mut record);
+    accumulate_record(&mut record);
+    record
+}
+
+//@ ## Instances
+//@
+//@ One module per field type: the smallest, the article's, the two that no
+//@ longer fit in a pair of registers, a signed one, and a wrapping one.
+
+macro_rules! instances {
+    ($($(#[$doc:meta])* $name:ident: $t:ty;)*) => {
+        $(
+            $(#[$doc])*
+            pub mod $name {
+                use super::GenericRecord;
+
+                pub type Record = GenericRecord<$t>;
+
+                #[inline(never)]
+                pub fn update_record_with_refs(record: &mut Record) {
+                    super::update_record_with_refs(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_with_ptrs(record: &mut Record) {
+                    super::update_record_with_ptrs(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_with_minimal_vars(record: &mut Record) {
+                    super::update_record_with_minimal_vars(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_with_shadowed_vars(record: &mut Record) {
+                    super::update_record_with_shadowed_vars(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_with_mut_tmp_var(record: &mut Record) {
+                    super::update_record_with_mut_tmp_var(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_no_refs(record: Record) -> Record {
+                    super::update_record_no_refs(record)
+                }
+
+                #[inline(never)]
+                pub fn update_record_mut(record: Record) -> Record {
+                    super::update_record_mut(record)
+                }
+
+                #[inline(never)]
+                pub fn update_mut_record_mut(record: Record) -> Record {
+                    super::update_mut_record_mut(record)
+                }
+            }
+        )*
+
+        /// the same three strategies for every field type, from the smallest to
+        /// the ones that no longer fit in registers
+        pub const FUNCTIONS: &[&str] = &[
+            $(
+                concat!("generic::", stringify!($name), "::update_record_with_refs"),
+                concat!("generic::", stringify!($name), "::update_record_no_refs"),
+                concat!("generic::", stringify!($name), "::update_record_mut"),
+            )*
+        ];
+    };
+}
+
+instances! {
+    /// `u8` fields: 3 bytes, no padding
+    of_u8: u8;
+    /// the article's `u32` fields: 12 bytes
+    of_u32: u32;
+    /// `u64` fields: 24 bytes
+    of_u64: u64;
+    /// `u128` fields: 48 bytes
+    of_u128: u128;
+    /// `i64` fields: 24 bytes
+    of_i64: i64;
+    /// `Wrapping<u64>` fields, which wrap in every profile: 24 bytes
+    of_wrapping_u64: std::num::Wrapping<u64>;
+}
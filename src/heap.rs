@@ -0,0 +1,250 @@
+//@ # Records that own heap data
+//@
+//@ `Record` is `Copy`, so "consume the record and return a new one" never has
+//@ to decide what happens to the old one: both are just bits. A record that
+//@ owns a `Vec` is not `Copy`, and every functional update has to either move
+//@ the `Vec` into the new record or clone it, and cloning allocates.
+//@
+//@ The records here are the article's with a `history`: every `b` that
+//@ `accumulate` overwrites is pushed onto it, so the heap data changes along
+//@ with the fields. [`owned::Record`] keeps its history in a `Vec<u32>`, and
+//@ [`shared::Record`] in an `Arc<Vec<u32>>` that clones of the record share
+//@ until one of them writes to it.
+//@
+//@ Each module has the article's operations and three strategies: in place
+//@ through a `&mut`, by value, and from a `&Record`, which has to clone before
+//@ it can update. `cargo xtask asm` and `cargo xtask ir` include all three.
+
+/// `history` in a `Vec`, moved from record to record
+pub mod owned {
+    /// the article's record, plus every `b` that `accumulate` has overwritten
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Record {
+        a: u32,
+        b: u32,
+        c: bool,
+        history: Vec<u32>,
+    }
+
+    impl Record {
+        pub fn new(a: u32, b: u32, c: bool) -> Record {
+            Record::with_history(a, b, c, Vec::new())
+        }
+
+        pub fn with_history(a: u32, b: u32, c: bool, history: Vec<u32>) -> Record {
+            Record { a, b, c, history }
+        }
+
+        pub fn history(&self) -> &[u32] {
+            &self.history
+        }
+    }
+
+    /// a record with an empty history
+    impl From<crate::Record> for Record {
+        fn from(record: crate::Record) -> Record {
+            Record::new(record.a(), record.b(), record.c())
+        }
+    }
+
+    /// drops the history
+    impl From<&Record> for crate::Record {
+        fn from(record: &Record) -> crate::Record {
+            crate::Record::new(record.a, record.b, record.c)
+        }
+    }
+
+    pub fn toggle_record(record: &mut Record) {
+        record.c = !record.c;
+    }
+
+    pub fn increment_record(record: &mut Record) {
+        record.a = record.a + 1;
+    }
+
+    pub fn accumulate_record(record: &mut Record) {
+        let a = record.a;
+        record.history.push(record.b);
+        record.a = record.a + record.b;
+        record.b = a;
+    }
+
+    // Struct update syntax moves `history` out of `record`: no clone.
+    pub fn get_toggled_record(record: Record) -> Record {
+        Record {
+            c: !record.c,
+            ..record
+        }
+    }
+
+    pub fn get_incremented_record(record: Record) -> Record {
+        Record {
+            a: record.a + 1,
+            ..record
+        }
+    }
+
+    pub fn get_accumulated_record(record: Record) -> Record {
+        let mut history = record.history;
+        history.push(record.b);
+        Record {
+            a: record.a + record.b,
+            b: record.a,
+            c: record.c,
+            history,
+        }
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_refs(record: &mut Record) {
+        toggle_record(record);
+        increment_record(record);
+        accumulate_record(record);
+    }
+
+    #[inline(never)]
+    pub fn update_record_no_refs(record: Record) -> Record {
+        let mut record = get_toggled_record(record);
+        record = get_incremented_record(record);
+        record = get_accumulated_record(record);
+        record
+    }
+
+    /// the caller keeps `record`, so the update starts from a clone
+    #[inline(never)]
+    pub fn update_record_from_ref(record: &Record) -> Record {
+        update_record_no_refs(record.clone())
+    }
+}
+
+//@ ## Clone on write
+//@
+//@ `Arc::make_mut` hands out a `&mut Vec<u32>` without copying when the record
+//@ holds the only reference to it, and copies the `Vec` first when it does
+//@ not. A by-value update of a record nobody else shares therefore allocates
+//@ no more than the in-place one, and cloning a record is only a reference
+//@ count until one of the clones is updated.
+
+/// `history` in an `Arc`, shared between clones until one of them is updated
+pub mod shared {
+    use std::sync::Arc;
+
+    /// the article's record, plus every `b` that `accumulate` has overwritten
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct Record {
+        a: u32,
+        b: u32,
+        c: bool,
+        history: Arc<Vec<u32>>,
+    }
+
+    impl Record {
+        pub fn new(a: u32, b: u32, c: bool) -> Record {
+            Record::with_history(a, b, c, Vec::new())
+        }
+
+        pub fn with_history(a: u32, b: u32, c: bool, history: Vec<u32>) -> Record {
+            Record {
+                a,
+                b,
+                c,
+                history: Arc::new(history),
+            }
+        }
+
+        pub fn history(&self) -> &[u32] {
+            &self.history
+        }
+
+        /// `true` if `self` and `other` hold the same history allocation
+        pub fn shares_history_with(&self, other: &Record) -> bool {
+            Arc::ptr_eq(&self.history, &other.history)
+        }
+    }
+
+    /// a record with an empty history
+    impl From<crate::Record> for Record {
+        fn from(record: crate::Record) -> Record {
+            Record::new(record.a(), record.b(), record.c())
+        }
+    }
+
+    /// drops the history
+    impl From<&Record> for crate::Record {
+        fn from(record: &Record) -> crate::Record {
+            crate::Record::new(record.a, record.b, record.c)
+        }
+    }
+
+    pub fn toggle_record(record: &mut Record) {
+        record.c = !record.c;
+    }
+
+    pub fn increment_record(record: &mut Record) {
+        record.a = record.a + 1;
+    }
+
+    pub fn accumulate_record(record: &mut Record) {
+        let a = record.a;
+        Arc::make_mut(&mut record.history).push(record.b);
+        record.a = record.a + record.b;
+        record.b = a;
+    }
+
+    pub fn get_toggled_record(record: Record) -> Record {
+        Record {
+            c: !record.c,
+            ..record
+        }
+    }
+
+    pub fn get_incremented_record(record: Record) -> Record {
+        Record {
+            a: record.a + 1,
+            ..record
+        }
+    }
+
+    pub fn get_accumulated_record(record: Record) -> Record {
+        let mut history = record.history;
+        Arc::make_mut(&mut history).push(record.b);
+        Record {
+            a: record.a + record.b,
+            b: record.a,
+            c: record.c,
+            history,
+        }
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_refs(record: &mut Record) {
+        toggle_record(record);
+        increment_record(record);
+        accumulate_record(record);
+    }
+
+    #[inline(never)]
+    pub fn update_record_no_refs(record: Record) -> Record {
+        let mut record = get_toggled_record(record);
+        record = get_incremented_record(record);
+        record = get_accumulated_record(record);
+        record
+    }
+
+    /// the caller keeps `record`, so the history is shared and then copied
+    #[inline(never)]
+    pub fn update_record_from_ref(record: &Record) -> Record {
+        update_record_no_refs(record.clone())
+    }
+}
+
+/// the in-place, by-value and from-a-reference updates of both ownership
+/// schemes, where an allocation would show up as a call
+pub const FUNCTIONS: &[&str] = &[
+    "heap::owned::update_record_with_refs",
+    "heap::owned::update_record_no_refs",
+    "heap::owned::update_record_from_ref",
+    "heap::shared::update_record_with_refs",
+    "heap::shared::update_record_no_refs",
+    "heap::shared::update_record_from_ref",
+];
@@ -0,0 +1,167 @@
+//@ # Strategies the compiler may inline
+//@
+//@ Every strategy in the article is `#[inline(never)]`, so its assembly can be
+//@ found and read. The article's one extra copy, in `update_record_mut`, comes
+//@ from exactly that: the record has to be returned through memory because
+//@ the function cannot disappear into its caller. Real callers see inlined
+//@ code, where the record may never leave registers at all.
+//@
+//@ The harnesses here put the body of each strategy (the same steps
+//@ [`batch`](crate::batch) loops over) into three ordinary contexts, and only
+//@ the harness itself is `#[inline(never)]`:
+//@
+//@ * `loop_*` updates one record [`ROUNDS`] times in a `for` loop,
+//@ * `chain_*` does the same with an iterator `fold`,
+//@ * `owner_*` updates the record kept in a field of a larger [`Owner`].
+//@
+//@ `cargo xtask asm` and `cargo xtask ir` include each context for the
+//@ in-place, second-struct and copy strategies, and
+//@ `cargo bench --bench strategies -- --inlined` times every harness.
+//@
+//@ Inlined, `update_record_mut` loses its copy: in a loop or on an owner's
+//@ field every strategy writes the record in place, with no `memcpy`. A
+//@ `chain_*` harness still returns its record through memory, like any
+//@ function returning a `Record`, but all three strategies do it alike.
+
+use crate::Record;
+use crate::batch::{
+    mut_record_mut, no_refs, record_mut, with_minimal_vars, with_mut_tmp_var, with_ptrs, with_refs,
+    with_shadowed_vars,
+};
+use crate::registry::{Convention, Strategy};
+
+/// how many times the `loop_*` and `chain_*` harnesses update their record
+pub const ROUNDS: usize = 8;
+
+/// a struct that keeps a record among other fields
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Owner {
+    id: u64,
+    name: [u8; 16],
+    record: Record,
+    updates: u64,
+}
+
+impl Owner {
+    pub fn new(id: u64, record: Record) -> Owner {
+        Owner {
+            id,
+            record,
+            ..Owner::default()
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn record(&self) -> Record {
+        self.record
+    }
+
+    /// how many times an `owner_*` harness has updated the record
+    pub fn updates(&self) -> u64 {
+        self.updates
+    }
+}
+
+//@ ## The harnesses
+//@
+//@ As in `batch`, an in-place step updates through a reference and a by-value
+//@ step is assigned back, whichever the context calls for.
+
+macro_rules! harnesses {
+    ($($kind:ident $step:ident => $loop:ident, $chain:ident, $owner:ident, $by_value:ident;)*) => {
+        $(
+            #[inline(never)]
+            pub fn $loop(record: &mut Record) {
+                for _ in 0..ROUNDS {
+                    harnesses!(@update $kind $step record);
+                }
+            }
+
+            #[inline(never)]
+            pub fn $chain(record: Record) -> Record {
+                (0..ROUNDS).fold(record, |record, _| harnesses!(@map $kind $step record))
+            }
+
+            #[inline(never)]
+            pub fn $owner(owner: &mut Owner) {
+                let record = &mut owner.record;
+                harnesses!(@update $kind $step record);
+                owner.updates += 1;
+            }
+
+            // `$owner` on a fresh owner, as a strategy the benchmark can time
+            fn $by_value(record: Record) -> Record {
+                let mut owner = Owner::new(0, record);
+                $owner(&mut owner);
+                owner.record
+            }
+        )*
+
+        /// every harness as a [`Strategy`], loops first, then chains, then owners;
+        /// an `owner_*` harness is run on a new [`Owner`] holding the record
+        pub static HARNESSES: &[Strategy] = &[
+            $(
+                Strategy {
+                    name: concat!("inlined::", stringify!($loop)),
+                    convention: Convention::InPlace($loop),
+                },
+            )*
+            $(
+                Strategy {
+                    name: concat!("inlined::", stringify!($chain)),
+                    convention: Convention::ByValue($chain),
+                },
+            )*
+            $(
+                Strategy {
+                    name: concat!("inlined::", stringify!($owner)),
+                    convention: Convention::ByValue($by_value),
+                },
+            )*
+        ];
+    };
+    (@update in_place $step:ident $record:ident) => { $step($record) };
+    (@update by_value $step:ident $record:ident) => { *$record = $step(*$record) };
+    (@map in_place $step:ident $record:ident) => {{
+        let mut record = $record;
+        $step(&mut record);
+        record
+    }};
+    (@map by_value $step:ident $record:ident) => { $step($record) };
+}
+
+harnesses! {
+    in_place with_refs => loop_with_refs, chain_with_refs, owner_with_refs, owner_with_refs_by_value;
+    in_place with_ptrs => loop_with_ptrs, chain_with_ptrs, owner_with_ptrs, owner_with_ptrs_by_value;
+    in_place with_minimal_vars =>
+        loop_with_minimal_vars, chain_with_minimal_vars, owner_with_minimal_vars,
+        owner_with_minimal_vars_by_value;
+    in_place with_shadowed_vars =>
+        loop_with_shadowed_vars, chain_with_shadowed_vars, owner_with_shadowed_vars,
+        owner_with_shadowed_vars_by_value;
+    in_place with_mut_tmp_var =>
+        loop_with_mut_tmp_var, chain_with_mut_tmp_var, owner_with_mut_tmp_var,
+        owner_with_mut_tmp_var_by_value;
+    by_value no_refs => loop_no_refs, chain_no_refs, owner_no_refs, owner_no_refs_by_value;
+    by_value record_mut => loop_mut, chain_mut, owner_mut, owner_mut_by_value;
+    by_value mut_record_mut =>
+        loop_mut_record_mut, chain_mut_record_mut, owner_mut_record_mut,
+        owner_mut_record_mut_by_value;
+}
+
+/// a mutating, a functional and a mutate-then-return strategy in each of the
+/// three contexts; the other five per context are in [`HARNESSES`] only
+pub const FUNCTIONS: &[&str] = &[
+    "inlined::loop_with_refs",
+    "inlined::loop_no_refs",
+    "inlined::loop_mut",
+    "inlined::chain_with_refs",
+    "inlined::chain_no_refs",
+    "inlined::chain_mut",
+    "inlined::owner_with_refs",
+    "inlined::owner_no_refs",
+    "inlined::owner_mut",
+];
@@ -0,0 +1,324 @@
+//@ # Other layouts for `Record`
+//@
+//@ The article's `Record` has nine bytes of data, and Rust is free to lay
+//@ them out however it likes. It picks `a`, `b`, `c` in that order and pads
+//@ the struct to twelve bytes, so a whole-record copy ends with the odd
+//@ `ldurh w12, [x0, #9]` / `ldrb w12, [x0, #11]` pair that moves the padding.
+//@
+//@ Each module below defines its own `Record` with a different layout, and
+//@ the same operations and strategies as the article, word for word:
+//@
+//@ | module      | layout                        | size | align |
+//@ |-------------|-------------------------------|------|-------|
+//@ | [`c`]       | `#[repr(C)]`                  | 12   | 4     |
+//@ | [`packed`]  | `#[repr(C, packed)]`          | 9    | 1     |
+//@ | [`aligned`] | `#[repr(C, align(16))]`       | 16   | 16    |
+//@ | [`bits`]    | one `u64`, `c` in a spare bit | 8    | 8     |
+//@
+//@ `cargo xtask asm` and `cargo xtask ir` include `update_record_with_refs`,
+//@ `update_record_no_refs` and `update_record_mut` for each of them, the
+//@ three strategies that show in-place updates, a second struct, and a copy.
+//@
+//@ ## Strategies
+//@
+//@ Given the six operations, the strategies are the article's. The macro
+//@ only saves writing them out four times.
+
+macro_rules! strategies {
+    () => {
+        #[inline(never)]
+        pub fn update_record_with_refs(record: &mut Record) {
+            toggle_record(record);
+            increment_record(record);
+            accumulate_record(record);
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_ptrs(record: &mut Record) {
+            *record = get_toggled_record(*record);
+            *record = get_incremented_record(*record);
+            *record = get_accumulated_record(*record);
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_minimal_vars(record: &mut Record) {
+            *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_shadowed_vars(record: &mut Record) {
+            let tmp = *record;
+            let tmp = get_toggled_record(tmp);
+            let tmp = get_incremented_record(tmp);
+            let tmp = get_accumulated_record(tmp);
+            *record = tmp;
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_mut_tmp_var(record: &mut Record) {
+            let mut tmp = *record;
+            tmp = get_toggled_record(tmp);
+            tmp = get_incremented_record(tmp);
+            tmp = get_accumulated_record(tmp);
+            *record = tmp;
+        }
+
+        #[inline(never)]
+        pub fn update_record_no_refs(record: Record) -> Record {
+            let mut record = get_toggled_record(record);
+            record = get_incremented_record(record);
+            record = get_accumulated_record(record);
+            record
+        }
+
+        #[inline(never)]
+        pub fn update_record_mut(record: Record) -> Record {
+            let mut record = record;
+            toggle_record(&mut record);
+            increment_record(&mut record);
+            accumulate_record(&mut record);
+            record
+        }
+
+        #[inline(never)]
+        pub fn update_mut_record_mut(mut record: Record) -> Record {
+            toggle_record(&mut record);
+            increment_record(&mut record);
+            accumulate_record(&mut record);
+            record
+        }
+    };
+}
+
+//@ ## Named fields
+//@
+//@ The three `repr` variants keep the fields `a`, `b` and `c`, so their
+//@ operations are the article's too.
+
+macro_rules! field_record {
+    () => {
+        pub fn toggle_record(record: &mut Record) {
+            record.c = !record.c;
+        }
+
+        pub fn increment_record(record: &mut Record) {
+            record.a = record.a + 1;
+        }
+
+        pub fn accumulate_record(record: &mut Record) {
+            let a = record.a;
+            record.a = record.a + record.b;
+            record.b = a;
+        }
+
+        pub fn get_toggled_record(record: Record) -> Record {
+            Record {
+                c: !record.c,
+                ..record
+            }
+        }
+
+        pub fn get_incremented_record(record: Record) -> Record {
+            Record {
+                a: record.a + 1,
+                ..record
+            }
+        }
+
+        pub fn get_accumulated_record(record: Record) -> Record {
+            Record {
+                a: record.a + record.b,
+                b: record.a,
+                ..record
+            }
+        }
+
+        impl From<crate::Record> for Record {
+            fn from(record: crate::Record) -> Record {
+                Record {
+                    a: record.a,
+                    b: record.b,
+                    c: record.c,
+                }
+            }
+        }
+
+        impl From<Record> for crate::Record {
+            fn from(record: Record) -> crate::Record {
+                crate::Record {
+                    a: record.a,
+                    b: record.b,
+                    c: record.c,
+                }
+            }
+        }
+
+        strategies!();
+    };
+}
+
+/// fields in declaration order, padded to 12 bytes
+pub mod c {
+    #[repr(C)]
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Record {
+        a: u32,
+        b: u32,
+        c: bool,
+    }
+
+    field_record!();
+}
+
+/// no padding: 9 bytes, and every field may be unaligned
+pub mod packed {
+    #[repr(C, packed)]
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Record {
+        a: u32,
+        b: u32,
+        c: bool,
+    }
+
+    field_record!();
+}
+
+/// padded to 16 bytes, so a record fills exactly one SIMD register
+pub mod aligned {
+    #[repr(C, align(16))]
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Record {
+        a: u32,
+        b: u32,
+        c: bool,
+    }
+
+    field_record!();
+}
+
+//@ ## Bit-packed
+//@
+//@ Two full `u32`s and a `bool` do not fit in 64 bits, so one bit has to come
+//@ from a number. This layout takes it from `a`: bits 0–30 hold `a`, bit 31
+//@ holds `c`, and bits 32–63 hold `b`. In exchange the whole record is one
+//@ register wide.
+//@
+//@ `a` is therefore a 31-bit number. A record whose `a` is 2³¹ or more does
+//@ not convert into this layout at all: `try_from` returns [`bits::TooWide`].
+//@ Increment and accumulate wrap at 2³¹, in every profile: the sum is worked
+//@ out in a `u64` and masked, so there is no overflow for a debug build to
+//@ catch, where the article's `u32` would panic. For every sum that stays
+//@ below 2³¹ the results are the same as the article's; `b` only ever
+//@ receives an old `a`, so it never needs the extra bit.
+
+/// `a` in bits 0–30, `c` in bit 31, `b` in bits 32–63
+pub mod bits {
+    use std::error::Error;
+    use std::fmt;
+
+    const A: u64 = (1 << 31) - 1;
+    const C: u64 = 1 << 31;
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Record(u64);
+
+    impl Record {
+        fn a(self) -> u64 {
+            self.0 & A
+        }
+
+        fn b(self) -> u64 {
+            self.0 >> 32
+        }
+
+        // `self` with `a` replaced by the low 31 bits of `a`
+        fn with_a(self, a: u64) -> Record {
+            Record(self.0 & !A | a & A)
+        }
+
+        // `self` with `b` replaced by `b`, which must fit in 32 bits
+        fn with_b(self, b: u64) -> Record {
+            Record(self.0 & (A | C) | b << 32)
+        }
+    }
+
+    pub fn toggle_record(record: &mut Record) {
+        record.0 ^= C;
+    }
+
+    pub fn increment_record(record: &mut Record) {
+        *record = record.with_a(record.a() + 1);
+    }
+
+    pub fn accumulate_record(record: &mut Record) {
+        let a = record.a();
+        *record = record.with_a(a + record.b()).with_b(a);
+    }
+
+    pub fn get_toggled_record(record: Record) -> Record {
+        Record(record.0 ^ C)
+    }
+
+    pub fn get_incremented_record(record: Record) -> Record {
+        record.with_a(record.a() + 1)
+    }
+
+    pub fn get_accumulated_record(record: Record) -> Record {
+        record.with_a(record.a() + record.b()).with_b(record.a())
+    }
+
+    /// a record whose `a` needs all 32 bits, so there is no room for `c`
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct TooWide {
+        pub a: u32,
+    }
+
+    impl fmt::Display for TooWide {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "`a` = {} does not fit in 31 bits", self.a)
+        }
+    }
+
+    impl Error for TooWide {}
+
+    impl TryFrom<crate::Record> for Record {
+        type Error = TooWide;
+
+        fn try_from(record: crate::Record) -> Result<Record, TooWide> {
+            if u64::from(record.a) > A {
+                return Err(TooWide { a: record.a });
+            }
+            let c = if record.c { C } else { 0 };
+            Ok(Record(u64::from(record.b) << 32 | c | u64::from(record.a)))
+        }
+    }
+
+    impl From<Record> for crate::Record {
+        fn from(record: Record) -> crate::Record {
+            crate::Record {
+                a: record.a() as u32,
+                b: record.b() as u32,
+                c: record.0 & C != 0,
+            }
+        }
+    }
+
+    strategies!();
+}
+
+/// one in-place, one by-value and one mutate-then-return strategy per layout,
+/// enough to see whether the layout changes how the record is written
+pub const FUNCTIONS: &[&str] = &[
+    "layout::c::update_record_with_refs",
+    "layout::c::update_record_no_refs",
+    "layout::c::update_record_mut",
+    "layout::packed::update_record_with_refs",
+    "layout::packed::update_record_no_refs",
+    "layout::packed::update_record_mut",
+    "layout::aligned::update_record_with_refs",
+    "layout::aligned::update_record_no_refs",
+    "layout::aligned::update_record_mut",
+    "layout::bits::update_record_with_refs",
+    "layout::bits::update_record_no_refs",
+    "layout::bits::update_record_mut",
+];
@@ -17,21 +17,32 @@
 //@
 //@ First lets create a `struct`. I'll call my new datatype `Record`. (This is
 //@ just my name for it; "Record" is not a Rust keyword.)
+//@
+//@ The struct leaves the type of its numbers open, so that
+//@ [`generic`](generic.md) can try wider ones later; `T` can be any type that
+//@ implements `Number`, which is every primitive integer. `Record` is the
+//@ instance with `u32` numbers, and the only one this article uses.
+
+use generic::Number;
 
-#[derive(Copy, Clone)]
-pub struct Record {
-    a: u32,
-    b: u32,
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GenericRecord<T> {
+    a: T,
+    b: T,
     c: bool,
 }
 
-//@ To make it interesting, I've given the `Record` type some fields arbitrarily:
+pub type Record = GenericRecord<u32>;
+
+//@ To make it interesting, I've given the record some fields arbitrarily:
 //@
-//@ * `a` - an unsigned 32-bit integer (`u32`)
-//@ * `b` - an unsigned 32-bit integer (`u32`)
+//@ * `a` - a number of type `T`, so an unsigned 32-bit integer (`u32`) in a
+//@   `Record`
+//@ * `b` - a second number of the same type
 //@ * `c` - a Boolean (`bool`)
 //@
-//@ Now let's create some functions to mutate the struct.
+//@ Now let's create some functions to mutate the struct. They are written for
+//@ any `T`, but everything below calls them on a `Record`.
 //@
 //@ ## Mutating the fields in-place
 //@
@@ -39,29 +50,36 @@ pub struct Record {
 //@
 //@ ### Toggle the Record's Boolean flag
 
-fn toggle_record(record: &mut Record) {
+fn toggle_record<T>(record: &mut GenericRecord<T>) {
     record.c = !record.c;
 }
 
 //@ For those unfamiliar with Rust syntax, this function takes a _mutable_
-//@ reference to a Record (`&mut Record`) and binds it to a parameter named
-//@ `record`. The function updates the record's `c` field. De-referencing is
-//@ implicit.
+//@ reference to a record (`&mut GenericRecord<T>`, which is `&mut Record` for
+//@ `u32`) and binds it to a parameter named `record`. The function updates the
+//@ record's `c` field. De-referencing is implicit.
 //@
 //@ It returns `Void`.
 //@
-//@ Let's have a couple more functions to update the other fields.
+//@ Let's have a couple more functions to update the other fields. A number
+//@ of any type has no literal `1`, so it is spelled `T::ONE`.
 
-fn increment_record(record: &mut Record) {
-    record.a = record.a + 1;
+fn increment_record<T: Number>(record: &mut GenericRecord<T>) {
+    record.a = record.a + T::ONE;
 }
 
 /// this will treat `a` as an "accumulator"
 /// Mostly I wrote this function just to give the compiler some more
 /// difficulty by both reading from and writing to the same variable.
-fn accumulate_record(record: &mut Record) {
+///
+/// `b` gets the old `a`, as in `get_accumulated_record`. The first version of
+/// this function assigned `record.a` after updating it, so `b` got the new
+/// `a` and the imperative strategies computed a different record from the
+/// functional ones; `equivalence` caught it.
+fn accumulate_record<T: Number>(record: &mut GenericRecord<T>) {
+    let a = record.a;
     record.a = record.a + record.b;
-    record.b = record.a;
+    record.b = a;
 }
 
 //@ ## Functional / Immutable Style
@@ -83,8 +101,8 @@ fn get_toggled_record_by_copying(record: Record) -> Record {
 //@
 //@ [5.1]: https://doc.rust-lang.org/book/ch05-01-defining-structs.html#creating-instances-from-other-instances-with-struct-update-syntax
 
-fn get_toggled_record(record: Record) -> Record {
-    Record {
+fn get_toggled_record<T>(record: GenericRecord<T>) -> GenericRecord<T> {
+    GenericRecord {
         c: !record.c,
         ..record
     }
@@ -93,15 +111,15 @@ fn get_toggled_record(record: Record) -> Record {
 //@ Here are the remaining functions from before, rewritten in functional/immutable
 //@ style with "struct update syntax."
 
-fn get_incremented_record(record: Record) -> Record {
-    Record {
-        a: record.a + 1,
+fn get_incremented_record<T: Number>(record: GenericRecord<T>) -> GenericRecord<T> {
+    GenericRecord {
+        a: record.a + T::ONE,
         ..record
     }
 }
 
-fn get_accumulated_record(record: Record) -> Record {
-    Record {
+fn get_accumulated_record<T: Number>(record: GenericRecord<T>) -> GenericRecord<T> {
+    GenericRecord {
         a: record.a + record.b,
         b: record.a,
         ..record
@@ -145,31 +163,29 @@ pub fn update_record_with_refs(record: &mut Record) {
 //@ Let's view the corresponding assembly:
 //@
 //@ ```sh
-//@ # target arch and compiler version
-//@ uname -v ;\
+//@ # compiler version, then the assembly for an arm64 Mac
 //@ cargo --version ;\
-//@ cargo rustc --release -- --emit asm &&\
-//@ find target -name '*.s' -exec cat {} \;
+//@ cargo rustc --release --target aarch64-apple-darwin -- --emit asm &&\
+//@ find target/aarch64-apple-darwin -name '*.s' -exec cat {} \;
 //@ ```
 //@
 //@ The salient parts:
 //@
 //@ ```
-//@ Darwin Kernel Version 24.5.0: Tue Apr 22 19:48:46 PDT 2025; root:xnu-11417.121.6~2/RELEASE_ARM64_T8103
-//@ cargo 1.86.0 (adf9b6ad1 2025-02-28)
+//@ cargo 1.95.0 (f2d3ce0bd 2026-03-21)
 //@ ...
 //@ ```
 //@
 //@ ```asm
-//@ __ZN15records_in_rust23update_record_with_refs17h80c99250a4a3f79fE:
+//@ __ZN15records_in_rust23update_record_with_refs17h44ec392d35f296f5E:
 //@ 	.cfi_startproc
 //@ 	ldrb	w8, [x0, #8]
 //@ 	eor	w8, w8, #0x1
 //@ 	strb	w8, [x0, #8]
 //@ 	ldp	w8, w9, [x0]
-//@ 	add	w8, w8, w9
 //@ 	add	w8, w8, #1
-//@ 	stp	w8, w8, [x0]
+//@ 	add	w9, w9, w8
+//@ 	stp	w9, w8, [x0]
 //@ 	ret
 //@ 	.cfi_endproc
 //@ ```
@@ -182,9 +198,10 @@ pub fn update_record_with_refs(record: &mut Record) {
 //@ 2. XOR (`eor`) the register with `1`. I.e. toggle the boolean
 //@ 3. store the modified byte back (`strb`)
 //@ 4. read two (32-bit) ints into registers `w8`, and `w9` (`ldp ...`)
-//@ 5. `add` those ints together
-//@ 6. `add` `1` to one of them
-//@ 7. write both registers back to memory (`stp`)
+//@ 5. `add` `1` to `a`
+//@ 6. `add` the new `a` to `b`
+//@ 7. write both registers back to memory (`stp`), swapped: the sum becomes
+//@    `a` and the incremented `a` becomes `b`
 //@ 8. `ret`-urn from the procedure
 //@
 //@ This maps pretty closely to the imperative functions being called.
@@ -219,7 +236,7 @@ pub fn update_record_with_ptrs(record: &mut Record) {
 //@ … and the corresponding assembly:
 //@
 //@ ```asm
-//@ __ZN15records_in_rust23update_record_with_ptrs17hc5c1df94ab26400bE:
+//@ __ZN15records_in_rust23update_record_with_ptrs17h8cfc35ff35375723E:
 //@ 	.cfi_startproc
 //@ 	ldrb	w8, [x0, #8]
 //@ 	eor	w8, w8, #0x1
@@ -247,13 +264,13 @@ pub fn update_record_with_ptrs(record: &mut Record) {
 /// minimize use of pointers by nesting function calls
 #[inline(never)]
 pub fn update_record_with_minimal_vars(record: &mut Record) {
-*record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
+    *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
 }
 
 //@ That produces ...
 //@
 //@ ```asm
-//@ __ZN15records_in_rust31update_record_with_minimal_vars17h1bc5b1920fe2a2daE:
+//@ __ZN15records_in_rust31update_record_with_minimal_vars17h305f364d3b965c4fE:
 //@ 	.cfi_startproc
 //@ 	ldp	w8, w9, [x0]
 //@ 	ldrb	w10, [x0, #8]
@@ -285,36 +302,22 @@ pub fn update_record_with_shadowed_vars(record: &mut Record) {
 //@ … compile …
 //@
 //@ ```asm
-//@ __ZN15records_in_rust30update_record_with_mut_tmp_var17hb6e407b831178dc9E:
-//@ 	.cfi_startproc
-//@ 	ldp	w8, w9, [x0]
-//@ 	ldrb	w10, [x0, #8]
-//@ 	mov	w11, #1
-//@ 	bic	w10, w11, w10
-//@ 	add	w8, w8, #1
-//@ 	add	w9, w8, w9
-//@ 	stp	w9, w8, [x0]
-//@ 	strb	w10, [x0, #8]
-//@ 	ret
-//@ 	.cfi_endproc
+//@ __ZN15records_in_rust32update_record_with_shadowed_vars17h5c216991f0af8a4eE = __ZN15records_in_rust31update_record_with_minimal_vars17h305f364d3b965c4fE
 //@ ```
 //@
-//@ Oddly, the only difference is the way the toggle is performed.
+//@ There is no code to show. The compiler found that this function is
+//@ `update_record_with_minimal_vars`, instruction for instruction, so it only
+//@ emits that one and makes this symbol an alias of it.
 //@
-//@ ```asm
-//@ mov	w11, #1             ; w11 = 1
-//@ bic	w10, w11, w10       ; w10 = w11 & ~w10
-//@ ```
-//@
-//@ That's an unexpected way to toggle a boolean. I don't really know what to say
-//@ about that.
-//@
-//@ ...
+//@ An earlier version of this article showed a listing here, and remarked that
+//@ "oddly, the only difference is the way the toggle is performed", with a
+//@ `mov` and a `bic` where the other strategies have an `eor`. That listing
+//@ was `update_record_with_mut_tmp_var`'s, pasted under this function's name,
+//@ so the remark was about that function; it has moved below.
 //@
 //@ Well, on to the next thing. What happens if I re-use a temp var instead of
 //@ shadowing the previous var?
 
-
 /// minimize use of pointers with a single, mutable tmp var
 #[inline(never)]
 pub fn update_record_with_mut_tmp_var(record: &mut Record) {
@@ -326,7 +329,7 @@ pub fn update_record_with_mut_tmp_var(record: &mut Record) {
 }
 
 //@ ```asm
-//@ __ZN15records_in_rust30update_record_with_mut_tmp_var17hb6e407b831178dc9E:
+//@ __ZN15records_in_rust30update_record_with_mut_tmp_var17h9fd4d5b49fd8983dE:
 //@ 	.cfi_startproc
 //@ 	ldp	w8, w9, [x0]
 //@ 	ldrb	w10, [x0, #8]
@@ -340,7 +343,16 @@ pub fn update_record_with_mut_tmp_var(record: &mut Record) {
 //@ 	.cfi_endproc
 //@ ```
 //@
-//@ Same thing. No surprised there, but it's good to know.
+//@ Oddly, the only difference from `update_record_with_minimal_vars` is the way
+//@ the toggle is performed.
+//@
+//@ ```asm
+//@ mov	w11, #1             ; w11 = 1
+//@ bic	w10, w11, w10       ; w10 = w11 & ~w10
+//@ ```
+//@
+//@ That's an unexpected way to toggle a boolean. I don't really know what to say
+//@ about that.
 //@
 //@ ### Can I do this with no refs?
 //@
@@ -351,8 +363,8 @@ pub fn update_record_with_mut_tmp_var(record: &mut Record) {
 //@ Perhaps we stay truer to functional style by moving a `record` into the
 //@ function, consuming it, and returning a new `Record`.
 //@
-//@ Again, I'll re-use a mutable var instead of shadowing, since that appeared to
-//@ not make a difference before.
+//@ Again, I'll re-use a mutable var instead of shadowing, since that only
+//@ changed the way the toggle is performed.
 
 #[inline(never)]
 pub fn update_record_no_refs(record: Record) -> Record {
@@ -365,7 +377,7 @@ pub fn update_record_no_refs(record: Record) -> Record {
 //@ Compile ...
 //@
 //@ ```asm
-//@ __ZN15records_in_rust21update_record_no_refs17h6f48abe941590117E:
+//@ __ZN15records_in_rust21update_record_no_refs17hbf6a2729fb747da2E:
 //@ 	.cfi_startproc
 //@ 	ldp	w9, w10, [x0]
 //@ 	ldrb	w11, [x0, #8]
@@ -399,32 +411,29 @@ pub fn update_record_mut(record: Record) -> Record {
 }
 
 //@ ```asm
-//@ __ZN15records_in_rust17update_record_mut17h90ee354aab9a591eE:
+//@ __ZN15records_in_rust17update_record_mut17h6913b905eee04871E:
 //@ 	.cfi_startproc
+//@ 	ldrb	w9, [x0, #8]
+//@ 	eor	w9, w9, #0x1
+//@ 	strb	w9, [x0, #8]
 //@ 	ldp	w9, w10, [x0]
-//@ 	ldrb	w11, [x0, #8]
-//@ 	ldurh	w12, [x0, #9]
-//@ 	sturh	w12, [x8, #9]
-//@ 	ldrb	w12, [x0, #11]
-//@ 	strb	w12, [x8, #11]
-//@ 	mov	w12, #1
-//@ 	bic	w11, w12, w11
-//@ 	add	w9, w9, w10
 //@ 	add	w9, w9, #1
-//@ 	stp	w9, w9, [x8]
-//@ 	strb	w11, [x8, #8]
+//@ 	add	w10, w10, w9
+//@ 	stp	w10, w9, [x0]
+//@ 	ldr	w9, [x0, #8]
+//@ 	str	w9, [x8, #8]
+//@ 	ldr	x9, [x0]
+//@ 	str	x9, [x8]
 //@ 	ret
 //@ 	.cfi_endproc
 //@ ```
 //@
-//@ Again, this creates a second struct.
-//@
-//@ Also, this loading is weird because my struct is represented in 9 bytes. It doesn't align well to "word boundaries."
+//@ Again, this creates a second struct, but not the way `update_record_no_refs`
+//@ does.
 //@
-//@ This is why we see `ldurh w12, [x0, #9]` ("load half-word starting at byte 9 into register `w12`").
-//@
-//@ We see loads from `x0`, but stores to `x8` indicating we are reading from one
-//@ struct and writing to a second.
+//@ The first seven instructions are `update_record_with_refs`: the record that
+//@ was passed in (`x0`) is updated in place. Only then is it copied to the
+//@ second struct (`x8`), eight bytes and then four.
 //@
 //@ The re-binding _should_ be a no-op, but I'll check anyway.
 
@@ -439,27 +448,15 @@ pub fn update_mut_record_mut(mut record: Record) -> Record {
 //@ …
 //@
 //@ ```asm
-//@ __ZN15records_in_rust21update_mut_record_mut17h40a89b93d70ddfc7E:
-//@ 	.cfi_startproc
-//@ 	ldrb	w9, [x0, #8]
-//@ 	eor	w9, w9, #0x1
-//@ 	strb	w9, [x0, #8]
-//@ 	ldp	w9, w10, [x0]
-//@ 	add	w9, w9, w10
-//@ 	add	w9, w9, #1
-//@ 	stp	w9, w9, [x0]
-//@ 	ldr	w9, [x0, #8]
-//@ 	str	w9, [x8, #8]
-//@ 	ldr	x9, [x0]
-//@ 	str	x9, [x8]
-//@ 	ret
-//@ 	.cfi_endproc
+//@ __ZN15records_in_rust21update_mut_record_mut17h822576fb1ab28e77E = __ZN15records_in_rust17update_record_mut17h6913b905eee04871E
 //@ ```
 //@
-//@ Well, that _is_ different. Again, unexpected.
+//@ It is a no-op: this is the same code. In fact the compiler only emits it
+//@ once, and `update_mut_record_mut`'s symbol is an alias of
+//@ `update_record_mut`'s.
 //@
-//@ Without the re-binding, the mutations are done in-place, but the results are
-//@ still copied to a new struct.
+//@ With or without the re-binding, the mutations are done in-place, and the
+//@ results are then copied to a new struct.
 //@
 //@ ## Conclusion
 //@
@@ -477,6 +474,157 @@ pub fn update_mut_record_mut(mut record: Record) -> Record {
 //@
 //@ Happy coding!
 //@
+//@ ## Appendix
+//@
+//@ The rest of the crate is tooling for checking the claims above. It lives in
+//@ its own modules so it does not get in the way of the article.
+//@
+//@ `registry` lists every `update_record_*` function by name, so the rest of the
+//@ tooling can enumerate them. `equivalence` runs them all over generated
+//@ records and reports the smallest record on which any two of them disagree.
+//@ `overflow` repeats every operation and strategy with wrapping, saturating
+//@ and checked arithmetic, for code that cannot let the build profile decide
+//@ what `u32::MAX + 1` is.
+//@
+//@ `record` gives `Record` a constructor, getters and a `(a, b, c)` text form,
+//@ so other crates can build the records they pass to the strategies.
+//@
+//@ `lens` reaches the fields through first-class lenses instead of struct
+//@ update syntax, and adds strategies built that way, to see whether the
+//@ abstraction survives optimization.
+//@
+//@ `op` turns toggle, increment and accumulate into values that can be put in
+//@ a list and applied in any order, so an update can be decided at run time.
+//@ `fusion` collapses such a list into one affine map, and repeats it `n`
+//@ times in O(log n) steps.
+//@
+//@ `batch` runs every strategy over a whole slice of records, where the
+//@ question becomes whether the loop is vectorized. `soa` stores a batch
+//@ column by column instead, with `c` as a bitset, to compare the two layouts.
+//@ `layout` repeats the article with `Record` declared `repr(C)`, packed,
+//@ over-aligned and bit-packed into a `u64`. `generic` repeats it with `a`
+//@ and `b` of any integer type, up to `u128`, where a record no longer fits
+//@ in registers. `big` carries an `[u64; N]` payload alongside the fields,
+//@ from none to 128 `u64`s, to find where a by-value update starts to cost a
+//@ copy.
+//@
+//@ `heap` gives up `Copy`: its records own a `Vec`, moved from one record to
+//@ the next or shared behind an `Arc` and copied on write, to see when a
+//@ functional update has to allocate.
+
+pub mod batch;
+pub mod bench;
+pub mod big;
+pub mod equivalence;
+pub mod fusion;
+pub mod generic;
+pub mod heap;
+pub mod inlined;
+pub mod layout;
+pub mod lens;
+pub mod op;
+pub mod overflow;
+pub mod record;
+pub mod registry;
+pub mod soa;
+
+//@ The assembly listings above were copied by hand out of the `.s` file. The
+//@ `records-asm` crate in this workspace does that mechanically, and
+//@ `cargo xtask asm` prints the release assembly of every registered strategy
+//@ for whatever machine you run it on (`--json` for a structured report).
+//@
+//@ A normalized copy of that assembly is checked in for each target triple
+//@ under `xtask/tests/golden`, and `cargo test` fails if a new compiler emits
+//@ something different, or if there is no copy for the machine it runs on.
+//@ When the change is expected, or the machine is new, `cargo xtask bless`
+//@ records the new assembly.
+//@
+//@ The listings above are arm64 Darwin's, and the `x0`/`x8` reasoning is
+//@ specific to its calling convention. On x86_64 the return slot comes in
+//@ `rdi` instead of `x8`, which moves the record itself to `rsi` whenever a
+//@ function has both. `--target TRIPLE` (or `--all-targets`) makes `asm`, `ir`
+//@ and `bless` cross-compile instead, for any target whose standard library
+//@ `rustup target add` has installed; the others are skipped with a note.
+//@ Golden copies for arm64 Darwin, x86_64, aarch64 and riscv64 Linux and
+//@ wasm32 are checked in, and `cargo test` compares every one that is
+//@ installed.
+//@
+//@ `cargo xtask ir` answers the `x0`-versus-`x8` question from LLVM IR instead,
+//@ so it works on any target: for each strategy it reports whether the stores
+//@ go through the record that was passed in ("in-place"), through the caller's
+//@ return slot ("second struct"), or both. Writing both only counts as
+//@ "in-place, then copied" when a `memcpy` copies the record that was passed
+//@ in to the return slot.
+//@
+//@ Both look at `--release` only. `cargo xtask matrix` builds the crate at
+//@ every `opt-level` from 0 to 3, `s` and `z`, each once with the default
+//@ codegen units and once with a single one, and tabulates each strategy's
+//@ instruction count and verdict with the two builds side by side (`O3` and
+//@ `O3+cgu1`). At the time of writing, the two columns of a level always
+//@ agree. There is no LTO column: the assembly is that of the library,
+//@ before anything is linked, so LTO does not change it, and an artifact
+//@ linked with LTO keeps none of the strategies. The article's strategies write their record the same way at every level but
+//@ `0`, except that at `1`, `s` and `z` `update_record_mut` builds its result
+//@ in the return slot instead of copying it there. `opt-level = "z"` does
+//@ change the abstractions: it stops inlining the `lens` and `op` steps, and
+//@ those strategies copy the record again.
+//@
+//@ The article judges "nearly identical code" and "the same assembly in a
+//@ slightly different order" by eye. `cargo xtask diff LEFT RIGHT` judges it
+//@ by what each instruction computes rather than which registers it uses: it
+//@ shows the left function in the right one's registers, marks instructions
+//@ that only moved with `<` and `>`, and says whether anything moved past an
+//@ instruction it depends on. At the time of writing, on x86_64 and aarch64
+//@ Linux `update_record_with_ptrs` and `update_record_with_refs` are the same
+//@ instructions in another order, while `update_record_with_shadowed_vars`
+//@ and `update_record_with_mut_tmp_var` really do differ, in how they toggle
+//@ `c`.
+//@
+//@ `cargo xtask listings` rewrites the listings in this file from one fresh
+//@ release build for `aarch64-apple-darwin`, on whatever machine it runs:
+//@ every `asm` block that starts with a function's label is replaced by that
+//@ function as your compiler emits it, and the `cargo --version` header above
+//@ is updated to match, so every listing is always from the compiler the
+//@ header names.
+//@
+//@ Whether the listings are copied by hand or regenerated, `cargo test` checks
+//@ that the label of each one names the function in the Rust block right above
+//@ it.
+//@
+//@ The article ends by leaving the rest to benchmarks. `cargo bench` runs
+//@ them: `bench` times every registered strategy on generated records and
+//@ reports the median and 5th/95th percentile nanoseconds per call, with a
+//@ Mann–Whitney test of whether each one differs from the first by more
+//@ than noise.
+//@
+//@ Both the listings and the benchmarks are of `#[inline(never)]` functions,
+//@ which is not how the strategies would be called in practice. `inlined`
+//@ puts each one in a loop, an iterator chain and a field of a larger struct,
+//@ where the compiler is free to inline it, and the tooling covers those too.
+//@
+//@ ### Method chaining
+//@
+//@ `record` also exposes the operations as methods, which gives two more ways
+//@ to write the update: a chain of consuming methods, each returning a new
+//@ record, and a chain of `&mut self` methods on the one record.
+
+/// immutable record, method-chaining style
+#[inline(never)]
+pub fn update_record_with_method_chain(record: Record) -> Record {
+    record.toggled().incremented().accumulated()
+}
+
+/// mutate in-place, method-chaining style
+#[inline(never)]
+pub fn update_record_with_mut_method_chain(record: &mut Record) {
+    record.toggle().increment().accumulate();
+}
+
+//@ The `records-derive` crate in this workspace brings the same style to other
+//@ structs: `#[derive(Functional)]` writes `with_a`, `map_a`, `set_a` and
+//@ `modify_a` for every field `a`, each one the struct update syntax of
+//@ `get_toggled_record` or its in-place counterpart.
+//@
 //@ ---
 //@
 //@ ### Footnotes
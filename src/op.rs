@@ -0,0 +1,219 @@
+//@ # Operations as values
+//@
+//@ Every `update_record_*` function in the article runs the same three steps,
+//@ toggle → increment → accumulate, written out by hand. [`RecordOp`] makes
+//@ each step a value, so a sequence of steps can be built at run time, stored,
+//@ parsed from a string, and applied either by value or in place.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    Record, accumulate_record, get_accumulated_record, get_incremented_record, get_toggled_record,
+    increment_record, toggle_record,
+};
+
+/// one step of an update
+#[derive(Copy, Clone, Debug)]
+pub enum RecordOp {
+    /// `c = !c`
+    Toggle,
+    /// `a = a + 1`
+    Increment,
+    /// `(a, b) = (a + b, a)`
+    Accumulate,
+    /// any other function from record to record
+    Custom(fn(Record) -> Record),
+}
+
+impl RecordOp {
+    /// the steps every `update_record_*` strategy takes, in order
+    pub const ARTICLE: [RecordOp; 3] =
+        [RecordOp::Toggle, RecordOp::Increment, RecordOp::Accumulate];
+
+    /// the functional style: consume the record and return a new one
+    pub fn apply(self, record: Record) -> Record {
+        match self {
+            RecordOp::Toggle => get_toggled_record(record),
+            RecordOp::Increment => get_incremented_record(record),
+            RecordOp::Accumulate => get_accumulated_record(record),
+            RecordOp::Custom(f) => f(record),
+        }
+    }
+
+    /// the imperative style: update the record through a `&mut`
+    pub fn apply_in_place(self, record: &mut Record) {
+        match self {
+            RecordOp::Toggle => toggle_record(record),
+            RecordOp::Increment => increment_record(record),
+            RecordOp::Accumulate => accumulate_record(record),
+            RecordOp::Custom(f) => *record = f(*record),
+        }
+    }
+
+    /// a pipeline of this step followed by `next`
+    pub fn then(self, next: RecordOp) -> Pipeline {
+        Pipeline::from(vec![self, next])
+    }
+}
+
+//@ ## Pipelines
+//@
+//@ A [`Pipeline`] is an ordered list of steps. It is applied left to right, so
+//@ `Pipeline::from(RecordOp::ARTICLE)` computes the same record as every
+//@ strategy in the article.
+
+/// a sequence of [`RecordOp`]s, applied in order
+#[derive(Clone, Debug, Default)]
+pub struct Pipeline {
+    ops: Vec<RecordOp>,
+}
+
+impl Pipeline {
+    /// the empty pipeline, which leaves a record unchanged
+    pub fn new() -> Pipeline {
+        Pipeline::default()
+    }
+
+    /// this pipeline followed by `op`
+    pub fn then(mut self, op: RecordOp) -> Pipeline {
+        self.ops.push(op);
+        self
+    }
+
+    pub fn ops(&self) -> &[RecordOp] {
+        &self.ops
+    }
+
+    pub fn apply(&self, record: Record) -> Record {
+        self.ops.iter().fold(record, |record, op| op.apply(record))
+    }
+
+    pub fn apply_in_place(&self, record: &mut Record) {
+        for op in &self.ops {
+            op.apply_in_place(record);
+        }
+    }
+}
+
+impl From<Vec<RecordOp>> for Pipeline {
+    fn from(ops: Vec<RecordOp>) -> Pipeline {
+        Pipeline { ops }
+    }
+}
+
+impl<const N: usize> From<[RecordOp; N]> for Pipeline {
+    fn from(ops: [RecordOp; N]) -> Pipeline {
+        Pipeline { ops: ops.to_vec() }
+    }
+}
+
+impl FromIterator<RecordOp> for Pipeline {
+    fn from_iter<I: IntoIterator<Item = RecordOp>>(ops: I) -> Pipeline {
+        Pipeline {
+            ops: ops.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<RecordOp> for Pipeline {
+    fn extend<I: IntoIterator<Item = RecordOp>>(&mut self, ops: I) {
+        self.ops.extend(ops);
+    }
+}
+
+//@ ## Text
+//@
+//@ Steps are written by name and pipelines as a comma-separated list, e.g.
+//@ `toggle, increment, accumulate`, so a sequence can come from a config file
+//@ or the command line. A `Custom` step has no name to parse back from.
+
+impl fmt::Display for RecordOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RecordOp::Toggle => "toggle",
+            RecordOp::Increment => "increment",
+            RecordOp::Accumulate => "accumulate",
+            RecordOp::Custom(_) => "custom",
+        })
+    }
+}
+
+impl fmt::Display for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, op) in self.ops.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{op}")?;
+        }
+        Ok(())
+    }
+}
+
+/// a step name that is not `toggle`, `increment` or `accumulate`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOpError {
+    pub name: String,
+}
+
+impl fmt::Display for ParseOpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown operation `{}`; expected `toggle`, `increment` or `accumulate`",
+            self.name
+        )
+    }
+}
+
+impl Error for ParseOpError {}
+
+impl FromStr for RecordOp {
+    type Err = ParseOpError;
+
+    fn from_str(s: &str) -> Result<RecordOp, ParseOpError> {
+        match s.trim() {
+            "toggle" => Ok(RecordOp::Toggle),
+            "increment" => Ok(RecordOp::Increment),
+            "accumulate" => Ok(RecordOp::Accumulate),
+            name => Err(ParseOpError {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for Pipeline {
+    type Err = ParseOpError;
+
+    fn from_str(s: &str) -> Result<Pipeline, ParseOpError> {
+        if s.trim().is_empty() {
+            return Ok(Pipeline::new());
+        }
+        s.split(',').map(str::parse).collect()
+    }
+}
+
+//@ ## Strategies
+//@
+//@ The article's update, as a loop over [`RecordOp::ARTICLE`]. Whether the
+//@ compiler unrolls the loop and removes the `match` is what `cargo xtask asm`
+//@ is for.
+
+/// the article's steps as data, applied by value
+#[inline(never)]
+pub fn update_record_with_ops(record: Record) -> Record {
+    RecordOp::ARTICLE
+        .iter()
+        .fold(record, |record, op| op.apply(record))
+}
+
+/// the article's steps as data, applied in place
+#[inline(never)]
+pub fn update_record_with_ops_in_place(record: &mut Record) {
+    for op in RecordOp::ARTICLE {
+        op.apply_in_place(record);
+    }
+}
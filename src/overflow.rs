@@ -0,0 +1,358 @@
+//@ # Choosing what happens on overflow
+//@
+//@ The article's operations use plain `+` on `u32`. That is fine for reading
+//@ assembly, but it means `a + 1` panics in a debug build and silently wraps in
+//@ a release build, so the same program computes different things depending on
+//@ how it was compiled.
+//@
+//@ This module repeats every operation and every `update_record_*` strategy
+//@ three times, once for each of the overflow behaviours the standard library
+//@ offers:
+//@
+//@ * [`wrapping`] wraps around at `u32::MAX`, like a release build,
+//@ * [`saturating`] stops at `u32::MAX`,
+//@ * [`checked`] returns an [`OverflowError`] naming the field that would have
+//@   overflowed.
+//@
+//@ Each behaviour is the same in every build profile. The strategies keep the
+//@ names and shapes they have in the article, so `overflow::wrapping::update_record_mut`
+//@ can be compared directly with `update_record_mut`.
+
+use std::error::Error;
+use std::fmt;
+
+/// a field of [`Record`](crate::Record) that an operation adds into
+///
+/// Both additions, `a + 1` and `a + b`, are stored in `a`; `b` only ever
+/// receives a copy of the old `a`, so it cannot overflow.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Field {
+    A,
+}
+
+/// the operation that overflowed
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `a + 1`
+    Increment,
+    /// `a + b`
+    Accumulate,
+}
+
+/// a [`checked`] operation would have overflowed `field`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OverflowError {
+    pub field: Field,
+    pub operation: Operation,
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Field::A => "a",
+        })
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operation::Increment => "increment",
+            Operation::Accumulate => "accumulate",
+        })
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} overflowed field `{}`", self.operation, self.field)
+    }
+}
+
+impl Error for OverflowError {}
+
+//@ `wrapping` and `saturating` differ only in which `u32` method does the
+//@ adding, so both are written out by one macro. The bodies are the article's,
+//@ with `+` replaced.
+
+macro_rules! infallible_mode {
+    ($add:ident) => {
+        use crate::Record;
+        use crate::registry::{Convention, Strategy};
+
+        pub fn toggle_record(record: &mut Record) {
+            record.c = !record.c;
+        }
+
+        pub fn increment_record(record: &mut Record) {
+            record.a = record.a.$add(1);
+        }
+
+        pub fn accumulate_record(record: &mut Record) {
+            let a = record.a;
+            record.a = record.a.$add(record.b);
+            record.b = a;
+        }
+
+        pub fn get_toggled_record(record: Record) -> Record {
+            Record {
+                c: !record.c,
+                ..record
+            }
+        }
+
+        pub fn get_incremented_record(record: Record) -> Record {
+            Record {
+                a: record.a.$add(1),
+                ..record
+            }
+        }
+
+        pub fn get_accumulated_record(record: Record) -> Record {
+            Record {
+                a: record.a.$add(record.b),
+                b: record.a,
+                ..record
+            }
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_refs(record: &mut Record) {
+            toggle_record(record);
+            increment_record(record);
+            accumulate_record(record);
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_ptrs(record: &mut Record) {
+            *record = get_toggled_record(*record);
+            *record = get_incremented_record(*record);
+            *record = get_accumulated_record(*record);
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_minimal_vars(record: &mut Record) {
+            *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record)));
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_shadowed_vars(record: &mut Record) {
+            let tmp = *record;
+            let tmp = get_toggled_record(tmp);
+            let tmp = get_incremented_record(tmp);
+            let tmp = get_accumulated_record(tmp);
+            *record = tmp;
+        }
+
+        #[inline(never)]
+        pub fn update_record_with_mut_tmp_var(record: &mut Record) {
+            let mut tmp = *record;
+            tmp = get_toggled_record(tmp);
+            tmp = get_incremented_record(tmp);
+            tmp = get_accumulated_record(tmp);
+            *record = tmp;
+        }
+
+        #[inline(never)]
+        pub fn update_record_no_refs(record: Record) -> Record {
+            let mut record = get_toggled_record(record);
+            record = get_incremented_record(record);
+            record = get_accumulated_record(record);
+            record
+        }
+
+        #[inline(never)]
+        pub fn update_record_mut(record: Record) -> Record {
+            let mut record = record;
+            toggle_record(&mut record);
+            increment_record(&mut record);
+            accumulate_record(&mut record);
+            record
+        }
+
+        #[inline(never)]
+        pub fn update_mut_record_mut(mut record: Record) -> Record {
+            toggle_record(&mut record);
+            increment_record(&mut record);
+            accumulate_record(&mut record);
+            record
+        }
+
+        /// every strategy from the article in this mode, in the same order
+        /// as [`registry::STRATEGIES`](crate::registry::STRATEGIES)
+        pub static STRATEGIES: &[Strategy] = &[
+            Strategy {
+                name: "update_record_with_refs",
+                convention: Convention::InPlace(update_record_with_refs),
+            },
+            Strategy {
+                name: "update_record_with_ptrs",
+                convention: Convention::InPlace(update_record_with_ptrs),
+            },
+            Strategy {
+                name: "update_record_with_minimal_vars",
+                convention: Convention::InPlace(update_record_with_minimal_vars),
+            },
+            Strategy {
+                name: "update_record_with_shadowed_vars",
+                convention: Convention::InPlace(update_record_with_shadowed_vars),
+            },
+            Strategy {
+                name: "update_record_with_mut_tmp_var",
+                convention: Convention::InPlace(update_record_with_mut_tmp_var),
+            },
+            Strategy {
+                name: "update_record_no_refs",
+                convention: Convention::ByValue(update_record_no_refs),
+            },
+            Strategy {
+                name: "update_record_mut",
+                convention: Convention::ByValue(update_record_mut),
+            },
+            Strategy {
+                name: "update_mut_record_mut",
+                convention: Convention::ByValue(update_mut_record_mut),
+            },
+        ];
+    };
+}
+
+/// operations and strategies that wrap around at `u32::MAX`
+pub mod wrapping {
+    infallible_mode!(wrapping_add);
+}
+
+/// operations and strategies that stop at `u32::MAX`
+pub mod saturating {
+    infallible_mode!(saturating_add);
+}
+
+//@ The checked strategies thread a `Result` through the same steps. A failing
+//@ operation leaves its record untouched, but what that means for the record
+//@ handed to an in-place strategy depends on where the strategy writes:
+//@
+//@ * `update_record_with_refs` and `update_record_with_ptrs` write back after
+//@   every step, so a failure on `increment` leaves the record toggled, and a
+//@   failure on `accumulate` leaves it toggled and incremented.
+//@ * `update_record_with_minimal_vars`, `update_record_with_shadowed_vars` and
+//@   `update_record_with_mut_tmp_var` work on a copy and only write back once
+//@   every step has succeeded, so a failure leaves the record as it was.
+//@
+//@ The by-value strategies consume their input, so they have nothing to leave
+//@ behind.
+
+/// operations and strategies that report overflow instead of producing a value
+pub mod checked {
+    use super::{Field, Operation, OverflowError};
+    use crate::Record;
+
+    fn add(field: Field, lhs: u32, rhs: u32, operation: Operation) -> Result<u32, OverflowError> {
+        lhs.checked_add(rhs)
+            .ok_or(OverflowError { field, operation })
+    }
+
+    /// toggling cannot overflow, so this is the only infallible operation
+    pub fn toggle_record(record: &mut Record) {
+        record.c = !record.c;
+    }
+
+    pub fn increment_record(record: &mut Record) -> Result<(), OverflowError> {
+        record.a = add(Field::A, record.a, 1, Operation::Increment)?;
+        Ok(())
+    }
+
+    pub fn accumulate_record(record: &mut Record) -> Result<(), OverflowError> {
+        let a = record.a;
+        record.a = add(Field::A, record.a, record.b, Operation::Accumulate)?;
+        record.b = a;
+        Ok(())
+    }
+
+    pub fn get_toggled_record(record: Record) -> Record {
+        Record {
+            c: !record.c,
+            ..record
+        }
+    }
+
+    pub fn get_incremented_record(record: Record) -> Result<Record, OverflowError> {
+        Ok(Record {
+            a: add(Field::A, record.a, 1, Operation::Increment)?,
+            ..record
+        })
+    }
+
+    pub fn get_accumulated_record(record: Record) -> Result<Record, OverflowError> {
+        Ok(Record {
+            a: add(Field::A, record.a, record.b, Operation::Accumulate)?,
+            b: record.a,
+            ..record
+        })
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_refs(record: &mut Record) -> Result<(), OverflowError> {
+        toggle_record(record);
+        increment_record(record)?;
+        accumulate_record(record)
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_ptrs(record: &mut Record) -> Result<(), OverflowError> {
+        *record = get_toggled_record(*record);
+        *record = get_incremented_record(*record)?;
+        *record = get_accumulated_record(*record)?;
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_minimal_vars(record: &mut Record) -> Result<(), OverflowError> {
+        *record = get_accumulated_record(get_incremented_record(get_toggled_record(*record))?)?;
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_shadowed_vars(record: &mut Record) -> Result<(), OverflowError> {
+        let tmp = *record;
+        let tmp = get_toggled_record(tmp);
+        let tmp = get_incremented_record(tmp)?;
+        let tmp = get_accumulated_record(tmp)?;
+        *record = tmp;
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn update_record_with_mut_tmp_var(record: &mut Record) -> Result<(), OverflowError> {
+        let mut tmp = *record;
+        tmp = get_toggled_record(tmp);
+        tmp = get_incremented_record(tmp)?;
+        tmp = get_accumulated_record(tmp)?;
+        *record = tmp;
+        Ok(())
+    }
+
+    #[inline(never)]
+    pub fn update_record_no_refs(record: Record) -> Result<Record, OverflowError> {
+        let mut record = get_toggled_record(record);
+        record = get_incremented_record(record)?;
+        record = get_accumulated_record(record)?;
+        Ok(record)
+    }
+
+    #[inline(never)]
+    pub fn update_record_mut(record: Record) -> Result<Record, OverflowError> {
+        let mut record = record;
+        toggle_record(&mut record);
+        increment_record(&mut record)?;
+        accumulate_record(&mut record)?;
+        Ok(record)
+    }
+
+    #[inline(never)]
+    pub fn update_mut_record_mut(mut record: Record) -> Result<Record, OverflowError> {
+        toggle_record(&mut record);
+        increment_record(&mut record)?;
+        accumulate_record(&mut record)?;
+        Ok(record)
+    }
+}
@@ -0,0 +1,142 @@
+//@ # Using `Record` from another crate
+//@
+//@ The article keeps `Record`'s fields private, which is fine while every
+//@ function that touches them lives in the same file. Other crates need a way
+//@ to build a record and read it back, so this module adds a constructor,
+//@ getters, and a textual form. The constructor and getters are written for
+//@ any field type, so [`generic`](generic.md) records have them too.
+//@
+//@ The fields stay private: the getters return copies, and a record can only
+//@ be changed from outside through the operations and strategies.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::{FromStr, ParseBoolError};
+
+use crate::{
+    GenericRecord, Record, accumulate_record, get_accumulated_record, get_incremented_record,
+    get_toggled_record, increment_record, toggle_record,
+};
+
+impl<T: Copy> GenericRecord<T> {
+    pub const fn new(a: T, b: T, c: bool) -> GenericRecord<T> {
+        GenericRecord { a, b, c }
+    }
+
+    pub const fn a(&self) -> T {
+        self.a
+    }
+
+    pub const fn b(&self) -> T {
+        self.b
+    }
+
+    pub const fn c(&self) -> bool {
+        self.c
+    }
+}
+
+//@ ## Methods
+//@
+//@ The article's operations are free functions so the assembly can be read one
+//@ step at a time. As methods they chain left to right, in the order they
+//@ happen: `record.toggled().incremented().accumulated()` reads better than
+//@ `get_accumulated_record(get_incremented_record(get_toggled_record(record)))`
+//@ and, since each method just calls the function, compiles to the same code.
+//@
+//@ The consuming methods return a new record. The `&mut self` ones update the
+//@ record in place and return it again, so they chain too.
+
+impl Record {
+    pub fn toggled(self) -> Record {
+        get_toggled_record(self)
+    }
+
+    pub fn incremented(self) -> Record {
+        get_incremented_record(self)
+    }
+
+    pub fn accumulated(self) -> Record {
+        get_accumulated_record(self)
+    }
+
+    pub fn toggle(&mut self) -> &mut Record {
+        toggle_record(self);
+        self
+    }
+
+    pub fn increment(&mut self) -> &mut Record {
+        increment_record(self);
+        self
+    }
+
+    pub fn accumulate(&mut self) -> &mut Record {
+        accumulate_record(self);
+        self
+    }
+}
+
+//@ ## Text
+//@
+//@ A record is written as the tuple of its fields, `(a, b, c)`, the same way
+//@ the equivalence checker has always printed them. Parsing accepts any
+//@ whitespace around the fields.
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.a, self.b, self.c)
+    }
+}
+
+/// why a string could not be parsed as a [`Record`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseRecordError {
+    /// not of the form `(a, b, c)`
+    Format,
+    A(ParseIntError),
+    B(ParseIntError),
+    C(ParseBoolError),
+}
+
+impl fmt::Display for ParseRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRecordError::Format => f.write_str("expected a record of the form `(a, b, c)`"),
+            ParseRecordError::A(error) => write!(f, "invalid field `a`: {error}"),
+            ParseRecordError::B(error) => write!(f, "invalid field `b`: {error}"),
+            ParseRecordError::C(error) => write!(f, "invalid field `c`: {error}"),
+        }
+    }
+}
+
+impl Error for ParseRecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseRecordError::Format => None,
+            ParseRecordError::A(error) | ParseRecordError::B(error) => Some(error),
+            ParseRecordError::C(error) => Some(error),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseRecordError;
+
+    fn from_str(s: &str) -> Result<Record, ParseRecordError> {
+        let fields = s
+            .trim()
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(ParseRecordError::Format)?;
+        let fields: Vec<&str> = fields.split(',').map(str::trim).collect();
+        let [a, b, c] = fields[..] else {
+            return Err(ParseRecordError::Format);
+        };
+        Ok(Record {
+            a: a.parse().map_err(ParseRecordError::A)?,
+            b: b.parse().map_err(ParseRecordError::B)?,
+            c: c.parse().map_err(ParseRecordError::C)?,
+        })
+    }
+}
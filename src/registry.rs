@@ -0,0 +1,143 @@
+//@ # A registry of update strategies
+//@
+//@ Every `update_record_*` function in the article is listed here once, by name,
+//@ along with how it is called. Tooling that needs to run, time, or disassemble
+//@ "all the strategies" walks this table instead of keeping its own list, so a
+//@ new strategy only has to be added in one place.
+//@
+//@ Strategies defined outside the article are named by their path relative to
+//@ the crate, like `lens::update_record_with_lenses`.
+
+use crate::batch::BATCHES;
+use crate::lens::{update_record_with_lenses, update_record_with_lenses_no_refs};
+use crate::op::{update_record_with_ops, update_record_with_ops_in_place};
+use crate::{
+    Record, update_mut_record_mut, update_record_mut, update_record_no_refs,
+    update_record_with_method_chain, update_record_with_minimal_vars,
+    update_record_with_mut_method_chain, update_record_with_mut_tmp_var, update_record_with_ptrs,
+    update_record_with_refs, update_record_with_shadowed_vars,
+};
+use crate::{big, generic, heap, inlined, layout, soa};
+
+//@ The strategies come in two shapes: the ones that update a record through a
+//@ `&mut Record`, and the ones that consume a `Record` and return a new one.
+
+/// how a strategy receives its record
+#[derive(Copy, Clone)]
+pub enum Convention {
+    /// `fn(&mut Record)`: update through a mutable reference
+    InPlace(fn(&mut Record)),
+    /// `fn(Record) -> Record`: consume the record and return a new one
+    ByValue(fn(Record) -> Record),
+}
+
+/// a named `update_record_*` function
+pub struct Strategy {
+    /// the function's path relative to the crate root
+    pub name: &'static str,
+    pub convention: Convention,
+}
+
+impl Strategy {
+    /// run the strategy on `record`, whatever its calling convention
+    pub fn apply(&self, record: Record) -> Record {
+        match self.convention {
+            Convention::InPlace(update) => {
+                let mut record = record;
+                update(&mut record);
+                record
+            }
+            Convention::ByValue(update) => update(record),
+        }
+    }
+
+    /// `true` if the strategy takes a `&mut Record`
+    pub fn is_in_place(&self) -> bool {
+        matches!(self.convention, Convention::InPlace(_))
+    }
+}
+
+/// every `update_record_*` function, in the order the article introduces them,
+/// followed by the ones from other modules
+pub static STRATEGIES: &[Strategy] = &[
+    Strategy {
+        name: "update_record_with_refs",
+        convention: Convention::InPlace(update_record_with_refs),
+    },
+    Strategy {
+        name: "update_record_with_ptrs",
+        convention: Convention::InPlace(update_record_with_ptrs),
+    },
+    Strategy {
+        name: "update_record_with_minimal_vars",
+        convention: Convention::InPlace(update_record_with_minimal_vars),
+    },
+    Strategy {
+        name: "update_record_with_shadowed_vars",
+        convention: Convention::InPlace(update_record_with_shadowed_vars),
+    },
+    Strategy {
+        name: "update_record_with_mut_tmp_var",
+        convention: Convention::InPlace(update_record_with_mut_tmp_var),
+    },
+    Strategy {
+        name: "update_record_no_refs",
+        convention: Convention::ByValue(update_record_no_refs),
+    },
+    Strategy {
+        name: "update_record_mut",
+        convention: Convention::ByValue(update_record_mut),
+    },
+    Strategy {
+        name: "update_mut_record_mut",
+        convention: Convention::ByValue(update_mut_record_mut),
+    },
+    Strategy {
+        name: "update_record_with_method_chain",
+        convention: Convention::ByValue(update_record_with_method_chain),
+    },
+    Strategy {
+        name: "update_record_with_mut_method_chain",
+        convention: Convention::InPlace(update_record_with_mut_method_chain),
+    },
+    Strategy {
+        name: "lens::update_record_with_lenses",
+        convention: Convention::InPlace(update_record_with_lenses),
+    },
+    Strategy {
+        name: "lens::update_record_with_lenses_no_refs",
+        convention: Convention::ByValue(update_record_with_lenses_no_refs),
+    },
+    Strategy {
+        name: "op::update_record_with_ops",
+        convention: Convention::ByValue(update_record_with_ops),
+    },
+    Strategy {
+        name: "op::update_record_with_ops_in_place",
+        convention: Convention::InPlace(update_record_with_ops_in_place),
+    },
+];
+
+/// the path of every function the assembly tooling studies: the strategies,
+/// the [batch](crate::batch) versions of each, then the ones that work on
+/// other representations of a record
+pub fn functions() -> Vec<&'static str> {
+    let strategies = STRATEGIES.iter().map(|strategy| strategy.name);
+    let batches = BATCHES
+        .iter()
+        .flat_map(|batch| [batch.update_all_name, batch.map_all_name]);
+    let others = soa::FUNCTIONS
+        .iter()
+        .chain(layout::FUNCTIONS)
+        .chain(generic::FUNCTIONS)
+        .chain(big::FUNCTIONS)
+        .chain(heap::FUNCTIONS)
+        .chain(inlined::FUNCTIONS)
+        .copied();
+    strategies.chain(batches).chain(others).collect()
+}
+
+/// look up a strategy by function name
+pub fn find(name: &str) -> Option<&'static Strategy> {
+    STRATEGIES.iter().find(|strategy| strategy.name == name)
+}
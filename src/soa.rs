@@ -0,0 +1,186 @@
+//@ # Struct of arrays
+//@
+//@ A `Record` is two `u32`s and a `bool`: nine bytes of data, padded to
+//@ twelve. In a slice of records the fields are interleaved, so updating every
+//@ `a` means striding over `b` and `c` too, and the article already caught the
+//@ compiler copying odd-sized pieces of padding around (`ldurh w12, [x0, #9]`).
+//@
+//@ [`RecordBatch`] stores the same records column by column instead: every
+//@ `a` in one `Vec<u32>`, every `b` in another, and every `c` as one bit of a
+//@ `Vec<u64>`. Each operation then runs down one or two columns of plain
+//@ integers, which is the shape vectorizers like best.
+//@
+//@ The operations come in both styles, as they do in the article: `&mut self`
+//@ methods that update the columns in place, and consuming methods that
+//@ return a new batch. `cargo xtask ir` and `cargo xtask asm` include the
+//@ update of a whole batch in each style, next to the slice-of-records
+//@ versions in [`batch`](crate::batch).
+
+use crate::Record;
+
+const BITS: usize = u64::BITS as usize;
+
+/// records stored column by column, with `c` packed into a bitset
+///
+/// Bits of `c` past `len` are always zero, so two batches holding the same
+/// records compare equal.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RecordBatch {
+    a: Vec<u32>,
+    b: Vec<u32>,
+    c: Vec<u64>,
+}
+
+impl RecordBatch {
+    pub fn new() -> RecordBatch {
+        RecordBatch::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> RecordBatch {
+        RecordBatch {
+            a: Vec::with_capacity(capacity),
+            b: Vec::with_capacity(capacity),
+            c: Vec::with_capacity(capacity.div_ceil(BITS)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.a.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.a.is_empty()
+    }
+
+    pub fn push(&mut self, record: Record) {
+        let i = self.len();
+        if i.is_multiple_of(BITS) {
+            self.c.push(0);
+        }
+        self.a.push(record.a);
+        self.b.push(record.b);
+        self.c[i / BITS] |= (record.c as u64) << (i % BITS);
+    }
+
+    pub fn get(&self, i: usize) -> Option<Record> {
+        Some(Record {
+            a: *self.a.get(i)?,
+            b: self.b[i],
+            c: self.c[i / BITS] >> (i % BITS) & 1 == 1,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Record> + '_ {
+        (0..self.len()).map(|i| self.get(i).expect("index is in bounds"))
+    }
+
+    // clear the bits of the last word that do not belong to a record
+    fn mask_tail(&mut self) {
+        let used = self.len() % BITS;
+        if let (Some(last), true) = (self.c.last_mut(), used > 0) {
+            *last &= (1 << used) - 1;
+        }
+    }
+}
+
+impl FromIterator<Record> for RecordBatch {
+    fn from_iter<I: IntoIterator<Item = Record>>(records: I) -> RecordBatch {
+        let mut batch = RecordBatch::new();
+        for record in records {
+            batch.push(record);
+        }
+        batch
+    }
+}
+
+impl From<&[Record]> for RecordBatch {
+    fn from(records: &[Record]) -> RecordBatch {
+        records.iter().copied().collect()
+    }
+}
+
+//@ ## Mutating the columns in place
+//@
+//@ Toggling flips 64 records per instruction; incrementing and accumulating
+//@ are loops over `u32`s.
+
+impl RecordBatch {
+    pub fn toggle_all(&mut self) {
+        for word in &mut self.c {
+            *word = !*word;
+        }
+        self.mask_tail();
+    }
+
+    pub fn increment_all(&mut self) {
+        for a in &mut self.a {
+            *a = *a + 1;
+        }
+    }
+
+    pub fn accumulate_all(&mut self) {
+        for (a, b) in self.a.iter_mut().zip(&mut self.b) {
+            let old = *a;
+            *a = *a + *b;
+            *b = old;
+        }
+    }
+
+    /// toggle, increment and accumulate every record, in place
+    #[inline(never)]
+    pub fn update_all(&mut self) {
+        self.toggle_all();
+        self.increment_all();
+        self.accumulate_all();
+    }
+}
+
+//@ ## Building new columns
+//@
+//@ The functional versions consume the batch and collect each column anew.
+//@ `into_iter().map().collect()` on a `Vec` reuses its allocation, and so
+//@ does zipping another column onto it: `accumulated` builds the new `a` in
+//@ the old `b`'s buffer and hands the old `a` over as the new `b`. Whether
+//@ this is really any different from the in-place version is up to the
+//@ optimizer.
+
+impl RecordBatch {
+    pub fn toggled(self) -> RecordBatch {
+        let mut batch = RecordBatch {
+            c: self.c.into_iter().map(|word| !word).collect(),
+            ..self
+        };
+        batch.mask_tail();
+        batch
+    }
+
+    pub fn incremented(self) -> RecordBatch {
+        RecordBatch {
+            a: self.a.into_iter().map(|a| a + 1).collect(),
+            ..self
+        }
+    }
+
+    pub fn accumulated(self) -> RecordBatch {
+        RecordBatch {
+            a: self
+                .b
+                .into_iter()
+                .zip(&self.a)
+                .map(|(b, a)| a + b)
+                .collect(),
+            b: self.a,
+            ..self
+        }
+    }
+
+    /// toggled, incremented and accumulated copies of every record
+    #[inline(never)]
+    pub fn updated(self) -> RecordBatch {
+        self.toggled().incremented().accumulated()
+    }
+}
+
+/// the two whole-batch updates, for `cargo xtask asm` and `cargo xtask ir` to
+/// compare with the per-record strategies
+pub const FUNCTIONS: &[&str] = &["soa::RecordBatch::update_all", "soa::RecordBatch::updated"];
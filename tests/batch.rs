@@ -0,0 +1,37 @@
+use records_in_rust::Record;
+use records_in_rust::batch::BATCHES;
+use records_in_rust::registry;
+
+fn records() -> Vec<Record> {
+    (0..100)
+        .map(|i| Record::new(i * 3, i * 7, i % 3 == 0))
+        .collect()
+}
+
+#[test]
+fn batches_match_their_strategy_record_by_record() {
+    for batch in BATCHES {
+        let strategy = registry::find(batch.strategy).unwrap();
+        let expected: Vec<Record> = records().into_iter().map(|r| strategy.apply(r)).collect();
+
+        let mut updated = records();
+        (batch.update_all)(&mut updated);
+        assert_eq!(updated, expected, "{}", batch.update_all_name);
+        assert_eq!(
+            (batch.map_all)(records()),
+            expected,
+            "{}",
+            batch.map_all_name
+        );
+    }
+}
+
+#[test]
+fn every_article_strategy_has_a_batch() {
+    let strategies: Vec<&str> = BATCHES.iter().map(|batch| batch.strategy).collect();
+    let article: Vec<&str> = registry::STRATEGIES[..strategies.len()]
+        .iter()
+        .map(|strategy| strategy.name)
+        .collect();
+    assert_eq!(strategies, article);
+}
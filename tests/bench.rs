@@ -0,0 +1,75 @@
+use records_in_rust::bench::{self, Config, Distribution, Report, compare, percentile};
+use records_in_rust::registry::{self, STRATEGIES};
+
+#[test]
+fn percentiles_interpolate_between_samples() {
+    let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(percentile(&sorted, 0.0), 1.0);
+    assert_eq!(percentile(&sorted, 0.5), 3.0);
+    assert_eq!(percentile(&sorted, 0.625), 3.5);
+    assert_eq!(percentile(&sorted, 1.0), 5.0);
+    assert_eq!(percentile(&[7.0], 0.95), 7.0);
+}
+
+#[test]
+fn identical_samples_are_not_a_significant_difference() {
+    let samples: Vec<f64> = (0..30).map(|i| 10.0 + f64::from(i % 5)).collect();
+    let comparison = compare(&samples, &samples);
+    assert_eq!(comparison.ratio, 1.0);
+    assert_eq!(comparison.z, 0.0);
+    assert!(!comparison.is_significant());
+}
+
+#[test]
+fn a_shifted_distribution_is_significant_in_the_right_direction() {
+    let baseline: Vec<f64> = (0..30).map(|i| 10.0 + f64::from(i % 7) * 0.1).collect();
+    let slower: Vec<f64> = baseline.iter().map(|x| x + 1.0).collect();
+    let comparison = compare(&baseline, &slower);
+    assert!(comparison.is_significant());
+    assert!(comparison.z > 0.0);
+    assert!(comparison.ratio > 1.0);
+    assert!(compare(&slower, &baseline).z < 0.0);
+}
+
+#[test]
+fn distributions_parse_and_stay_in_range() {
+    for distribution in [Distribution::Zero, Distribution::Small, Distribution::Large] {
+        assert_eq!(distribution.to_string().parse(), Ok(distribution));
+    }
+    assert!("uniform".parse::<Distribution>().is_err());
+
+    let records = Distribution::Small.records(1000, 7);
+    assert_eq!(records.len(), 1000);
+    assert!(records.iter().all(|r| r.a() <= 255 && r.b() <= 255));
+    assert!(records.iter().any(|r| r.a() > 2));
+    assert_eq!(records, Distribution::Small.records(1000, 7));
+    assert!(Distribution::Zero.records(10, 7).iter().all(|r| r.a() == 0));
+}
+
+#[test]
+fn every_strategy_gets_its_samples_and_a_row() {
+    let config = Config {
+        iterations: 100,
+        samples: 3,
+        inputs: 16,
+        ..Config::default()
+    };
+    let strategies: Vec<_> = STRATEGIES.iter().collect();
+    let measurements = bench::run(&strategies, &config);
+    assert_eq!(measurements.len(), STRATEGIES.len());
+    for (measurement, strategy) in measurements.iter().zip(STRATEGIES) {
+        assert_eq!(measurement.name, strategy.name);
+        assert_eq!(measurement.samples.len(), 3);
+        let summary = measurement.summary();
+        assert!(summary.p5 <= summary.median && summary.median <= summary.p95);
+    }
+
+    let report = Report {
+        config: &config,
+        measurements: &measurements,
+    }
+    .to_string();
+    let baseline = registry::find("update_record_with_refs").unwrap().name;
+    assert!(report.lines().nth(1).unwrap().ends_with(baseline));
+    assert_eq!(report.lines().count(), 2 + STRATEGIES.len());
+}
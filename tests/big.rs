@@ -0,0 +1,57 @@
+mod common;
+
+use common::records;
+use records_in_rust::big::{self, BigRecord, n0, n1, n4, n16, n128};
+use records_in_rust::{Record, update_record_no_refs};
+
+fn payload<const N: usize>(seed: u64) -> [u64; N] {
+    std::array::from_fn(|i| seed.wrapping_mul(i as u64 + 1))
+}
+
+/// `update_record_no_refs` and `update_mut_record_mut` of one size
+fn assert_keeps_the_payload<const N: usize>(updates: [fn(BigRecord<N>) -> BigRecord<N>; 2]) {
+    for record in records() {
+        let payload = payload(u64::from(record.a()));
+        let big = BigRecord::new(record.a(), record.b(), record.c(), payload);
+        let expected = update_record_no_refs(record);
+        for update in updates {
+            let updated = update(big);
+            assert_eq!(Record::from(updated), expected, "on {record}");
+            assert_eq!(updated.payload(), &payload, "on {record}");
+        }
+    }
+}
+
+#[test]
+fn every_size_computes_the_article_s_record_and_keeps_the_payload() {
+    assert_keeps_the_payload([n0::update_record_no_refs, n0::update_mut_record_mut]);
+    assert_keeps_the_payload([n1::update_record_no_refs, n1::update_mut_record_mut]);
+    assert_keeps_the_payload([n4::update_record_no_refs, n4::update_mut_record_mut]);
+    assert_keeps_the_payload([n16::update_record_no_refs, n16::update_mut_record_mut]);
+    assert_keeps_the_payload([n128::update_record_no_refs, n128::update_mut_record_mut]);
+}
+
+#[test]
+fn sizes_grow_from_an_empty_payload() {
+    let empty = size_of::<n0::BigRecord>();
+    assert!(empty >= size_of::<Record>());
+    assert_eq!(empty % align_of::<u64>(), 0);
+    for (n, size) in [
+        (1, size_of::<n1::BigRecord>()),
+        (4, size_of::<n4::BigRecord>()),
+        (16, size_of::<n16::BigRecord>()),
+        (128, size_of::<n128::BigRecord>()),
+    ] {
+        assert_eq!(size, empty + n * size_of::<u64>(), "payload of {n}");
+    }
+    let lengths: Vec<usize> = big::SIZES.iter().map(|&(n, _)| n).collect();
+    assert!(lengths.is_sorted(), "{lengths:?}");
+    assert_eq!(big::FUNCTIONS.len(), 2 * big::SIZES.len());
+}
+
+#[test]
+fn converting_to_a_big_record_zeroes_the_payload() {
+    let big = n4::BigRecord::from(Record::new(1, 2, true));
+    assert_eq!(big.payload(), &[0; 4]);
+    assert_eq!(Record::from(big), Record::new(1, 2, true));
+}
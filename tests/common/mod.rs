@@ -0,0 +1,38 @@
+use records_in_rust::Record;
+
+/// the records the module tests run every strategy on
+///
+/// `a` stays below 2^31, so the bit-packed layout can hold every one of them.
+pub fn records() -> impl Iterator<Item = Record> {
+    (0..200u32).map(|i| Record::new(i * 1_000_003, i * 7, i % 2 == 0))
+}
+
+/// what each of the article's eight strategies in `$module` returns for
+/// `$record`, in the article's order
+// not every test crate that includes this module uses it
+#[allow(unused_macros)]
+macro_rules! outputs {
+    ($module:ident, $record:expr) => {{
+        let record = $record;
+        let mut outputs = Vec::new();
+        for update in [
+            $module::update_record_with_refs,
+            $module::update_record_with_ptrs,
+            $module::update_record_with_minimal_vars,
+            $module::update_record_with_shadowed_vars,
+            $module::update_record_with_mut_tmp_var,
+        ] {
+            let mut updated = record;
+            update(&mut updated);
+            outputs.push(updated);
+        }
+        for update in [
+            $module::update_record_no_refs,
+            $module::update_record_mut,
+            $module::update_mut_record_mut,
+        ] {
+            outputs.push(update(record));
+        }
+        outputs
+    }};
+}
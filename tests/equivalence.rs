@@ -0,0 +1,86 @@
+use records_in_rust::equivalence::{self, MAX_FIELD};
+use records_in_rust::registry::{Convention, Strategy};
+use records_in_rust::{Record, update_record_no_refs};
+
+#[test]
+fn every_update_strategy_computes_the_same_record() {
+    if let Err(divergence) = equivalence::check(100_000, 0x5eed) {
+        panic!("{divergence}");
+    }
+}
+
+/// the first `accumulate_record`: `b` gets the new `a` rather than the old one
+fn accumulate_into_both(record: Record) -> Record {
+    let a = record.a() + 1 + record.b();
+    Record::new(a, a, !record.c())
+}
+
+static DIVERGENT: &[Strategy] = &[
+    Strategy {
+        name: "update_record_no_refs",
+        convention: Convention::ByValue(update_record_no_refs),
+    },
+    Strategy {
+        name: "accumulate_into_both",
+        convention: Convention::ByValue(accumulate_into_both),
+    },
+];
+
+#[test]
+fn check_reports_a_divergent_strategy() {
+    let divergence = equivalence::check_with(DIVERGENT, MAX_FIELD, 1_000, 0x5eed).unwrap_err();
+    assert_eq!(divergence.input, Record::new(0, 1, false));
+    let names: Vec<_> = divergence.outputs.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["update_record_no_refs", "accumulate_into_both"]);
+}
+
+#[test]
+fn shrink_finds_the_smallest_divergent_record() {
+    let divergence = equivalence::compare_with(DIVERGENT, Record::new(1_803_221_547, 977, true));
+    let divergence = equivalence::shrink_with(DIVERGENT, divergence.unwrap());
+    assert_eq!(divergence.input, Record::new(0, 1, false));
+    assert_eq!(
+        divergence.outputs,
+        [
+            ("update_record_no_refs", Record::new(2, 1, true)),
+            ("accumulate_into_both", Record::new(2, 2, true)),
+        ]
+    );
+}
+
+#[test]
+fn compare_agrees_on_records_the_bug_does_not_touch() {
+    let record = Record::new(1_803_221_547, 0, true);
+    assert!(equivalence::compare_with(DIVERGENT, record).is_none());
+}
+
+/// agrees with `update_record_no_refs` only while both fields are zero
+fn zero_fields_only(record: Record) -> Record {
+    if record.a() == 0 && record.b() == 0 {
+        update_record_no_refs(record)
+    } else {
+        record
+    }
+}
+
+#[test]
+fn check_with_a_max_field_of_zero_only_generates_zeroes() {
+    static ZERO_ONLY: &[Strategy] = &[
+        Strategy {
+            name: "update_record_no_refs",
+            convention: Convention::ByValue(update_record_no_refs),
+        },
+        Strategy {
+            name: "zero_fields_only",
+            convention: Convention::ByValue(zero_fields_only),
+        },
+    ];
+    assert!(equivalence::check_with(ZERO_ONLY, 0, 1_000, 0x5eed).is_ok());
+    assert!(equivalence::check_with(ZERO_ONLY, 1, 1_000, 0x5eed).is_err());
+}
+
+#[test]
+fn no_strategies_never_diverge() {
+    assert!(equivalence::compare_with(&[], Record::new(1, 2, true)).is_none());
+    assert!(equivalence::check_with(&[], MAX_FIELD, 1_000, 0x5eed).is_ok());
+}
@@ -0,0 +1,40 @@
+use records_in_rust::Record;
+use records_in_rust::fusion::{self, Affine, NotAffine};
+use records_in_rust::op::{Pipeline, RecordOp};
+use records_in_rust::overflow::wrapping;
+
+#[test]
+fn fast_forward_matches_repeated_wrapping_updates() {
+    let mut record = Record::new(u32::MAX - 3, 17, true);
+    for n in 0..2_000 {
+        assert_eq!(
+            fusion::fast_forward(Record::new(u32::MAX - 3, 17, true), n),
+            record,
+            "n = {n}"
+        );
+        record = wrapping::update_record_no_refs(record);
+    }
+}
+
+#[test]
+fn fused_pipelines_match_step_by_step_application() {
+    let pipeline: Pipeline = "accumulate, increment, accumulate, toggle, accumulate, increment"
+        .parse()
+        .unwrap();
+    let fused = Affine::fuse(pipeline.ops().iter().copied()).unwrap();
+    let record = Record::new(5, 9, false);
+    assert_eq!(fused.apply(record), pipeline.apply(record));
+
+    let mut repeated = record;
+    for _ in 0..10 {
+        repeated = pipeline.apply(repeated);
+    }
+    assert_eq!(fused.pow(10).apply(record), repeated);
+    assert_eq!(fused.pow(0), Affine::IDENTITY);
+}
+
+#[test]
+fn custom_steps_cannot_be_fused() {
+    let ops = [RecordOp::Toggle, RecordOp::Custom(|record| record)];
+    assert_eq!(Affine::fuse(ops), Err(NotAffine { index: 1 }));
+}
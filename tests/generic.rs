@@ -0,0 +1,73 @@
+#[macro_use]
+mod common;
+
+use std::num::Wrapping;
+
+use common::records;
+use records_in_rust::generic::{
+    self, GenericRecord, of_i64, of_u8, of_u32, of_u64, of_u128, of_wrapping_u64,
+};
+use records_in_rust::update_record_no_refs;
+
+#[test]
+fn u32_instance_is_the_article_s_record() {
+    for record in records() {
+        assert_eq!(outputs!(of_u32, record), [update_record_no_refs(record); 8]);
+    }
+}
+
+#[test]
+fn wider_instances_compute_the_article_s_record_widened() {
+    for record in records() {
+        let expected = update_record_no_refs(record);
+        assert_eq!(outputs!(of_u64, record.widen()), [expected.widen(); 8]);
+        assert_eq!(outputs!(of_u128, record.widen()), [expected.widen(); 8]);
+        assert_eq!(outputs!(of_i64, record.widen()), [expected.widen(); 8]);
+    }
+}
+
+#[test]
+fn wider_instances_do_not_overflow_where_u32_would() {
+    let record = GenericRecord::new(u64::from(u32::MAX), 1, false);
+    let expected = GenericRecord::new(u64::from(u32::MAX) + 2, u64::from(u32::MAX) + 1, true);
+    assert_eq!(outputs!(of_u64, record), [expected; 8]);
+}
+
+#[test]
+fn signed_fields_accumulate_negative_numbers() {
+    let expected = GenericRecord::new(-11, -4, false);
+    assert_eq!(
+        outputs!(of_i64, GenericRecord::new(-5, -7, true)),
+        [expected; 8]
+    );
+}
+
+#[test]
+fn narrow_fields_fit_small_records() {
+    let expected = GenericRecord::new(121, 101, true);
+    assert_eq!(
+        outputs!(of_u8, GenericRecord::new(100, 20, false)),
+        [expected; 8]
+    );
+}
+
+#[test]
+fn wrapping_fields_wrap_in_every_profile() {
+    let expected = GenericRecord::new(Wrapping(3), Wrapping(0), true);
+    assert_eq!(
+        outputs!(
+            of_wrapping_u64,
+            GenericRecord::new(Wrapping(u64::MAX), Wrapping(3), false)
+        ),
+        [expected; 8]
+    );
+}
+
+#[test]
+fn every_instance_is_registered_with_the_tooling() {
+    let functions = records_in_rust::registry::functions();
+    for function in generic::FUNCTIONS {
+        assert!(functions.contains(function), "{function}");
+    }
+    assert_eq!(generic::FUNCTIONS.len(), 6 * 3);
+}
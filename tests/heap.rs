@@ -0,0 +1,106 @@
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use common::records;
+use records_in_rust::heap::{owned, shared};
+use records_in_rust::{Record, update_record_no_refs};
+
+// Counts allocations per thread, so tests running in parallel do not see each
+// other's.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// the number of allocations `f` makes on this thread
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn every_strategy_computes_the_article_s_record_and_logs_b() {
+    for record in records() {
+        let expected = update_record_no_refs(record);
+
+        let mut updated = owned::Record::from(record);
+        owned::update_record_with_refs(&mut updated);
+        let by_value = owned::update_record_no_refs(record.into());
+        let from_ref = owned::update_record_from_ref(&record.into());
+        for updated in [&updated, &by_value, &from_ref] {
+            assert_eq!(Record::from(updated), expected, "on {record}");
+            assert_eq!(updated.history(), [record.b()], "on {record}");
+        }
+
+        let mut updated = shared::Record::from(record);
+        shared::update_record_with_refs(&mut updated);
+        let by_value = shared::update_record_no_refs(record.into());
+        let from_ref = shared::update_record_from_ref(&record.into());
+        for updated in [&updated, &by_value, &from_ref] {
+            assert_eq!(Record::from(updated), expected, "on {record}");
+            assert_eq!(updated.history(), [record.b()], "on {record}");
+        }
+    }
+}
+
+#[test]
+fn moving_an_owned_record_does_not_allocate() {
+    let record = owned::Record::with_history(1, 2, false, Vec::with_capacity(4));
+    let (updated, count) = allocations(|| owned::update_record_no_refs(record));
+    assert_eq!(count, 0);
+    assert_eq!(updated.history(), [2]);
+}
+
+#[test]
+fn updating_an_owned_record_from_a_reference_clones_it() {
+    let record = owned::Record::with_history(1, 2, false, Vec::with_capacity(4));
+    let (updated, count) = allocations(|| owned::update_record_from_ref(&record));
+    assert!(count > 0);
+    assert_eq!(record.history(), []);
+    assert_eq!(updated.history(), [2]);
+}
+
+#[test]
+fn updating_an_unshared_record_writes_its_history_in_place() {
+    let record = shared::Record::with_history(1, 2, false, Vec::with_capacity(4));
+    let (updated, count) = allocations(|| shared::update_record_no_refs(record));
+    assert_eq!(count, 0);
+    assert_eq!(updated.history(), [2]);
+}
+
+#[test]
+fn updating_a_shared_record_copies_its_history_and_leaves_the_clone_alone() {
+    let record = shared::Record::with_history(1, 2, false, vec![7]);
+    let (clone, count) = allocations(|| record.clone());
+    assert_eq!(count, 0);
+    assert!(clone.shares_history_with(&record));
+
+    let (updated, count) = allocations(|| shared::update_record_no_refs(clone));
+    assert!(count > 0);
+    assert!(!updated.shares_history_with(&record));
+    assert_eq!(record.history(), [7]);
+    assert_eq!(updated.history(), [7, 2]);
+}
@@ -0,0 +1,70 @@
+use records_in_rust::inlined::{self, HARNESSES, Owner, ROUNDS};
+use records_in_rust::{Record, update_record_no_refs};
+
+/// records small enough that `ROUNDS` updates cannot overflow `a`
+fn small_records() -> impl Iterator<Item = Record> {
+    (0..200u32).map(|i| Record::new(i * 101, i * 7, i % 2 == 0))
+}
+
+fn rounds(record: Record, n: usize) -> Record {
+    (0..n).fold(record, |record, _| update_record_no_refs(record))
+}
+
+#[test]
+fn loops_and_chains_update_the_record_every_round() {
+    for record in small_records() {
+        let expected = rounds(record, ROUNDS);
+        for harness in HARNESSES {
+            if !harness.name.starts_with("inlined::owner_") {
+                assert_eq!(
+                    harness.apply(record),
+                    expected,
+                    "{} on {record}",
+                    harness.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn owners_update_their_record_once_and_keep_the_other_fields() {
+    let owners = [
+        inlined::owner_with_refs,
+        inlined::owner_with_ptrs,
+        inlined::owner_with_minimal_vars,
+        inlined::owner_with_shadowed_vars,
+        inlined::owner_with_mut_tmp_var,
+        inlined::owner_no_refs,
+        inlined::owner_mut,
+        inlined::owner_mut_record_mut,
+    ];
+    for record in small_records() {
+        for update in owners {
+            let mut owner = Owner::new(42, record);
+            update(&mut owner);
+            update(&mut owner);
+            assert_eq!(owner.record(), rounds(record, 2), "on {record}");
+            assert_eq!((owner.id(), owner.updates()), (42, 2));
+        }
+    }
+    for harness in HARNESSES {
+        if harness.name.starts_with("inlined::owner_") {
+            let record = Record::new(1, 2, false);
+            assert_eq!(harness.apply(record), update_record_no_refs(record));
+        }
+    }
+}
+
+#[test]
+fn every_strategy_has_a_harness_in_every_context() {
+    assert_eq!(HARNESSES.len(), 3 * 8);
+    for context in ["loop_", "chain_", "owner_"] {
+        let prefix = format!("inlined::{context}");
+        let count = HARNESSES
+            .iter()
+            .filter(|harness| harness.name.starts_with(&prefix))
+            .count();
+        assert_eq!(count, 8, "{context}");
+    }
+}
@@ -0,0 +1,64 @@
+#[macro_use]
+mod common;
+
+use std::mem::{align_of, size_of};
+
+use common::records;
+use records_in_rust::layout::{aligned, bits, c, packed};
+use records_in_rust::{Record, update_record_no_refs};
+
+#[test]
+fn layouts_have_the_documented_size_and_alignment() {
+    assert_eq!((size_of::<Record>(), align_of::<Record>()), (12, 4));
+    assert_eq!((size_of::<c::Record>(), align_of::<c::Record>()), (12, 4));
+    assert_eq!(
+        (size_of::<packed::Record>(), align_of::<packed::Record>()),
+        (9, 1)
+    );
+    assert_eq!(
+        (size_of::<aligned::Record>(), align_of::<aligned::Record>()),
+        (16, 16)
+    );
+    assert_eq!(
+        (size_of::<bits::Record>(), align_of::<bits::Record>()),
+        (8, 8)
+    );
+}
+
+fn converted<R: Into<Record>>(outputs: Vec<R>) -> Vec<Record> {
+    outputs.into_iter().map(Into::into).collect()
+}
+
+#[test]
+fn every_layout_computes_the_article_s_record() {
+    for record in records() {
+        let expected = [update_record_no_refs(record); 8];
+        let c = converted(outputs!(c, c::Record::from(record)));
+        assert_eq!(c, expected, "c on {record}");
+        let packed = converted(outputs!(packed, packed::Record::from(record)));
+        assert_eq!(packed, expected, "packed on {record}");
+        let aligned = converted(outputs!(aligned, aligned::Record::from(record)));
+        assert_eq!(aligned, expected, "aligned on {record}");
+        let bits = converted(outputs!(bits, bits::Record::try_from(record).unwrap()));
+        assert_eq!(bits, expected, "bits on {record}");
+    }
+}
+
+#[test]
+fn bit_packed_a_wraps_at_31_bits() {
+    let record = bits::Record::try_from(Record::new((1 << 31) - 1, 5, false)).unwrap();
+    let updated: Record = bits::update_record_no_refs(record).into();
+    assert_eq!(updated, Record::new(5, 0, true));
+}
+
+#[test]
+fn bit_packed_layout_rejects_a_32_bit_a() {
+    assert_eq!(
+        bits::Record::try_from(Record::new(1 << 31, 5, false)),
+        Err(bits::TooWide { a: 1 << 31 })
+    );
+    assert_eq!(
+        bits::Record::try_from(Record::new(u32::MAX, 0, true)),
+        Err(bits::TooWide { a: u32::MAX })
+    );
+}
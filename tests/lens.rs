@@ -0,0 +1,30 @@
+use records_in_rust::Record;
+use records_in_rust::lens::{self, Field, Lens};
+
+#[test]
+fn field_lenses_get_and_set_one_field() {
+    let record = Record::new(1, 2, false);
+    assert_eq!(lens::B.get(&record), 2);
+    assert_eq!(lens::A.set(record, 7), Record::new(7, 2, false));
+    assert_eq!(lens::C.modify(record, |c| !c), record.toggled());
+}
+
+#[test]
+fn composed_lenses_reach_into_nested_structs() {
+    let first: Field<(Record, u8), Record> = Field {
+        get: |pair| pair.0,
+        set: |pair, record| (record, pair.1),
+    };
+    let a = first.compose(lens::A);
+    let pair = (Record::new(1, 2, true), 9);
+    assert_eq!(a.get(&pair), 1);
+    assert_eq!(a.modify(pair, |a| a + 1), (Record::new(2, 2, true), 9));
+}
+
+#[test]
+fn lens_operations_match_the_methods() {
+    let record = Record::new(3, 4, true);
+    assert_eq!(lens::get_toggled_record(record), record.toggled());
+    assert_eq!(lens::get_incremented_record(record), record.incremented());
+    assert_eq!(lens::get_accumulated_record(record), record.accumulated());
+}
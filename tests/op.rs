@@ -0,0 +1,51 @@
+use records_in_rust::Record;
+use records_in_rust::op::{ParseOpError, Pipeline, RecordOp};
+
+fn swap(record: Record) -> Record {
+    Record::new(record.b(), record.a(), record.c())
+}
+
+#[test]
+fn article_pipeline_matches_the_methods() {
+    let record = Record::new(3, 4, false);
+    let expected = record.toggled().incremented().accumulated();
+    let pipeline = Pipeline::from(RecordOp::ARTICLE);
+    assert_eq!(pipeline.apply(record), expected);
+
+    let mut in_place = record;
+    pipeline.apply_in_place(&mut in_place);
+    assert_eq!(in_place, expected);
+}
+
+#[test]
+fn pipelines_apply_custom_ops_in_order() {
+    let pipeline = RecordOp::Increment
+        .then(RecordOp::Custom(swap))
+        .then(RecordOp::Increment);
+    assert_eq!(
+        pipeline.apply(Record::new(1, 5, true)),
+        Record::new(6, 2, true)
+    );
+    assert_eq!(pipeline.to_string(), "increment, custom, increment");
+    assert_eq!(
+        Pipeline::new().apply(Record::new(1, 5, true)),
+        Record::new(1, 5, true)
+    );
+}
+
+#[test]
+fn pipelines_parse_from_text() {
+    let pipeline: Pipeline = "accumulate,toggle , accumulate".parse().unwrap();
+    assert_eq!(pipeline.to_string(), "accumulate, toggle, accumulate");
+    assert_eq!(
+        pipeline.apply(Record::new(1, 2, false)),
+        Record::new(4, 3, true)
+    );
+    assert_eq!(
+        "toggle, reverse".parse::<Pipeline>().unwrap_err(),
+        ParseOpError {
+            name: "reverse".to_string()
+        }
+    );
+    assert!("".parse::<Pipeline>().unwrap().ops().is_empty());
+}
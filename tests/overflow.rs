@@ -0,0 +1,109 @@
+use records_in_rust::overflow::{Field, Operation, OverflowError, checked, saturating, wrapping};
+use records_in_rust::{Record, equivalence};
+
+#[test]
+fn wrapping_strategies_agree_across_the_whole_range() {
+    if let Err(divergence) =
+        equivalence::check_with(wrapping::STRATEGIES, u32::MAX, 100_000, 0x5eed)
+    {
+        panic!("{divergence}");
+    }
+}
+
+#[test]
+fn saturating_strategies_agree_across_the_whole_range() {
+    if let Err(divergence) =
+        equivalence::check_with(saturating::STRATEGIES, u32::MAX, 100_000, 0x5eed)
+    {
+        panic!("{divergence}");
+    }
+}
+
+#[test]
+fn overflow_error_names_the_field() {
+    let error = OverflowError {
+        field: Field::A,
+        operation: Operation::Accumulate,
+    };
+    assert_eq!(error.to_string(), "accumulate overflowed field `a`");
+}
+
+#[test]
+fn wrapping_wraps_around_to_zero() {
+    let mut record = Record::new(u32::MAX, 5, false);
+    wrapping::increment_record(&mut record);
+    assert_eq!(record, Record::new(0, 5, false));
+    assert_eq!(
+        wrapping::update_record_no_refs(Record::new(u32::MAX, 5, false)),
+        Record::new(5, 0, true)
+    );
+    assert_eq!(
+        wrapping::get_accumulated_record(Record::new(u32::MAX, 2, true)),
+        Record::new(1, u32::MAX, true)
+    );
+}
+
+#[test]
+fn saturating_stops_at_max() {
+    let mut record = Record::new(u32::MAX, 5, false);
+    saturating::increment_record(&mut record);
+    assert_eq!(record, Record::new(u32::MAX, 5, false));
+    assert_eq!(
+        saturating::update_record_no_refs(Record::new(u32::MAX, 5, false)),
+        Record::new(u32::MAX, u32::MAX, true)
+    );
+    assert_eq!(
+        saturating::get_accumulated_record(Record::new(u32::MAX - 1, 2, true)),
+        Record::new(u32::MAX, u32::MAX - 1, true)
+    );
+}
+
+#[test]
+fn checked_reports_the_overflowing_field_and_operation() {
+    let increment = OverflowError {
+        field: Field::A,
+        operation: Operation::Increment,
+    };
+    let accumulate = OverflowError {
+        field: Field::A,
+        operation: Operation::Accumulate,
+    };
+    let mut record = Record::new(u32::MAX, 0, false);
+    assert_eq!(checked::increment_record(&mut record), Err(increment));
+    let mut record = Record::new(u32::MAX, 1, false);
+    assert_eq!(checked::accumulate_record(&mut record), Err(accumulate));
+    assert_eq!(
+        checked::update_record_no_refs(Record::new(u32::MAX, 0, false)),
+        Err(increment)
+    );
+    assert_eq!(
+        checked::update_record_mut(Record::new(u32::MAX - 1, 1, false)),
+        Err(accumulate)
+    );
+    assert_eq!(
+        checked::update_record_no_refs(Record::new(u32::MAX - 1, 0, false)),
+        Ok(Record::new(u32::MAX, u32::MAX, true))
+    );
+}
+
+#[test]
+fn checked_in_place_strategies_leave_what_the_docs_say() {
+    let input = Record::new(u32::MAX - 1, 1, false);
+
+    let mut record = input;
+    assert!(checked::update_record_with_refs(&mut record).is_err());
+    assert_eq!(record, Record::new(u32::MAX, 1, true));
+    let mut record = input;
+    assert!(checked::update_record_with_ptrs(&mut record).is_err());
+    assert_eq!(record, Record::new(u32::MAX, 1, true));
+
+    for update in [
+        checked::update_record_with_minimal_vars,
+        checked::update_record_with_shadowed_vars,
+        checked::update_record_with_mut_tmp_var,
+    ] {
+        let mut record = input;
+        assert!(update(&mut record).is_err());
+        assert_eq!(record, input);
+    }
+}
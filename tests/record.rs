@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use records_in_rust::Record;
+use records_in_rust::record::ParseRecordError;
+
+#[test]
+fn getters_return_what_was_passed_to_new() {
+    let record = Record::new(1, 2, true);
+    assert_eq!((record.a(), record.b(), record.c()), (1, 2, true));
+    assert_eq!(Record::default(), Record::new(0, 0, false));
+}
+
+#[test]
+fn display_round_trips_through_from_str() {
+    let record = Record::new(u32::MAX, 7, true);
+    assert_eq!(record.to_string(), "(4294967295, 7, true)");
+    assert_eq!(record.to_string().parse(), Ok(record));
+    assert_eq!(" ( 1 ,2,  false ) ".parse(), Ok(Record::new(1, 2, false)));
+}
+
+#[test]
+fn from_str_reports_the_bad_field() {
+    assert_eq!(
+        "1, 2, true".parse::<Record>(),
+        Err(ParseRecordError::Format)
+    );
+    assert_eq!("(1, 2)".parse::<Record>(), Err(ParseRecordError::Format));
+    assert!(matches!(
+        "(1, -2, true)".parse::<Record>(),
+        Err(ParseRecordError::B(_))
+    ));
+    assert_eq!(
+        "(1, 2, yes)".parse::<Record>().unwrap_err().to_string(),
+        "invalid field `c`: provided string was not `true` or `false`"
+    );
+}
+
+#[test]
+fn equal_records_hash_alike() {
+    let records: HashSet<Record> = [Record::new(1, 2, true), Record::new(1, 2, true)].into();
+    assert_eq!(records.len(), 1);
+    assert_ne!(Record::new(1, 2, true), Record::new(1, 2, false));
+}
+
+#[test]
+fn methods_chain_in_the_order_they_apply() {
+    let record = Record::new(1, 2, false);
+    assert_eq!(
+        record.toggled().incremented().accumulated(),
+        Record::new(4, 2, true)
+    );
+
+    let mut record = record;
+    record.toggle().increment().accumulate();
+    assert_eq!(record, Record::new(4, 2, true));
+}
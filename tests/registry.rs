@@ -0,0 +1,36 @@
+use records_in_rust::registry::{self, STRATEGIES};
+
+// every source file that defines strategies, and its path from the crate root
+const SOURCES: &[(&str, &str)] = &[
+    ("", include_str!("../src/lib.rs")),
+    ("lens::", include_str!("../src/lens.rs")),
+    ("op::", include_str!("../src/op.rs")),
+];
+
+#[test]
+fn every_update_function_is_registered() {
+    let defined: Vec<String> = SOURCES
+        .iter()
+        .flat_map(|(path, source)| {
+            source
+                .lines()
+                .filter_map(|line| line.strip_prefix("pub fn "))
+                .filter_map(|rest| rest.split('(').next())
+                .filter(|name| name.starts_with("update_"))
+                .map(move |name| format!("{path}{name}"))
+        })
+        .collect();
+    let registered: Vec<&str> = STRATEGIES.iter().map(|strategy| strategy.name).collect();
+    assert_eq!(defined, registered);
+}
+
+#[test]
+fn strategies_are_found_by_name() {
+    for strategy in STRATEGIES {
+        assert!(std::ptr::eq(
+            registry::find(strategy.name).unwrap(),
+            strategy
+        ));
+    }
+    assert!(registry::find("update_record_with_magic").is_none());
+}
@@ -0,0 +1,43 @@
+use records_in_rust::Record;
+use records_in_rust::batch;
+use records_in_rust::soa::RecordBatch;
+
+// more than one word of `c`, and not a multiple of 64
+fn records() -> Vec<Record> {
+    (0..150)
+        .map(|i| Record::new(i * 3, i * 7, i % 3 == 0))
+        .collect()
+}
+
+#[test]
+fn batches_hold_the_records_pushed_into_them() {
+    let batch = RecordBatch::from(&records()[..]);
+    assert_eq!(batch.len(), 150);
+    assert_eq!(batch.iter().collect::<Vec<_>>(), records());
+    assert_eq!(batch.get(150), None);
+    assert!(RecordBatch::new().is_empty());
+}
+
+#[test]
+fn column_updates_match_record_updates() {
+    let mut expected = records();
+    batch::update_all_with_refs(&mut expected);
+
+    let mut batch = RecordBatch::from(&records()[..]);
+    batch.update_all();
+    assert_eq!(batch.iter().collect::<Vec<_>>(), expected);
+
+    let updated = RecordBatch::from(&records()[..]).updated();
+    assert_eq!(updated, batch);
+}
+
+#[test]
+fn toggling_twice_restores_the_batch() {
+    let batch = RecordBatch::from(&records()[..]);
+    let mut toggled = batch.clone();
+    toggled.toggle_all();
+    assert_ne!(toggled, batch);
+    toggled.toggle_all();
+    assert_eq!(toggled, batch);
+    assert_eq!(batch.clone().toggled().toggled(), batch);
+}
@@ -0,0 +1,60 @@
+use records_asm::Golden;
+
+use crate::{Result, Targets, golden_dir, strategy_names};
+
+/// `cargo xtask asm [--json] [--target TRIPLE]... [--all-targets] [NAME...]`
+///
+/// With target options, `--json` prints an array with one report per target.
+pub fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut json = false;
+    let mut targets = Targets::default();
+    let mut names = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            flag if targets.parse(flag, &mut args)? => {}
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`").into()),
+            _ => names.push(arg),
+        }
+    }
+    if names.is_empty() {
+        names = strategy_names();
+    }
+
+    let mut reports = Vec::new();
+    for build in targets.builds()? {
+        reports.push(build.report(names.iter().map(String::as_str))?);
+    }
+    if json && targets.any() {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    } else if json {
+        println!("{}", serde_json::to_string_pretty(&reports[0])?);
+    } else {
+        for report in &reports {
+            print!("{report}");
+        }
+    }
+    Ok(())
+}
+
+/// `cargo xtask bless [--target TRIPLE]... [--all-targets]`
+pub fn bless(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut targets = Targets::default();
+    while let Some(arg) = args.next() {
+        if !targets.parse(&arg, &mut args)? {
+            return Err(format!("unexpected argument `{arg}`").into());
+        }
+    }
+    let names = strategy_names();
+    for build in targets.builds()? {
+        let report = build.report(names.iter().map(String::as_str))?;
+        let golden = Golden::new(golden_dir(), &report.target);
+        golden.bless(&report)?;
+        println!(
+            "blessed {} functions in {}",
+            report.functions.len(),
+            golden.dir().display()
+        );
+    }
+    Ok(())
+}
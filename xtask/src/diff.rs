@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+
+use records_asm::{Arch, Diff};
+
+use crate::{Result, Targets};
+
+/// `cargo xtask diff [--json] [--target TRIPLE]... [--all-targets] LEFT RIGHT`
+///
+/// With target options, `--json` prints an object keyed by target triple.
+pub fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut json = false;
+    let mut targets = Targets::default();
+    let mut names = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            flag if targets.parse(flag, &mut args)? => {}
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`").into()),
+            _ => names.push(arg),
+        }
+    }
+    if names.len() != 2 {
+        return Err("`diff` compares two strategies; name both".into());
+    }
+
+    let mut diffs = BTreeMap::new();
+    for build in targets.builds()? {
+        let report = build.report(names.iter().map(String::as_str))?;
+        let [left, right] = &report.functions[..] else {
+            unreachable!("a report has one function per name");
+        };
+        let diff = Diff::new(
+            Arch::from_triple(&report.target),
+            &left.function,
+            &right.function,
+        );
+        if !json {
+            if targets.any() {
+                println!("# {}", report.target);
+            }
+            print!("{diff}");
+        }
+        diffs.insert(report.target, diff);
+    }
+    if json && targets.any() {
+        println!("{}", serde_json::to_string_pretty(&diffs)?);
+    } else if let Some(diff) = json.then(|| diffs.values().next()).flatten() {
+        println!("{}", serde_json::to_string_pretty(diff)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use records_asm::{Analysis, Build, Error};
+
+use crate::{Result, Targets, strategy_names};
+
+/// `cargo xtask ir [--json] [--target TRIPLE]... [--all-targets] [NAME...]`
+///
+/// With target options, `--json` prints an object keyed by target triple.
+pub fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut json = false;
+    let mut targets = Targets::default();
+    let mut names = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => json = true,
+            flag if targets.parse(flag, &mut args)? => {}
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`").into()),
+            _ => names.push(arg),
+        }
+    }
+    if names.is_empty() {
+        names = strategy_names();
+    }
+
+    let builds = targets.builds()?;
+    if json && targets.any() {
+        let mut by_target = BTreeMap::new();
+        for build in &builds {
+            let triple = build.triple().unwrap_or_default().to_string();
+            by_target.insert(triple, analyze(build, &names)?);
+        }
+        println!("{}", serde_json::to_string_pretty(&by_target)?);
+        return Ok(());
+    }
+    for build in &builds {
+        let analyses = analyze(build, &names)?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&analyses)?);
+            return Ok(());
+        }
+        if let Some(triple) = build.triple() {
+            println!("# {triple}");
+        }
+        print(&names, &analyses);
+    }
+    Ok(())
+}
+
+fn print(names: &[String], analyses: &[Analysis]) {
+    let width = names.iter().map(String::len).max().unwrap_or_default();
+    for (name, analysis) in names.iter().zip(analyses) {
+        let mut notes = Vec::new();
+        if analysis.memcpy {
+            notes.push("memcpy".to_string());
+        }
+        if analysis.writes_stack {
+            notes.push("stack".to_string());
+        }
+        if analysis.vectorized {
+            notes.push("vectorized".to_string());
+        }
+        notes.extend(analysis.calls.iter().map(|call| format!("calls {call}")));
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", notes.join(", "))
+        };
+        println!("{name:<width$} {}{notes}", analysis.verdict);
+    }
+}
+
+/// emit the LLVM IR and analyze `names` (paths relative to the crate)
+pub fn analyze(build: &Build, names: &[String]) -> records_asm::Result<Vec<Analysis>> {
+    let listing = build.ir()?;
+    names
+        .iter()
+        .map(|name| {
+            let path = format!("{}::{name}", build.crate_name());
+            listing
+                .function(&path)
+                .map(|function| function.analyze())
+                .ok_or(Error::MissingFunction(path))
+        })
+        .collect()
+}
@@ -0,0 +1,48 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use records_asm::{Build, Toolchain, literate};
+
+use crate::{Result, workspace};
+
+/// the machine the article was written on, whose assembly it shows
+pub const ARTICLE_TARGET: &str = "aarch64-apple-darwin";
+
+/// `cargo xtask listings`
+///
+/// Rewrites the listings and the `cargo --version` header in `src/lib.md`
+/// from one fresh release build for [`ARTICLE_TARGET`], whatever the host is,
+/// then builds the crate so that tango carries them over into `src/lib.rs`.
+pub fn run(mut args: impl Iterator<Item = String>) -> Result<()> {
+    if let Some(arg) = args.next() {
+        return Err(format!("unexpected argument `{arg}`").into());
+    }
+    let build = Build::new(workspace()).target(ARTICLE_TARGET);
+    if !build.target_installed()? {
+        return Err(records_asm::Error::TargetNotInstalled(ARTICLE_TARGET.to_string()).into());
+    }
+    let listing = build.listing()?;
+    let toolchain = Toolchain::detect()?;
+
+    let path = workspace().join("src").join("lib.md");
+    let markdown = fs::read_to_string(&path)?;
+    let regenerated = literate::regenerate(&markdown, &listing, &toolchain)?;
+    if regenerated == markdown {
+        println!("the listings in src/lib.md are up to date");
+        return Ok(());
+    }
+    fs::write(&path, regenerated)?;
+
+    // tango copies `lib.md` over `lib.rs` when it is the newer of the two
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let status = Command::new(cargo)
+        .current_dir(workspace())
+        .args(["build", "--package", "records-in-rust"])
+        .status()?;
+    if !status.success() {
+        return Err("`cargo build` failed after rewriting src/lib.md".into());
+    }
+    println!("rewrote the listings in src/lib.md and src/lib.rs for {ARTICLE_TARGET}");
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Developer tasks for `records-in-rust`, run with `cargo xtask <command>`.
+
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use records_asm::{Build, TARGETS};
+
+mod asm;
+mod diff;
+mod ir;
+mod listings;
+mod matrix;
+
+const USAGE: &str = "\
+usage: cargo xtask <command> [options]
+
+commands:
+    asm [--json] [NAME...]    release assembly of each strategy (default: all)
+    bless                     overwrite the golden assembly for this host
+    diff [--json] LEFT RIGHT  compare two strategies' assembly, instruction by
+                              instruction, up to register renaming and reordering
+    ir [--json] [NAME...]     copy-elision verdict for each strategy, from LLVM IR
+    listings                  re-emit the article's listings in src/lib.md and
+                              src/lib.rs, for aarch64-apple-darwin
+    matrix [--json] [NAME...] instruction count and verdict at every opt-level,
+                              with the default codegen units and with one
+
+asm, bless, diff and ir also take
+    --target TRIPLE           cross-compile for TRIPLE instead; may be repeated
+    --all-targets             every target the tooling can read
+Targets whose standard library is not installed are skipped with a note.
+";
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("asm") => asm::run(args),
+        Some("bless") => asm::bless(args),
+        Some("diff") => diff::run(args),
+        Some("ir") => ir::run(args),
+        Some("listings") => listings::run(args),
+        Some("matrix") => matrix::run(args),
+        _ => {
+            eprint!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// the root of the workspace, where `records-in-rust` lives
+fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside the workspace")
+        .to_path_buf()
+}
+
+/// where the golden assembly for each target triple is checked in
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// the `--target` and `--all-targets` options
+#[derive(Default)]
+struct Targets {
+    triples: Vec<String>,
+}
+
+impl Targets {
+    /// take `arg`, and its value from `args`, if it is a target option
+    fn parse(&mut self, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<bool> {
+        match arg {
+            "--target" => {
+                let triple = args.next().ok_or("`--target` needs a target triple")?;
+                self.triples.push(triple);
+            }
+            "--all-targets" => self.triples.extend(TARGETS.iter().map(|t| t.to_string())),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// `true` if any target was asked for, rather than the host
+    fn any(&self) -> bool {
+        !self.triples.is_empty()
+    }
+
+    /// a build of the workspace for each installed target, or for the host if
+    /// no target was asked for
+    fn builds(&self) -> Result<Vec<Build>> {
+        if !self.any() {
+            return Ok(vec![Build::new(workspace())]);
+        }
+        let mut builds = Vec::new();
+        for triple in &self.triples {
+            let build = Build::new(workspace()).target(triple);
+            if build.target_installed()? {
+                builds.push(build);
+            } else {
+                let error = records_asm::Error::TargetNotInstalled(triple.clone());
+                eprintln!("skipping {triple}: {error}");
+            }
+        }
+        if builds.is_empty() {
+            return Err("none of the requested targets is installed".into());
+        }
+        Ok(builds)
+    }
+}
+
+/// the names of every registered strategy and its batch versions
+fn strategy_names() -> Vec<String> {
+    records_in_rust::registry::functions()
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
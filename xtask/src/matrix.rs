@@ -0,0 +1,133 @@
+use records_asm::{Analysis, Build, Error, Profile, Verdict};
+use serde::Serialize;
+
+use crate::{Result, workspace};
+
+/// one build of the matrix
+#[derive(Serialize)]
+struct Column {
+    profile: Profile,
+    cells: Vec<Cell>,
+}
+
+/// one function in one build
+#[derive(Serialize)]
+struct Cell {
+    name: String,
+    instructions: usize,
+    analysis: Analysis,
+}
+
+/// `cargo xtask matrix [--json] [NAME...]`
+///
+/// Each `opt-level` gets two columns, side by side: the default codegen units,
+/// and a single one.
+pub fn run(args: impl Iterator<Item = String>) -> Result<()> {
+    let mut json = false;
+    let mut names = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{flag}`").into()),
+            _ => names.push(arg),
+        }
+    }
+    if names.is_empty() {
+        names = records_in_rust::registry::STRATEGIES
+            .iter()
+            .map(|strategy| strategy.name.to_string())
+            .collect();
+    }
+
+    let columns = Profile::OPT_LEVELS
+        .into_iter()
+        .flat_map(|opt_level| {
+            [false, true].map(|single| Profile::new(opt_level).single_codegen_unit(single))
+        })
+        .map(|profile| column(profile, &names))
+        .collect::<records_asm::Result<Vec<_>>>()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&columns)?);
+    } else {
+        print(&names, &columns);
+    }
+    Ok(())
+}
+
+/// build once with `profile` and look up every one of `names`
+fn column(profile: Profile, names: &[String]) -> records_asm::Result<Column> {
+    let build = Build::new(workspace()).profile(profile.clone());
+    let (listing, ir) = build.listings()?;
+    let cells = names
+        .iter()
+        .map(|name| {
+            let path = format!("{}::{name}", build.crate_name());
+            let function = listing.function(&path);
+            let analysis = ir.function(&path).map(|function| function.analyze());
+            match (function, analysis) {
+                (Some(function), Some(analysis)) => Ok(Cell {
+                    name: name.clone(),
+                    instructions: function.instructions().count(),
+                    analysis,
+                }),
+                _ => Err(Error::MissingFunction(path)),
+            }
+        })
+        .collect::<records_asm::Result<_>>()?;
+    Ok(Column { profile, cells })
+}
+
+fn print(names: &[String], columns: &[Column]) {
+    let cells: Vec<Vec<String>> = columns
+        .iter()
+        .map(|column| column.cells.iter().map(cell).collect())
+        .collect();
+    let name_width = names.iter().map(String::len).max().unwrap_or_default();
+    let widths: Vec<usize> = columns
+        .iter()
+        .zip(&cells)
+        .map(|(column, cells)| {
+            let header = column.profile.to_string().len();
+            cells.iter().map(String::len).fold(header, usize::max)
+        })
+        .collect();
+
+    let mut header = format!("{:<name_width$}", "");
+    for (column, width) in columns.iter().zip(&widths) {
+        header += &format!("  {:<width$}", column.profile.to_string());
+    }
+    println!("{}", header.trim_end());
+    for (row, name) in names.iter().enumerate() {
+        let mut line = format!("{name:<name_width$}");
+        for (cells, width) in cells.iter().zip(&widths) {
+            line += &format!("  {:<width$}", cells[row]);
+        }
+        println!("{}", line.trim_end());
+    }
+    println!();
+    println!("instructions, then how the record is written:");
+    println!("  in-place  through the record passed in");
+    println!("  second    into the caller's return slot");
+    println!("  copied    in place, then copied to the return slot");
+    println!("  both      in place, and separately into the return slot");
+    println!("  regs      not at all; the result is returned in registers");
+    println!("  +memcpy   the IR calls memcpy");
+    println!("  +calls    it calls other functions, which may do the writing");
+}
+
+fn cell(cell: &Cell) -> String {
+    let verdict = match cell.analysis.verdict {
+        Verdict::InPlace => "in-place",
+        Verdict::SecondStruct => "second",
+        Verdict::InPlaceThenCopied => "copied",
+        Verdict::Both => "both",
+        Verdict::Registers => "regs",
+    };
+    let memcpy = if cell.analysis.memcpy { "+memcpy" } else { "" };
+    let calls = if cell.analysis.calls.is_empty() {
+        ""
+    } else {
+        "+calls"
+    };
+    format!("{} {verdict}{memcpy}{calls}", cell.instructions)
+}
@@ -0,0 +1,9 @@
+use records_asm::literate;
+
+#[test]
+fn asm_listings_show_the_function_above_them() {
+    let article = include_str!("../../src/lib.md");
+    let mislabels = literate::check(article);
+    let message: Vec<_> = mislabels.iter().map(ToString::to_string).collect();
+    assert!(mislabels.is_empty(), "src/lib.md:\n{}", message.join("\n"));
+}
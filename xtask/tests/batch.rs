@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use records_asm::Build;
+use records_in_rust::batch::BATCHES;
+
+// The imperative loop is the baseline: whatever the compiler does with it, the
+// functional loops should get the same treatment.
+const BASELINE: &str = "batch::update_all_with_refs";
+
+#[test]
+fn functional_batches_vectorize_like_the_imperative_one() {
+    let build = Build::new(Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap());
+    let listing = build.ir().unwrap();
+    let vectorized = |name: &str| {
+        let path = format!("{}::{name}", build.crate_name());
+        listing.function(&path).unwrap().analyze().vectorized
+    };
+    let baseline = vectorized(BASELINE);
+    for batch in BATCHES {
+        for name in [batch.update_all_name, batch.map_all_name] {
+            assert_eq!(
+                vectorized(name),
+                baseline,
+                "{name} is {}vectorized, {BASELINE} is {}",
+                if vectorized(name) { "" } else { "not " },
+                if baseline { "" } else { "not" },
+            );
+        }
+    }
+}
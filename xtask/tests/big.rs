@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use records_asm::Build;
+use records_in_rust::big::SIZES;
+
+#[test]
+fn once_a_size_copies_every_larger_size_copies() {
+    let build = Build::new(Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap());
+    let listing = build.ir().unwrap();
+    for strategy in ["update_record_no_refs", "update_mut_record_mut"] {
+        let copies: Vec<(usize, bool)> = SIZES
+            .iter()
+            .map(|(n, module)| {
+                let path = format!("{}::{module}::{strategy}", build.crate_name());
+                (*n, listing.function(&path).unwrap().analyze().memcpy)
+            })
+            .collect();
+        assert!(
+            copies.is_sorted_by_key(|&(_, memcpy)| memcpy),
+            "{strategy}: {copies:?}"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use records_asm::{Build, Golden, TARGETS, Toolchain};
+use records_in_rust::registry;
+
+fn check(build: Build) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let report = build.report(registry::functions()).unwrap();
+
+    let golden = Golden::new(manifest_dir.join("tests/golden"), &report.target);
+    assert!(
+        golden.exists(),
+        "no golden assembly for {}; run `cargo xtask bless --target {}` to record it",
+        report.target,
+        report.target
+    );
+    let mismatches = golden.check(&report).unwrap();
+    let message: Vec<_> = mismatches.iter().map(ToString::to_string).collect();
+    assert!(
+        mismatches.is_empty(),
+        "{}\nif the change is expected, run `cargo xtask bless`",
+        message.join("\n")
+    );
+}
+
+fn workspace() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+#[test]
+fn update_strategies_match_their_golden_assembly() {
+    check(Build::new(workspace()));
+}
+
+// Cross-compiled only where the target's standard library is installed, so
+// the test passes on a machine with nothing but the host.
+#[test]
+fn cross_compiled_strategies_match_their_golden_assembly() {
+    let host = Toolchain::detect().unwrap().host;
+    for &target in TARGETS.iter().filter(|&&target| target != host) {
+        let build = Build::new(workspace()).target(target);
+        if build.target_installed().unwrap() {
+            check(build);
+        } else {
+            eprintln!("skipping {target}: not installed");
+        }
+    }
+}
@@ -0,0 +1,17 @@
+records_in_rust::batch::update_all_with_minimal_vars:
+	cbz	x1, .L0
+	mov	w8, #12
+	madd	x8, x1, x8, x0
+.L1:
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	w10, w9, [x0]
+	strb	w11, [x0, #8]
+	add	x0, x0, #12
+	cmp	x0, x8
+	b.ne	.L1
+.L0:
+	ret
@@ -0,0 +1,18 @@
+records_in_rust::batch::update_all_with_refs:
+	cbz	x1, .L0
+	add	x8, x1, x1, lsl #1
+	lsl	x8, x8, #2
+	add	x9, x0, #8
+.L1:
+	ldrb	w10, [x9]
+	eor	w10, w10, #0x1
+	strb	w10, [x9]
+	ldp	w10, w11, [x9, #-8]
+	add	w10, w10, #1
+	add	w11, w11, w10
+	stp	w11, w10, [x9, #-8]
+	add	x9, x9, #12
+	subs	x8, x8, #12
+	b.ne	.L1
+.L0:
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::big::n1::update_mut_record_mut:
+	ldrb	w9, [x0, #16]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #16]
+	ldp	w9, w10, [x0, #8]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	stp	w10, w9, [x0, #8]
+	ldr	x9, [x0, #16]
+	str	x9, [x8, #16]
+	ldr	q0, [x0]
+	str	q0, [x8]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::big::n128::update_mut_record_mut:
+	mov	x1, x0
+	mov	x0, x8
+	ldrb	w8, [x1, #1032]
+	eor	w8, w8, #0x1
+	strb	w8, [x1, #1032]
+	ldr	w8, [x1, #1024]
+	add	w8, w8, #1
+	ldr	w9, [x1, #1028]
+	add	w9, w9, w8
+	str	w9, [x1, #1024]
+	str	w8, [x1, #1028]
+	mov	w2, #1040
+	b	_memcpy
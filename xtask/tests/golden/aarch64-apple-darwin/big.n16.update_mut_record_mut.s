@@ -0,0 +1,19 @@
+records_in_rust::big::n16::update_mut_record_mut:
+	ldrb	w9, [x0, #136]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #136]
+	ldp	w9, w10, [x0, #128]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	stp	w10, w9, [x0, #128]
+	ldp	q0, q1, [x0, #96]
+	stp	q0, q1, [x8, #96]
+	ldp	q0, q1, [x0, #32]
+	stp	q0, q1, [x8, #32]
+	ldp	q1, q0, [x0, #64]
+	stp	q1, q0, [x8, #64]
+	ldp	q1, q0, [x0]
+	stp	q1, q0, [x8]
+	ldr	q0, [x0, #128]
+	str	q0, [x8, #128]
+	ret
@@ -0,0 +1,17 @@
+records_in_rust::big::n16::update_record_no_refs:
+	ldp	w9, w10, [x0, #128]
+	ldrb	w11, [x0, #136]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	ldp	q0, q1, [x0, #64]
+	stp	q0, q1, [x8, #64]
+	ldp	q0, q1, [x0, #96]
+	stp	q0, q1, [x8, #96]
+	ldp	q0, q1, [x0]
+	stp	q0, q1, [x8]
+	ldp	q0, q1, [x0, #32]
+	stp	q0, q1, [x8, #32]
+	add	w10, w9, w10
+	stp	w10, w9, [x8, #128]
+	strb	w11, [x8, #136]
+	ret
@@ -0,0 +1,11 @@
+records_in_rust::big::n2::update_mut_record_mut:
+	ldrb	w9, [x0, #24]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #24]
+	ldp	w9, w10, [x0, #16]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	stp	w10, w9, [x0, #16]
+	ldp	q0, q1, [x0]
+	stp	q0, q1, [x8]
+	ret
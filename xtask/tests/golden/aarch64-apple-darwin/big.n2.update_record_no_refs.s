@@ -0,0 +1,11 @@
+records_in_rust::big::n2::update_record_no_refs:
+	ldp	w9, w10, [x0, #16]
+	ldrb	w11, [x0, #24]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	ldr	q0, [x0]
+	str	q0, [x8]
+	add	w10, w9, w10
+	stp	w10, w9, [x8, #16]
+	strb	w11, [x8, #24]
+	ret
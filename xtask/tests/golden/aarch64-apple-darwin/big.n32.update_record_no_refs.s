@@ -0,0 +1,27 @@
+records_in_rust::big::n32::update_record_no_refs:
+	ldr	w9, [x0, #256]
+	ldr	w10, [x0, #260]
+	ldrb	w11, [x0, #264]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	ldp	q0, q1, [x0, #192]
+	stp	q0, q1, [x8, #192]
+	ldp	q0, q1, [x0, #224]
+	stp	q0, q1, [x8, #224]
+	ldp	q0, q1, [x0, #128]
+	stp	q0, q1, [x8, #128]
+	ldp	q0, q1, [x0, #160]
+	stp	q0, q1, [x8, #160]
+	ldp	q0, q1, [x0, #64]
+	stp	q0, q1, [x8, #64]
+	ldp	q0, q1, [x0, #96]
+	stp	q0, q1, [x8, #96]
+	ldp	q0, q1, [x0]
+	stp	q0, q1, [x8]
+	ldp	q0, q1, [x0, #32]
+	stp	q0, q1, [x8, #32]
+	add	w10, w9, w10
+	str	w10, [x8, #256]
+	str	w9, [x8, #260]
+	strb	w11, [x8, #264]
+	ret
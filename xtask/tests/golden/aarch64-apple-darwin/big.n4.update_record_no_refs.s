@@ -0,0 +1,11 @@
+records_in_rust::big::n4::update_record_no_refs:
+	ldp	w9, w10, [x0, #32]
+	ldrb	w11, [x0, #40]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	ldp	q0, q1, [x0]
+	stp	q0, q1, [x8]
+	add	w10, w9, w10
+	stp	w10, w9, [x8, #32]
+	strb	w11, [x8, #40]
+	ret
@@ -0,0 +1,23 @@
+records_in_rust::big::n64::update_record_no_refs:
+	stp	x22, x21, [sp, #-48]!
+	stp	x20, x19, [sp, #16]
+	stp	x29, x30, [sp, #32]
+	add	x29, sp, #32
+	mov	x1, x0
+	mov	x19, x8
+	ldr	w8, [x0, #512]
+	ldr	w20, [x0, #516]
+	ldrb	w9, [x0, #520]
+	eor	w21, w9, #0x1
+	add	w22, w8, #1
+	mov	x0, x19
+	mov	w2, #512
+	bl	_memcpy
+	add	w8, w22, w20
+	str	w8, [x19, #512]
+	str	w22, [x19, #516]
+	strb	w21, [x19, #520]
+	ldp	x29, x30, [sp, #32]
+	ldp	x20, x19, [sp, #16]
+	ldp	x22, x21, [sp], #48
+	ret
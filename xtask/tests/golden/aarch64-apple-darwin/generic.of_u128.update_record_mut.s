@@ -0,0 +1,17 @@
+records_in_rust::generic::of_u128::update_record_mut:
+	ldrb	w9, [x0, #32]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #32]
+	ldp	x10, x9, [x0]
+	adds	x10, x10, #1
+	cinc	x9, x9, hs
+	ldp	x12, x11, [x0, #16]
+	adds	x12, x12, x10
+	adc	x11, x11, x9
+	stp	x12, x11, [x0]
+	stp	x10, x9, [x0, #16]
+	ldp	q0, q1, [x0, #16]
+	stp	q0, q1, [x8, #16]
+	ldr	q1, [x0]
+	str	q1, [x8]
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::generic::of_u128::update_record_no_refs:
+	ldp	x10, x9, [x0]
+	ldp	x12, x11, [x0, #16]
+	ldrb	w13, [x0, #32]
+	eor	w13, w13, #0x1
+	adds	x10, x10, #1
+	cinc	x9, x9, hs
+	adds	x12, x10, x12
+	adc	x11, x9, x11
+	stp	x12, x11, [x8]
+	stp	x10, x9, [x8, #16]
+	strb	w13, [x8, #32]
+	ret
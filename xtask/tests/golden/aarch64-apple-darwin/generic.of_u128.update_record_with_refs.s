@@ -0,0 +1,13 @@
+records_in_rust::generic::of_u128::update_record_with_refs:
+	ldrb	w8, [x0, #32]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #32]
+	ldp	x9, x8, [x0]
+	adds	x9, x9, #1
+	cinc	x8, x8, hs
+	ldp	x11, x10, [x0, #16]
+	adds	x11, x11, x9
+	adc	x10, x10, x8
+	stp	x11, x10, [x0]
+	stp	x9, x8, [x0, #16]
+	ret
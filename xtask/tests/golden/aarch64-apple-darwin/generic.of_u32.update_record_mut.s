@@ -0,0 +1,14 @@
+records_in_rust::generic::of_u32::update_record_mut:
+# same code as records_in_rust::update_record_mut
+	ldrb	w9, [x0, #8]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #8]
+	ldp	w9, w10, [x0]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	stp	w10, w9, [x0]
+	ldr	w9, [x0, #8]
+	str	w9, [x8, #8]
+	ldr	x9, [x0]
+	str	x9, [x8]
+	ret
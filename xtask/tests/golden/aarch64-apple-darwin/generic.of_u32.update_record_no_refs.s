@@ -0,0 +1,10 @@
+records_in_rust::generic::of_u32::update_record_no_refs:
+# same code as records_in_rust::update_record_no_refs
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	eor	w11, w11, #0x1
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	w10, w9, [x8]
+	strb	w11, [x8, #8]
+	ret
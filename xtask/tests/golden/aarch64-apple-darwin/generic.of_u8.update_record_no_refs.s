@@ -0,0 +1,10 @@
+records_in_rust::generic::of_u8::update_record_no_refs:
+# same code as records_in_rust::generic::of_u8::update_record_mut
+	ubfx	w8, w0, #8, #16
+	mvn	w9, w0
+	add	w8, w8, #1
+	add	w10, w8, w0, lsr #16
+	lsl	w0, w8, #16
+	bfi	w0, w10, #8, #8
+	bfxil	w0, w9, #0, #1
+	ret
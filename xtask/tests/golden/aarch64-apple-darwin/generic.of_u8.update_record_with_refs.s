@@ -0,0 +1,11 @@
+records_in_rust::generic::of_u8::update_record_with_refs:
+	ldrb	w8, [x0]
+	eor	w8, w8, #0x1
+	strb	w8, [x0]
+	ldrb	w8, [x0, #1]
+	add	w8, w8, #1
+	ldrb	w9, [x0, #2]
+	add	w9, w9, w8
+	strb	w9, [x0, #1]
+	strb	w8, [x0, #2]
+	ret
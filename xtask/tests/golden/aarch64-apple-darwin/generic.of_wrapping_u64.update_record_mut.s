@@ -0,0 +1,13 @@
+records_in_rust::generic::of_wrapping_u64::update_record_mut:
+	ldrb	w9, [x0, #16]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #16]
+	ldp	x9, x10, [x0]
+	add	x9, x9, #1
+	add	x10, x10, x9
+	stp	x10, x9, [x0]
+	ldr	x9, [x0, #16]
+	str	x9, [x8, #16]
+	ldr	q0, [x0]
+	str	q0, [x8]
+	ret
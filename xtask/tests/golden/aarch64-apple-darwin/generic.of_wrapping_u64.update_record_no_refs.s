@@ -0,0 +1,9 @@
+records_in_rust::generic::of_wrapping_u64::update_record_no_refs:
+	ldp	x9, x10, [x0]
+	ldrb	w11, [x0, #16]
+	eor	w11, w11, #0x1
+	add	x9, x9, #1
+	add	x10, x9, x10
+	stp	x10, x9, [x8]
+	strb	w11, [x8, #16]
+	ret
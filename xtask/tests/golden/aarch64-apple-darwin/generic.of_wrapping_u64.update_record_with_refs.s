@@ -0,0 +1,9 @@
+records_in_rust::generic::of_wrapping_u64::update_record_with_refs:
+	ldrb	w8, [x0, #16]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #16]
+	ldp	x8, x9, [x0]
+	add	x8, x8, #1
+	add	x9, x9, x8
+	stp	x9, x8, [x0]
+	ret
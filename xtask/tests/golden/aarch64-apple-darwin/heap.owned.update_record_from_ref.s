@@ -0,0 +1,46 @@
+records_in_rust::heap::owned::update_record_from_ref:
+	sub	sp, sp, #128
+	stp	d9, d8, [sp, #48]
+	stp	x24, x23, [sp, #64]
+	stp	x22, x21, [sp, #80]
+	stp	x20, x19, [sp, #96]
+	stp	x29, x30, [sp, #112]
+	add	x29, sp, #112
+	ldr	d8, [x0, #24]
+	ldrb	w23, [x0, #32]
+	ldr	x22, [x0, #16]
+	cbz	x22, .L0
+	mov	x24, x8
+	ldr	x21, [x0, #8]
+	lsl	x19, x22, #2
+	bl	__rustc::__rust_no_alloc_shim_is_unstable_v2
+	mov	x0, x19
+	mov	w1, #4
+	bl	__rustc::__rust_alloc
+	cbz	x0, .L1
+	mov	x20, x0
+	mov	x1, x21
+	mov	x2, x19
+	bl	_memcpy
+	mov	x8, x24
+	b	.L2
+.L0:
+	mov	w20, #4
+.L2:
+	str	d8, [sp, #32]
+	strb	w23, [sp, #40]
+	stp	x22, x20, [sp, #8]
+	str	x22, [sp, #24]
+	add	x0, sp, #8
+	bl	records_in_rust::heap::owned::update_record_no_refs
+	ldp	x29, x30, [sp, #112]
+	ldp	x20, x19, [sp, #96]
+	ldp	x22, x21, [sp, #80]
+	ldp	x24, x23, [sp, #64]
+	ldp	d9, d8, [sp, #48]
+	add	sp, sp, #128
+	ret
+.L1:
+	mov	w0, #4
+	mov	x1, x19
+	bl	alloc::raw_vec::handle_error
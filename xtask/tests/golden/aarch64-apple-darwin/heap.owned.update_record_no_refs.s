@@ -0,0 +1,59 @@
+records_in_rust::heap::owned::update_record_no_refs:
+.L0:
+	sub	sp, sp, #96
+	stp	x24, x23, [sp, #32]
+	stp	x22, x21, [sp, #48]
+	stp	x20, x19, [sp, #64]
+	stp	x29, x30, [sp, #80]
+	add	x29, sp, #80
+	mov	x19, x8
+	ldp	w8, w21, [x0, #24]
+	ldrb	w9, [x0, #32]
+	mov	w10, #1
+	bic	w20, w10, w9
+	ldr	q0, [x0]
+	str	q0, [x19]
+	ldr	x9, [x0, #16]
+	str	x9, [x19, #16]
+	add	w22, w8, #1
+	stp	w22, w21, [x19, #24]
+	strb	w20, [x19, #32]
+	ldr	q0, [x0]
+	str	q0, [sp]
+	ldr	x23, [x0, #16]
+	str	x23, [sp, #16]
+	ldr	x8, [sp]
+	cmp	x23, x8
+	b.ne	.L1
+.L2:
+	mov	x0, sp
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+.L3:
+.L1:
+	ldr	x8, [sp, #8]
+	str	w21, [x8, x23, lsl #2]
+	add	x8, x23, #1
+	add	w9, w22, w21
+	ldr	q0, [sp]
+	str	q0, [x19]
+	str	x8, [x19, #16]
+	stp	w9, w22, [x19, #24]
+	strb	w20, [x19, #32]
+	ldp	x29, x30, [sp, #80]
+	ldp	x20, x19, [sp, #64]
+	ldp	x22, x21, [sp, #48]
+	ldp	x24, x23, [sp, #32]
+	add	sp, sp, #96
+	ret
+.L4:
+.L5:
+	mov	x19, x0
+	ldr	x8, [sp]
+	cbz	x8, .L6
+	ldr	x0, [sp, #8]
+	lsl	x1, x8, #2
+	mov	w2, #4
+	bl	__rustc::__rust_dealloc
+.L6:
+	mov	x0, x19
+	bl	__Unwind_Resume
@@ -0,0 +1,34 @@
+records_in_rust::heap::owned::update_record_with_refs:
+	stp	x22, x21, [sp, #-48]!
+	stp	x20, x19, [sp, #16]
+	stp	x29, x30, [sp, #32]
+	add	x29, sp, #32
+	ldrb	w8, [x0, #32]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #32]
+	ldp	w8, w21, [x0, #24]
+	add	w20, w8, #1
+	str	w20, [x0, #24]
+	ldr	x22, [x0, #16]
+	ldr	x10, [x0]
+	mov	x9, x21
+	mov	x8, x20
+	cmp	x22, x10
+	b.eq	.L0
+.L1:
+	ldr	x10, [x0, #8]
+	str	w21, [x10, x22, lsl #2]
+	add	x10, x22, #1
+	str	x10, [x0, #16]
+	add	w8, w8, w9
+	stp	w8, w20, [x0, #24]
+	ldp	x29, x30, [sp, #32]
+	ldp	x20, x19, [sp, #16]
+	ldp	x22, x21, [sp], #48
+	ret
+.L0:
+	mov	x19, x0
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+	mov	x0, x19
+	ldp	w8, w9, [x19, #24]
+	b	.L1
@@ -0,0 +1,20 @@
+records_in_rust::heap::shared::update_record_from_ref:
+	ldr	d0, [x0, #8]
+	ldrb	w10, [x0, #16]
+	ldr	x9, [x0]
+	mov	w11, #1
+	ldadd	x11, x11, [x9]
+	tbnz	x11, #63, .L0
+	sub	sp, sp, #48
+	stp	x29, x30, [sp, #32]
+	add	x29, sp, #32
+	str	d0, [sp, #16]
+	strb	w10, [sp, #24]
+	str	x9, [sp, #8]
+	add	x0, sp, #8
+	bl	records_in_rust::heap::shared::update_record_no_refs
+	ldp	x29, x30, [sp, #32]
+	add	sp, sp, #48
+	ret
+.L0:
+	brk	#0x1
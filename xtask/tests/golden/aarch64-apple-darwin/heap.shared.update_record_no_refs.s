@@ -0,0 +1,62 @@
+records_in_rust::heap::shared::update_record_no_refs:
+.L0:
+	sub	sp, sp, #80
+	stp	x24, x23, [sp, #16]
+	stp	x22, x21, [sp, #32]
+	stp	x20, x19, [sp, #48]
+	stp	x29, x30, [sp, #64]
+	add	x29, sp, #64
+	mov	x19, x8
+	ldp	w8, w22, [x0, #8]
+	ldrb	w9, [x0, #16]
+	ldr	x10, [x0]
+	mov	w11, #1
+	bic	w21, w11, w9
+	add	w23, w8, #1
+	stp	w23, w22, [x19, #8]
+	strb	w21, [x19, #16]
+	str	x10, [x19]
+	str	x10, [sp, #8]
+.L1:
+	add	x0, sp, #8
+	bl	alloc::sync::Arc<T,A>::make_mut
+.L2:
+	mov	x20, x0
+	ldr	x24, [x0, #16]
+	ldr	x8, [x0]
+	cmp	x24, x8
+	b.ne	.L3
+.L4:
+	mov	x0, x20
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+.L5:
+.L3:
+	ldr	x8, [x20, #8]
+	str	w22, [x8, x24, lsl #2]
+	add	x8, x24, #1
+	str	x8, [x20, #16]
+	add	w8, w23, w22
+	ldr	x9, [sp, #8]
+	str	x9, [x19]
+	stp	w8, w23, [x19, #8]
+	strb	w21, [x19, #16]
+	ldp	x29, x30, [sp, #64]
+	ldp	x20, x19, [sp, #48]
+	ldp	x22, x21, [sp, #32]
+	ldp	x24, x23, [sp, #16]
+	add	sp, sp, #80
+	ret
+.L6:
+.L7:
+	mov	x19, x0
+	ldr	x8, [sp, #8]
+	mov	x9, #-1
+	ldaddl	x9, x8, [x8]
+	cmp	x8, #1
+	b.ne	.L8
+	dmb	ishld
+	add	x0, sp, #8
+	bl	alloc::sync::Arc<T,A>::drop_slow
+.L8:
+	mov	x0, x19
+	bl	__Unwind_Resume
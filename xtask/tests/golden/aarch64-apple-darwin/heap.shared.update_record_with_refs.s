@@ -0,0 +1,37 @@
+records_in_rust::heap::shared::update_record_with_refs:
+	stp	x24, x23, [sp, #-64]!
+	stp	x22, x21, [sp, #16]
+	stp	x20, x19, [sp, #32]
+	stp	x29, x30, [sp, #48]
+	add	x29, sp, #48
+	mov	x19, x0
+	ldrb	w8, [x0, #16]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #16]
+	ldr	w8, [x0, #8]
+	add	w21, w8, #1
+	str	w21, [x0, #8]
+	bl	alloc::sync::Arc<T,A>::make_mut
+	ldr	w22, [x19, #12]
+	ldr	x23, [x0, #16]
+	ldr	x8, [x0]
+	cmp	x23, x8
+	b.eq	.L0
+.L1:
+	ldr	x8, [x0, #8]
+	str	w22, [x8, x23, lsl #2]
+	add	x8, x23, #1
+	str	x8, [x0, #16]
+	ldr	w8, [x19, #8]
+	add	w8, w8, w22
+	stp	w8, w21, [x19, #8]
+	ldp	x29, x30, [sp, #48]
+	ldp	x20, x19, [sp, #32]
+	ldp	x22, x21, [sp, #16]
+	ldp	x24, x23, [sp], #64
+	ret
+.L0:
+	mov	x20, x0
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+	mov	x0, x20
+	b	.L1
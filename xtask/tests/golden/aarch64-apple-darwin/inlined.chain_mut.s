@@ -0,0 +1,26 @@
+records_in_rust::inlined::chain_mut:
+# same code as records_in_rust::inlined::chain_with_ptrs
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	add	w9, w9, #2
+	add	w10, w9, w10
+	add	w9, w9, w10
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w10, w9
+	and	w11, w11, #0x1
+	stp	w9, w10, [x8]
+	strb	w11, [x8, #8]
+	ldurh	w9, [x0, #9]
+	sturh	w9, [x8, #9]
+	ldrb	w9, [x0, #11]
+	strb	w9, [x8, #11]
+	ret
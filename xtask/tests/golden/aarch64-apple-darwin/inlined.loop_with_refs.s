@@ -0,0 +1,23 @@
+records_in_rust::inlined::loop_with_refs:
+	ldrb	w8, [x0, #8]
+	ldp	w9, w10, [x0]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	and	w8, w8, #0x1
+	strb	w8, [x0, #8]
+	add	w8, w9, w10
+	stp	w8, w10, [x0]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::inlined::owner_mut:
+# same code as records_in_rust::inlined::owner_mut_record_mut
+	ldp	w8, w9, [x0, #32]
+	ldrb	w10, [x0, #40]
+	mov	w11, #1
+	bic	w10, w11, w10
+	add	w8, w8, #1
+	add	w9, w8, w9
+	stp	w9, w8, [x0, #32]
+	strb	w10, [x0, #40]
+	ldr	x8, [x0, #24]
+	add	x8, x8, #1
+	str	x8, [x0, #24]
+	ret
@@ -0,0 +1,12 @@
+records_in_rust::inlined::owner_no_refs:
+	ldp	w8, w9, [x0, #32]
+	ldrb	w10, [x0, #40]
+	eor	w10, w10, #0x1
+	add	w8, w8, #1
+	add	w9, w8, w9
+	stp	w9, w8, [x0, #32]
+	strb	w10, [x0, #40]
+	ldr	x8, [x0, #24]
+	add	x8, x8, #1
+	str	x8, [x0, #24]
+	ret
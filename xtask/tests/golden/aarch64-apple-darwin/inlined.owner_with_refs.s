@@ -0,0 +1,12 @@
+records_in_rust::inlined::owner_with_refs:
+	ldrb	w8, [x0, #40]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #40]
+	ldp	w8, w9, [x0, #32]
+	add	w8, w8, #1
+	add	w9, w9, w8
+	stp	w9, w8, [x0, #32]
+	ldr	x8, [x0, #24]
+	add	x8, x8, #1
+	str	x8, [x0, #24]
+	ret
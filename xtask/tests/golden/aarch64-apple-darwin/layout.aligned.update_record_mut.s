@@ -0,0 +1,11 @@
+records_in_rust::layout::aligned::update_record_mut:
+	ldrb	w9, [x0, #8]
+	eor	w9, w9, #0x1
+	strb	w9, [x0, #8]
+	ldp	w9, w10, [x0]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	stp	w10, w9, [x0]
+	ldr	q0, [x0]
+	str	q0, [x8]
+	ret
@@ -0,0 +1,8 @@
+records_in_rust::layout::bits::update_record_mut:
+	add	x8, x0, #1
+	and	x9, x0, #0x80000000
+	add	x10, x8, x0, lsr #32
+	bfxil	x9, x10, #0, #31
+	bfi	x9, x8, #32, #31
+	eor	x0, x9, #0x80000000
+	ret
@@ -0,0 +1,10 @@
+records_in_rust::layout::c::update_record_with_refs:
+# same code as records_in_rust::update_record_with_refs
+	ldrb	w8, [x0, #8]
+	eor	w8, w8, #0x1
+	strb	w8, [x0, #8]
+	ldp	w8, w9, [x0]
+	add	w8, w8, #1
+	add	w9, w9, w8
+	stp	w9, w8, [x0]
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::layout::packed::update_record_mut:
+	ldrb	w9, [x0, #8]
+	mov	w10, #1
+	bic	w9, w10, w9
+	strb	w9, [x0, #8]
+	ldp	w10, w11, [x0]
+	add	w10, w10, #1
+	add	w11, w11, w10
+	stp	w11, w10, [x0]
+	strb	w9, [x8, #8]
+	ldr	x9, [x0]
+	str	x9, [x8]
+	ret
@@ -0,0 +1,10 @@
+records_in_rust::lens::update_record_with_lenses:
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	mov	w11, #1
+	bic	w10, w11, w10
+	add	w8, w8, #1
+	add	w9, w9, w8
+	stp	w9, w8, [x0]
+	strb	w10, [x0, #8]
+	ret
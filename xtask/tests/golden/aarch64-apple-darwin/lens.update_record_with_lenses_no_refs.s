@@ -0,0 +1,9 @@
+records_in_rust::lens::update_record_with_lenses_no_refs:
+	ldrb	w9, [x0, #8]
+	ldp	w10, w11, [x0]
+	eor	w9, w9, #0x1
+	add	w10, w10, #1
+	add	w11, w11, w10
+	stp	w11, w10, [x8]
+	strb	w9, [x8, #8]
+	ret
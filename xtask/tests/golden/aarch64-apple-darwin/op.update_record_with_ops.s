@@ -0,0 +1,14 @@
+records_in_rust::op::update_record_with_ops:
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	ldurh	w12, [x0, #9]
+	sturh	w12, [x8, #9]
+	ldrb	w12, [x0, #11]
+	strb	w12, [x8, #11]
+	add	w9, w9, #1
+	add	w10, w10, w9
+	mov	w12, #1
+	bic	w11, w12, w11
+	stp	w10, w9, [x8]
+	strb	w11, [x8, #8]
+	ret
@@ -0,0 +1,157 @@
+records_in_rust::soa::RecordBatch::update_all:
+	ldr	x8, [x0, #64]
+	cbz	x8, .L0
+	ldr	x12, [x0, #56]
+	lsl	x9, x8, #3
+	add	x8, x12, x9
+	sub	x10, x9, #8
+	mov	x9, x12
+	cmp	x10, #55
+	b.ls	.L1
+	lsr	x9, x10, #3
+	add	x10, x9, #1
+	and	x11, x10, #0x3ffffffffffffff8
+	add	x9, x12, x11, lsl #3
+	add	x12, x12, #32
+	and	x13, x10, #0x3ffffffffffffff8
+.L2:
+	ldp	q0, q1, [x12, #-32]
+	ldp	q2, q3, [x12]
+	mvn.16b	v0, v0
+	mvn.16b	v1, v1
+	mvn.16b	v2, v2
+	mvn.16b	v3, v3
+	stp	q0, q1, [x12, #-32]
+	stp	q2, q3, [x12], #64
+	subs	x13, x13, #8
+	b.ne	.L2
+	cmp	x10, x11
+	b.eq	.L3
+.L1:
+	ldr	x10, [x9]
+	mvn	x10, x10
+	str	x10, [x9], #8
+	cmp	x9, x8
+	b.ne	.L1
+.L3:
+	ldr	x9, [x0, #16]
+	ands	x10, x9, #0x3f
+	b.eq	.L4
+	mov	x11, #-1
+	lsl	x10, x11, x10
+	ldur	x11, [x8, #-8]
+	bic	x10, x11, x10
+	stur	x10, [x8, #-8]
+	ldr	x8, [x0, #8]
+	b	.L5
+.L0:
+	ldr	x9, [x0, #16]
+.L4:
+	ldr	x8, [x0, #8]
+	cbz	x9, .L6
+.L5:
+	add	x10, x8, x9, lsl #2
+	sub	x11, x10, x8
+	sub	x13, x11, #4
+	mov	x12, x8
+	cmp	x13, #12
+	b.lo	.L7
+	lsr	x11, x13, #2
+	add	x11, x11, #1
+	cmp	x13, #60
+	b.hs	.L8
+	mov	x13, #0
+	b	.L9
+.L8:
+	and	x12, x11, #0xc
+	and	x13, x11, #0x7ffffffffffffff0
+	add	x14, x8, #32
+	movi.4s	v0, #1
+	and	x15, x11, #0x7ffffffffffffff0
+.L10:
+	ldp	q1, q2, [x14, #-32]
+	ldp	q3, q4, [x14]
+	add.4s	v1, v1, v0
+	add.4s	v2, v2, v0
+	add.4s	v3, v3, v0
+	add.4s	v4, v4, v0
+	stp	q1, q2, [x14, #-32]
+	stp	q3, q4, [x14], #64
+	subs	x15, x15, #16
+	b.ne	.L10
+	cmp	x11, x13
+	b.eq	.L6
+	cbz	x12, .L11
+.L9:
+	and	x14, x11, #0x7ffffffffffffffc
+	add	x12, x8, x14, lsl #2
+	sub	x15, x13, x14
+	lsl	x13, x13, #2
+	movi.4s	v0, #1
+.L12:
+	ldr	q1, [x8, x13]
+	add.4s	v1, v1, v0
+	str	q1, [x8, x13]
+	add	x13, x13, #16
+	adds	x15, x15, #4
+	b.ne	.L12
+	cmp	x11, x14
+	b.eq	.L6
+.L7:
+	ldr	w11, [x12]
+	add	w11, w11, #1
+	str	w11, [x12], #4
+	cmp	x12, x10
+	b.ne	.L7
+.L6:
+	ldr	x10, [x0, #40]
+	cmp	x10, x9
+	csel	x9, x10, x9, lo
+	cbz	x9, .L13
+	ldr	x10, [x0, #32]
+	cmp	x9, #8
+	b.lo	.L14
+	lsl	x11, x9, #2
+	add	x12, x8, x11
+	add	x11, x10, x11
+	cmp	x8, x11
+	ccmp	x10, x12, #2, lo
+	b.lo	.L14
+	and	x11, x9, #0xfffffffffffffff8
+	add	x12, x10, #16
+	add	x13, x8, #16
+	and	x14, x9, #0xfffffffffffffff8
+.L15:
+	ldp	q0, q1, [x13, #-16]
+	ldp	q2, q3, [x12, #-16]
+	add.4s	v2, v2, v0
+	add.4s	v3, v3, v1
+	stp	q2, q3, [x13, #-16]
+	stp	q0, q1, [x12, #-16]
+	add	x12, x12, #32
+	add	x13, x13, #32
+	subs	x14, x14, #8
+	b.ne	.L15
+	cmp	x9, x11
+	b.ne	.L16
+	b	.L13
+.L14:
+	mov	x11, #0
+.L16:
+	lsl	x12, x11, #2
+	add	x10, x10, x12
+	add	x8, x8, x12
+	sub	x9, x9, x11
+.L17:
+	ldr	w11, [x8]
+	ldr	w12, [x10]
+	add	w12, w12, w11
+	str	w12, [x8], #4
+	str	w11, [x10], #4
+	subs	x9, x9, #1
+	b.ne	.L17
+.L13:
+	ret
+.L11:
+	add	x12, x8, x13, lsl #2
+	b	.L7
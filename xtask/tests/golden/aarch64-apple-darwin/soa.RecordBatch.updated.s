@@ -0,0 +1,182 @@
+records_in_rust::soa::RecordBatch::updated:
+	ldp	x9, x10, [x0, #56]
+	cbz	x10, .L0
+	cmp	x10, #8
+	b.hs	.L1
+	mov	x11, #0
+	b	.L2
+.L1:
+	and	x11, x10, #0xffffffffffffff8
+	add	x12, x9, #32
+	and	x13, x10, #0xffffffffffffff8
+.L3:
+	ldp	q0, q1, [x12, #-32]
+	ldp	q2, q3, [x12]
+	mvn.16b	v0, v0
+	mvn.16b	v1, v1
+	mvn.16b	v2, v2
+	mvn.16b	v3, v3
+	stp	q0, q1, [x12, #-32]
+	stp	q2, q3, [x12], #64
+	subs	x13, x13, #8
+	b.ne	.L3
+	cmp	x10, x11
+	b.eq	.L0
+.L2:
+	sub	x12, x10, x11
+	add	x11, x9, x11, lsl #3
+.L4:
+	ldr	x13, [x11]
+	mvn	x13, x13
+	str	x13, [x11], #8
+	subs	x12, x12, #1
+	b.ne	.L4
+.L0:
+	ldp	x11, x12, [x0, #8]
+	ldp	x13, x16, [x0, #32]
+	cbz	x10, .L5
+	and	x14, x12, #0x3f
+	cbz	x14, .L5
+	add	x15, x9, x10, lsl #3
+	mov	x17, #-1
+	lsl	x14, x17, x14
+	ldur	x17, [x15, #-8]
+	bic	x14, x17, x14
+	stur	x14, [x15, #-8]
+	b	.L6
+.L5:
+	cbz	x12, .L7
+.L6:
+	cmp	x12, #4
+	b.hs	.L8
+	mov	x14, #0
+	b	.L9
+.L8:
+	cmp	x12, #16
+	b.hs	.L10
+	mov	x14, #0
+	b	.L11
+.L10:
+	and	x15, x12, #0xc
+	and	x14, x12, #0x1ffffffffffffff0
+	add	x17, x11, #32
+	movi.4s	v0, #1
+	and	x1, x12, #0x1ffffffffffffff0
+.L12:
+	ldp	q1, q2, [x17, #-32]
+	ldp	q3, q4, [x17]
+	add.4s	v1, v1, v0
+	add.4s	v2, v2, v0
+	add.4s	v3, v3, v0
+	add.4s	v4, v4, v0
+	stp	q1, q2, [x17, #-32]
+	stp	q3, q4, [x17], #64
+	subs	x1, x1, #16
+	b.ne	.L12
+	cmp	x12, x14
+	b.eq	.L7
+	cbz	x15, .L9
+.L11:
+	mov	x17, x14
+	and	x14, x12, #0x1ffffffffffffffc
+	sub	x15, x17, x14
+	add	x17, x11, x17, lsl #2
+	movi.4s	v0, #1
+.L13:
+	ldr	q1, [x17]
+	add.4s	v1, v1, v0
+	str	q1, [x17], #16
+	adds	x15, x15, #4
+	b.ne	.L13
+	cmp	x12, x14
+	b.eq	.L7
+.L9:
+	sub	x15, x12, x14
+	add	x14, x11, x14, lsl #2
+.L14:
+	ldr	w17, [x14]
+	add	w17, w17, #1
+	str	w17, [x14], #4
+	subs	x15, x15, #1
+	b.ne	.L14
+.L7:
+	ldr	x14, [x0, #48]
+	ldr	x15, [x0]
+	cmp	x12, x16
+	csel	x16, x12, x16, lo
+	ldr	x17, [x0, #24]
+	cbz	x16, .L15
+	cmp	x16, #4
+	b.lo	.L16
+	lsl	x0, x16, #2
+	add	x1, x11, x0
+	cmp	x13, x1
+	b.hs	.L17
+	add	x0, x13, x0
+	cmp	x11, x0
+	b.hs	.L17
+.L16:
+	mov	x0, #0
+.L18:
+	sub	x1, x16, x0
+	lsl	x2, x0, #2
+	add	x0, x11, x2
+	add	x2, x13, x2
+.L19:
+	ldr	w3, [x2]
+	ldr	w4, [x0], #4
+	add	w3, w4, w3
+	str	w3, [x2], #4
+	subs	x1, x1, #1
+	b.ne	.L19
+.L15:
+	stp	x17, x13, [x8]
+	stp	x16, x15, [x8, #16]
+	stp	x11, x12, [x8, #32]
+	stp	x14, x9, [x8, #48]
+	str	x10, [x8, #64]
+	ret
+.L17:
+	cmp	x16, #16
+	b.hs	.L20
+	mov	x0, #0
+	b	.L21
+.L20:
+	and	x1, x16, #0xc
+	and	x0, x16, #0x1ffffffffffffff0
+	add	x2, x13, #32
+	add	x3, x11, #32
+	and	x4, x16, #0x1ffffffffffffff0
+.L22:
+	ldp	q0, q1, [x2, #-32]
+	ldp	q2, q3, [x2]
+	ldp	q4, q5, [x3, #-32]
+	ldp	q6, q7, [x3], #64
+	add.4s	v0, v4, v0
+	add.4s	v1, v5, v1
+	add.4s	v2, v6, v2
+	add.4s	v3, v7, v3
+	stp	q0, q1, [x2, #-32]
+	stp	q2, q3, [x2], #64
+	subs	x4, x4, #16
+	b.ne	.L22
+	cmp	x16, x0
+	b.eq	.L15
+	cbz	x1, .L18
+.L21:
+	mov	x2, x0
+	and	x0, x16, #0x1ffffffffffffffc
+	sub	x1, x2, x0
+	lsl	x3, x2, #2
+	add	x2, x11, x3
+	add	x3, x13, x3
+.L23:
+	ldr	q0, [x3]
+	ldr	q1, [x2], #16
+	add.4s	v0, v1, v0
+	str	q0, [x3], #16
+	adds	x1, x1, #4
+	b.ne	.L23
+	cmp	x16, x0
+	b.eq	.L15
+	b	.L18
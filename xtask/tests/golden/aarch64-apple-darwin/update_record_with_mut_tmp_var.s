@@ -0,0 +1,10 @@
+records_in_rust::update_record_with_mut_tmp_var:
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	mov	w11, #1
+	bic	w10, w11, w10
+	add	w8, w8, #1
+	add	w9, w8, w9
+	stp	w9, w8, [x0]
+	strb	w10, [x0, #8]
+	ret
@@ -0,0 +1,9 @@
+records_in_rust::update_record_with_ptrs:
+	ldrb	w8, [x0, #8]
+	eor	w8, w8, #0x1
+	ldp	w9, w10, [x0]
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	w10, w9, [x0]
+	strb	w8, [x0, #8]
+	ret
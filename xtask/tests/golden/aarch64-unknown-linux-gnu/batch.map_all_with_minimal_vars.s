@@ -0,0 +1,21 @@
+records_in_rust::batch::map_all_with_minimal_vars:
+	ldp	x10, x9, [x0, #8]
+	ldr	x11, [x0]
+	cbz	x9, .L0
+	add	x12, x10, #8
+	mov	w13, #1
+	mov	x14, x9
+.L1:
+	ldp	w15, w16, [x12, #-8]
+	ldrb	w17, [x12]
+	subs	x14, x14, #1
+	add	w15, w15, #1
+	add	w16, w15, w16
+	stp	w16, w15, [x12, #-8]
+	bic	w16, w13, w17
+	strb	w16, [x12], #12
+	b.ne	.L1
+.L0:
+	stp	x11, x10, [x8]
+	str	x9, [x8, #16]
+	ret
@@ -0,0 +1,18 @@
+records_in_rust::batch::update_all_mut:
+	cbz	x1, .L0
+	mov	w8, #12
+	mov	w9, #1
+	madd	x8, x1, x8, x0
+.L1:
+	ldp	w10, w11, [x0]
+	ldrb	w12, [x0, #8]
+	add	w10, w10, #1
+	add	w11, w10, w11
+	stp	w11, w10, [x0]
+	bic	w11, w9, w12
+	strb	w11, [x0, #8]
+	add	x0, x0, #12
+	cmp	x0, x8
+	b.ne	.L1
+.L0:
+	ret
@@ -0,0 +1,17 @@
+records_in_rust::batch::update_all_with_ptrs:
+	cbz	x1, .L0
+	add	x8, x1, x1, lsl #1
+	add	x9, x0, #8
+	lsl	x8, x8, #2
+.L1:
+	ldp	w10, w11, [x9, #-8]
+	ldrb	w12, [x9]
+	subs	x8, x8, #12
+	add	w10, w10, #1
+	add	w11, w10, w11
+	stp	w11, w10, [x9, #-8]
+	eor	w11, w12, #0x1
+	strb	w11, [x9], #12
+	b.ne	.L1
+.L0:
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::big::n1::update_mut_record_mut:
+	ldp	w10, w11, [x0, #8]
+	ldrb	w9, [x0, #16]
+	eor	w9, w9, #0x1
+	add	w10, w10, #1
+	strb	w9, [x0, #16]
+	add	w9, w11, w10
+	stp	w9, w10, [x0, #8]
+	ldr	x9, [x0, #16]
+	ldr	q0, [x0]
+	str	x9, [x8, #16]
+	str	q0, [x8]
+	ret
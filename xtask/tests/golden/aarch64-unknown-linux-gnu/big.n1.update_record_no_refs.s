@@ -0,0 +1,11 @@
+records_in_rust::big::n1::update_record_no_refs:
+	ldrb	w9, [x0, #16]
+	ldp	w10, w11, [x0, #8]
+	eor	w9, w9, #0x1
+	strb	w9, [x8, #16]
+	ldr	x9, [x0]
+	add	w10, w10, #1
+	add	w11, w10, w11
+	str	x9, [x8]
+	stp	w11, w10, [x8, #8]
+	ret
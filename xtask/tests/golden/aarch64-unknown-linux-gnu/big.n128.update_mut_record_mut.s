@@ -0,0 +1,14 @@
+records_in_rust::big::n128::update_mut_record_mut:
+	mov	x1, x0
+	mov	x0, x8
+	mov	w2, #1040
+	ldrb	w8, [x1, #1032]
+	ldr	w9, [x1, #1024]
+	ldr	w10, [x1, #1028]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	strb	w8, [x1, #1032]
+	add	w8, w10, w9
+	str	w8, [x1, #1024]
+	str	w9, [x1, #1028]
+	b	memcpy
@@ -0,0 +1,23 @@
+records_in_rust::big::n128::update_record_no_refs:
+	stp	x29, x30, [sp, #-48]!
+	stp	x22, x21, [sp, #16]
+	stp	x20, x19, [sp, #32]
+	mov	x29, sp
+	mov	x19, x8
+	ldr	w8, [x0, #1024]
+	ldrb	w9, [x0, #1032]
+	mov	x1, x0
+	ldr	w20, [x0, #1028]
+	mov	x0, x19
+	mov	w2, #1024
+	eor	w21, w9, #0x1
+	add	w22, w8, #1
+	bl	memcpy
+	add	w8, w22, w20
+	str	w22, [x19, #1028]
+	str	w8, [x19, #1024]
+	strb	w21, [x19, #1032]
+	ldp	x20, x19, [sp, #32]
+	ldp	x22, x21, [sp, #16]
+	ldp	x29, x30, [sp], #48
+	ret
@@ -0,0 +1,19 @@
+records_in_rust::big::n16::update_mut_record_mut:
+	ldp	q0, q1, [x0, #96]
+	ldrb	w9, [x0, #136]
+	eor	w9, w9, #0x1
+	stp	q0, q1, [x8, #96]
+	ldp	q0, q1, [x0, #32]
+	strb	w9, [x0, #136]
+	ldp	w10, w9, [x0, #128]
+	stp	q0, q1, [x8, #32]
+	ldp	q0, q2, [x0, #64]
+	add	w10, w10, #1
+	add	w9, w9, w10
+	stp	q0, q2, [x8, #64]
+	stp	w9, w10, [x0, #128]
+	ldp	q0, q1, [x0]
+	ldr	q2, [x0, #128]
+	stp	q0, q1, [x8]
+	str	q2, [x8, #128]
+	ret
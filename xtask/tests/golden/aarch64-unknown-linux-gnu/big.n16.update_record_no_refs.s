@@ -0,0 +1,17 @@
+records_in_rust::big::n16::update_record_no_refs:
+	ldp	q0, q1, [x0, #64]
+	ldrb	w11, [x0, #136]
+	ldp	w9, w10, [x0, #128]
+	stp	q0, q1, [x8, #64]
+	ldp	q0, q2, [x0, #96]
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	q0, q2, [x8, #96]
+	ldp	q1, q0, [x0]
+	stp	w10, w9, [x8, #128]
+	eor	w10, w11, #0x1
+	strb	w10, [x8, #136]
+	stp	q1, q0, [x8]
+	ldp	q1, q0, [x0, #32]
+	stp	q1, q0, [x8, #32]
+	ret
@@ -0,0 +1,27 @@
+records_in_rust::big::n32::update_record_no_refs:
+	ldp	q0, q1, [x0, #192]
+	ldr	w9, [x0, #256]
+	ldr	w10, [x0, #260]
+	ldrb	w11, [x0, #264]
+	add	w9, w9, #1
+	stp	q0, q1, [x8, #192]
+	ldp	q0, q2, [x0, #224]
+	add	w10, w9, w10
+	str	w9, [x8, #260]
+	str	w10, [x8, #256]
+	eor	w10, w11, #0x1
+	stp	q0, q2, [x8, #224]
+	ldp	q1, q0, [x0, #128]
+	strb	w10, [x8, #264]
+	stp	q1, q0, [x8, #128]
+	ldp	q2, q0, [x0, #160]
+	stp	q2, q0, [x8, #160]
+	ldp	q1, q0, [x0, #64]
+	stp	q1, q0, [x8, #64]
+	ldp	q2, q0, [x0, #96]
+	stp	q2, q0, [x8, #96]
+	ldp	q1, q0, [x0]
+	stp	q1, q0, [x8]
+	ldp	q1, q0, [x0, #32]
+	stp	q1, q0, [x8, #32]
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::big::n4::update_mut_record_mut:
+	ldp	w10, w11, [x0, #32]
+	ldrb	w9, [x0, #40]
+	ldp	q0, q1, [x0]
+	eor	w9, w9, #0x1
+	add	w10, w10, #1
+	strb	w9, [x0, #40]
+	add	w9, w11, w10
+	stp	w9, w10, [x0, #32]
+	ldr	q2, [x0, #32]
+	stp	q0, q1, [x8]
+	str	q2, [x8, #32]
+	ret
@@ -0,0 +1,11 @@
+records_in_rust::big::n4::update_record_no_refs:
+	ldp	w9, w10, [x0, #32]
+	ldp	q0, q1, [x0]
+	ldrb	w11, [x0, #40]
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	q0, q1, [x8]
+	stp	w10, w9, [x8, #32]
+	eor	w10, w11, #0x1
+	strb	w10, [x8, #40]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::big::n64::update_mut_record_mut:
+	mov	x1, x0
+	mov	x0, x8
+	mov	w2, #528
+	ldrb	w8, [x1, #520]
+	ldr	w9, [x1, #512]
+	ldr	w10, [x1, #516]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	strb	w8, [x1, #520]
+	add	w8, w10, w9
+	str	w8, [x1, #512]
+	str	w9, [x1, #516]
+	b	memcpy
@@ -0,0 +1,15 @@
+records_in_rust::big::n8::update_mut_record_mut:
+	ldp	w10, w11, [x0, #64]
+	ldrb	w9, [x0, #72]
+	ldp	q0, q1, [x0, #32]
+	eor	w9, w9, #0x1
+	add	w10, w10, #1
+	strb	w9, [x0, #72]
+	add	w9, w11, w10
+	stp	w9, w10, [x0, #64]
+	stp	q0, q1, [x8, #32]
+	ldp	q0, q2, [x0]
+	ldr	q1, [x0, #64]
+	stp	q0, q2, [x8]
+	str	q1, [x8, #64]
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::big::n8::update_record_no_refs:
+	ldp	q0, q1, [x0]
+	ldrb	w11, [x0, #72]
+	ldp	w9, w10, [x0, #64]
+	stp	q0, q1, [x8]
+	ldp	q0, q1, [x0, #32]
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	w10, w9, [x8, #64]
+	eor	w10, w11, #0x1
+	stp	q0, q1, [x8, #32]
+	strb	w10, [x8, #72]
+	ret
@@ -0,0 +1,10 @@
+records_in_rust::generic::of_i64::update_record_with_refs:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_with_refs
+	ldp	x9, x10, [x0]
+	ldrb	w8, [x0, #16]
+	eor	w8, w8, #0x1
+	add	x9, x9, #1
+	strb	w8, [x0, #16]
+	add	x8, x10, x9
+	stp	x8, x9, [x0]
+	ret
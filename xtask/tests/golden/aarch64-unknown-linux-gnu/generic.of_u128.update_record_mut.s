@@ -0,0 +1,17 @@
+records_in_rust::generic::of_u128::update_record_mut:
+	ldrb	w9, [x0, #32]
+	ldp	x10, x11, [x0]
+	eor	w9, w9, #0x1
+	adds	x10, x10, #1
+	strb	w9, [x0, #32]
+	ldp	x9, x12, [x0, #16]
+	cinc	x11, x11, hs
+	stp	x10, x11, [x0, #16]
+	ldp	q1, q0, [x0, #16]
+	adds	x9, x9, x10
+	adc	x10, x12, x11
+	stp	x9, x10, [x0]
+	stp	q1, q0, [x8, #16]
+	ldr	q0, [x0]
+	str	q0, [x8]
+	ret
@@ -0,0 +1,13 @@
+records_in_rust::generic::of_u128::update_record_no_refs:
+	ldp	x9, x10, [x0]
+	ldp	x11, x12, [x0, #16]
+	adds	x9, x9, #1
+	cinc	x10, x10, hs
+	adds	x11, x9, x11
+	adc	x12, x10, x12
+	stp	x9, x10, [x8, #16]
+	stp	x11, x12, [x8]
+	ldrb	w11, [x0, #32]
+	eor	w9, w11, #0x1
+	strb	w9, [x8, #32]
+	ret
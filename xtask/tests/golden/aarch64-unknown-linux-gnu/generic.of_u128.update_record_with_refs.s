@@ -0,0 +1,13 @@
+records_in_rust::generic::of_u128::update_record_with_refs:
+	ldp	x9, x10, [x0]
+	ldrb	w8, [x0, #32]
+	ldp	x11, x12, [x0, #16]
+	eor	w8, w8, #0x1
+	adds	x9, x9, #1
+	strb	w8, [x0, #32]
+	cinc	x8, x10, hs
+	adds	x10, x11, x9
+	adc	x11, x12, x8
+	stp	x9, x8, [x0, #16]
+	stp	x10, x11, [x0]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::generic::of_u64::update_record_mut:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_mut
+	ldp	x10, x11, [x0]
+	ldrb	w9, [x0, #16]
+	eor	w9, w9, #0x1
+	add	x10, x10, #1
+	strb	w9, [x0, #16]
+	add	x9, x11, x10
+	stp	x9, x10, [x0]
+	ldr	x9, [x0, #16]
+	ldr	q0, [x0]
+	str	x9, [x8, #16]
+	str	q0, [x8]
+	ret
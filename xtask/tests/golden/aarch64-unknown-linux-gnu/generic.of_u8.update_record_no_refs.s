@@ -0,0 +1,11 @@
+records_in_rust::generic::of_u8::update_record_no_refs:
+# same code as records_in_rust::generic::of_u8::update_record_mut
+	ubfx	w8, w0, #8, #16
+	add	w8, w8, #1
+	add	w9, w8, w0, lsr #16
+	lsl	w8, w8, #16
+	bfi	w8, w9, #8, #8
+	mvn	w9, w0
+	bfxil	w8, w9, #0, #1
+	mov	w0, w8
+	ret
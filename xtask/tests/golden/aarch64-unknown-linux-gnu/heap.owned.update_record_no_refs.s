@@ -0,0 +1,59 @@
+records_in_rust::heap::owned::update_record_no_refs:
+.L0:
+	sub	sp, sp, #96
+	stp	x29, x30, [sp, #32]
+	str	x23, [sp, #48]
+	stp	x22, x21, [sp, #64]
+	stp	x20, x19, [sp, #80]
+	add	x29, sp, #32
+	ldr	q0, [x0]
+	mov	x19, x8
+	ldr	x9, [x0, #16]
+	ldp	w10, w20, [x0, #24]
+	str	q0, [x8]
+	ldr	q0, [x0]
+	mov	w8, #1
+	str	x9, [x19, #16]
+	ldrb	w9, [x0, #32]
+	ldr	x23, [x0, #16]
+	str	q0, [sp]
+	add	w21, w10, #1
+	ldr	x10, [sp]
+	bic	w22, w8, w9
+	stp	w21, w20, [x19, #24]
+	strb	w22, [x19, #32]
+	cmp	x23, x10
+	str	x23, [sp, #16]
+	b.ne	.L1
+.L2:
+	mov	x0, sp
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+.L3:
+.L1:
+	ldr	x8, [sp, #8]
+	ldr	q0, [sp]
+	add	x9, x23, #1
+	str	x9, [x19, #16]
+	str	w20, [x8, x23, lsl #2]
+	add	w8, w21, w20
+	str	q0, [x19]
+	stp	w8, w21, [x19, #24]
+	strb	w22, [x19, #32]
+	ldp	x20, x19, [sp, #80]
+	ldr	x23, [sp, #48]
+	ldp	x22, x21, [sp, #64]
+	ldp	x29, x30, [sp, #32]
+	add	sp, sp, #96
+	ret
+.L4:
+.L5:
+	ldr	x8, [sp]
+	mov	x19, x0
+	cbz	x8, .L6
+	ldr	x0, [sp, #8]
+	lsl	x1, x8, #2
+	mov	w2, #4
+	bl	__rustc::__rust_dealloc
+.L6:
+	mov	x0, x19
+	bl	_Unwind_Resume
@@ -0,0 +1,34 @@
+records_in_rust::heap::owned::update_record_with_refs:
+	stp	x29, x30, [sp, #-48]!
+	stp	x22, x21, [sp, #16]
+	stp	x20, x19, [sp, #32]
+	mov	x29, sp
+	ldp	w10, w22, [x0, #24]
+	ldrb	w8, [x0, #32]
+	ldr	x20, [x0, #16]
+	ldr	x9, [x0]
+	eor	w8, w8, #0x1
+	add	w21, w10, #1
+	cmp	x20, x9
+	strb	w8, [x0, #32]
+	mov	w9, w22
+	mov	w8, w21
+	str	w21, [x0, #24]
+	b.eq	.L0
+.L1:
+	ldr	x10, [x0, #8]
+	add	x11, x20, #1
+	add	w8, w8, w9
+	str	x11, [x0, #16]
+	str	w22, [x10, x20, lsl #2]
+	stp	w8, w21, [x0, #24]
+	ldp	x20, x19, [sp, #32]
+	ldp	x22, x21, [sp, #16]
+	ldp	x29, x30, [sp], #48
+	ret
+.L0:
+	mov	x19, x0
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+	ldp	w8, w9, [x19, #24]
+	mov	x0, x19
+	b	.L1
@@ -0,0 +1,29 @@
+records_in_rust::heap::shared::update_record_from_ref:
+	sub	sp, sp, #80
+	str	d8, [sp, #32]
+	stp	x29, x30, [sp, #40]
+	str	x21, [sp, #56]
+	stp	x20, x19, [sp, #64]
+	add	x29, sp, #40
+	ldr	x20, [x0]
+	ldr	d8, [x0, #8]
+	mov	x19, x8
+	ldrb	w21, [x0, #16]
+	mov	w0, #1
+	mov	x1, x20
+	bl	__aarch64_ldadd8_relax
+	tbnz	x0, #63, .L0
+	add	x0, sp, #8
+	mov	x8, x19
+	str	d8, [sp, #16]
+	strb	w21, [sp, #24]
+	str	x20, [sp, #8]
+	bl	records_in_rust::heap::shared::update_record_no_refs
+	ldp	x20, x19, [sp, #64]
+	ldr	x21, [sp, #56]
+	ldp	x29, x30, [sp, #40]
+	ldr	d8, [sp, #32]
+	add	sp, sp, #80
+	ret
+.L0:
+	brk	#0x1
@@ -0,0 +1,62 @@
+records_in_rust::heap::shared::update_record_no_refs:
+.L0:
+	sub	sp, sp, #80
+	stp	x29, x30, [sp, #16]
+	stp	x24, x23, [sp, #32]
+	stp	x22, x21, [sp, #48]
+	stp	x20, x19, [sp, #64]
+	add	x29, sp, #16
+	ldp	w10, w21, [x0, #8]
+	ldrb	w11, [x0, #16]
+	mov	x19, x8
+	mov	w8, #1
+	ldr	x9, [x0]
+	add	w22, w10, #1
+	bic	w23, w8, w11
+	str	x9, [x19]
+	stp	w22, w21, [x19, #8]
+	strb	w23, [x19, #16]
+	str	x9, [sp, #8]
+.L1:
+	add	x0, sp, #8
+	bl	alloc::sync::Arc<T,A>::make_mut
+.L2:
+	ldr	x24, [x0, #16]
+	ldr	x8, [x0]
+	mov	x20, x0
+	cmp	x24, x8
+	b.ne	.L3
+.L4:
+	mov	x0, x20
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+.L5:
+.L3:
+	ldr	x8, [x20, #8]
+	ldr	x10, [sp, #8]
+	add	x9, x24, #1
+	str	x9, [x20, #16]
+	str	w21, [x8, x24, lsl #2]
+	add	w8, w22, w21
+	str	x10, [x19]
+	stp	w8, w22, [x19, #8]
+	strb	w23, [x19, #16]
+	ldp	x20, x19, [sp, #64]
+	ldp	x22, x21, [sp, #48]
+	ldp	x24, x23, [sp, #32]
+	ldp	x29, x30, [sp, #16]
+	add	sp, sp, #80
+	ret
+.L6:
+.L7:
+	ldr	x1, [sp, #8]
+	mov	x19, x0
+	mov	x0, #-1
+	bl	__aarch64_ldadd8_rel
+	cmp	x0, #1
+	b.ne	.L8
+	add	x0, sp, #8
+	dmb	ishld
+	bl	alloc::sync::Arc<T,A>::drop_slow
+.L8:
+	mov	x0, x19
+	bl	_Unwind_Resume
@@ -0,0 +1,37 @@
+records_in_rust::heap::shared::update_record_with_refs:
+	stp	x29, x30, [sp, #-64]!
+	str	x23, [sp, #16]
+	stp	x22, x21, [sp, #32]
+	stp	x20, x19, [sp, #48]
+	mov	x29, sp
+	ldrb	w8, [x0, #16]
+	ldr	w9, [x0, #8]
+	mov	x19, x0
+	eor	w8, w8, #0x1
+	add	w21, w9, #1
+	strb	w8, [x0, #16]
+	str	w21, [x0, #8]
+	bl	alloc::sync::Arc<T,A>::make_mut
+	ldr	x23, [x0, #16]
+	ldr	x8, [x0]
+	ldr	w22, [x19, #12]
+	cmp	x23, x8
+	b.eq	.L0
+.L1:
+	ldr	x8, [x0, #8]
+	ldr	w10, [x19, #8]
+	add	x9, x23, #1
+	str	x9, [x0, #16]
+	str	w22, [x8, x23, lsl #2]
+	add	w8, w10, w22
+	stp	w8, w21, [x19, #8]
+	ldp	x20, x19, [sp, #48]
+	ldr	x23, [sp, #16]
+	ldp	x22, x21, [sp, #32]
+	ldp	x29, x30, [sp], #64
+	ret
+.L0:
+	mov	x20, x0
+	bl	alloc::raw_vec::RawVec<T,A>::grow_one
+	mov	x0, x20
+	b	.L1
@@ -0,0 +1,25 @@
+records_in_rust::inlined::chain_no_refs:
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	ldurh	w12, [x0, #9]
+	add	w9, w9, #2
+	and	w11, w11, #0x1
+	sturh	w12, [x8, #9]
+	add	w10, w9, w10
+	strb	w11, [x8, #8]
+	ldrb	w11, [x0, #11]
+	add	w9, w9, w10
+	add	w10, w10, w9
+	strb	w11, [x8, #11]
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w9, w10
+	add	w9, w9, #1
+	add	w10, w10, w9
+	add	w10, w10, #1
+	add	w9, w10, w9
+	stp	w9, w10, [x8]
+	ret
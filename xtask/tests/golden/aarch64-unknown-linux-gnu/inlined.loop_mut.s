@@ -0,0 +1,24 @@
+records_in_rust::inlined::loop_mut:
+# same code as records_in_rust::inlined::loop_no_refs
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	add	w8, w8, #1
+	add	w9, w8, w9
+	add	w9, w9, #1
+	add	w8, w9, w8
+	add	w8, w8, #1
+	add	w9, w8, w9
+	add	w9, w9, #1
+	add	w8, w9, w8
+	add	w8, w8, #1
+	add	w9, w8, w9
+	add	w9, w9, #1
+	add	w8, w9, w8
+	add	w8, w8, #1
+	add	w9, w8, w9
+	add	w9, w9, #1
+	add	w8, w9, w8
+	stp	w8, w9, [x0]
+	and	w8, w10, #0x1
+	strb	w8, [x0, #8]
+	ret
@@ -0,0 +1,23 @@
+records_in_rust::inlined::loop_with_refs:
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	add	w8, w8, #1
+	and	w10, w10, #0x1
+	add	w9, w9, w8
+	strb	w10, [x0, #8]
+	add	w9, w9, #1
+	add	w8, w8, w9
+	add	w8, w8, #1
+	add	w9, w9, w8
+	add	w9, w9, #1
+	add	w8, w8, w9
+	add	w8, w8, #1
+	add	w9, w9, w8
+	add	w9, w9, #1
+	add	w8, w8, w9
+	add	w8, w8, #1
+	add	w9, w9, w8
+	add	w9, w9, #1
+	add	w8, w8, w9
+	stp	w8, w9, [x0]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::inlined::owner_mut:
+# same code as records_in_rust::inlined::owner_mut_record_mut
+	ldp	w10, w11, [x0, #32]
+	ldrb	w9, [x0, #40]
+	mov	w8, #1
+	bic	w8, w8, w9
+	add	w9, w10, #1
+	ldr	x10, [x0, #24]
+	add	w11, w9, w11
+	strb	w8, [x0, #40]
+	add	x8, x10, #1
+	stp	w11, w9, [x0, #32]
+	str	x8, [x0, #24]
+	ret
@@ -0,0 +1,12 @@
+records_in_rust::inlined::owner_no_refs:
+	ldp	w9, w10, [x0, #32]
+	ldrb	w8, [x0, #40]
+	ldr	x11, [x0, #24]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	add	w10, w9, w10
+	strb	w8, [x0, #40]
+	add	x8, x11, #1
+	stp	w10, w9, [x0, #32]
+	str	x8, [x0, #24]
+	ret
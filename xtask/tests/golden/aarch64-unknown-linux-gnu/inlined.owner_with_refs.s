@@ -0,0 +1,12 @@
+records_in_rust::inlined::owner_with_refs:
+	ldp	w9, w10, [x0, #32]
+	ldrb	w8, [x0, #40]
+	ldr	x11, [x0, #24]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	strb	w8, [x0, #40]
+	add	w8, w10, w9
+	stp	w8, w9, [x0, #32]
+	add	x8, x11, #1
+	str	x8, [x0, #24]
+	ret
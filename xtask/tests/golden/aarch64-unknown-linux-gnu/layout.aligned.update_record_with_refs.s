@@ -0,0 +1,9 @@
+records_in_rust::layout::aligned::update_record_with_refs:
+	ldp	w9, w10, [x0]
+	ldrb	w8, [x0, #8]
+	eor	w8, w8, #0x1
+	add	w9, w9, #1
+	strb	w8, [x0, #8]
+	add	w8, w10, w9
+	stp	w8, w9, [x0]
+	ret
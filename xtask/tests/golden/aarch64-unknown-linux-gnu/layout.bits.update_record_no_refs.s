@@ -0,0 +1,9 @@
+records_in_rust::layout::bits::update_record_no_refs:
+# same code as records_in_rust::layout::bits::update_record_mut
+	add	x8, x0, #1
+	and	x10, x0, #0x80000000
+	add	x9, x8, x0, lsr #32
+	bfxil	x10, x9, #0, #31
+	bfi	x10, x8, #32, #31
+	eor	x0, x10, #0x80000000
+	ret
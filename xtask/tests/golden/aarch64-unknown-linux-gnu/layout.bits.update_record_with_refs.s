@@ -0,0 +1,11 @@
+records_in_rust::layout::bits::update_record_with_refs:
+# same code as records_in_rust::layout::bits::update_record_with_ptrs
+	ldr	x8, [x0]
+	add	x9, x8, #1
+	add	x10, x9, x8, lsr #32
+	and	x8, x8, #0x80000000
+	bfxil	x8, x10, #0, #31
+	bfi	x8, x9, #32, #31
+	eor	x8, x8, #0x80000000
+	str	x8, [x0]
+	ret
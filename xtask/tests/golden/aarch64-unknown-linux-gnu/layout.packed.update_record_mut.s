@@ -0,0 +1,13 @@
+records_in_rust::layout::packed::update_record_mut:
+	ldp	w10, w11, [x0]
+	ldrb	w12, [x0, #8]
+	mov	w9, #1
+	add	w10, w10, #1
+	bic	w9, w9, w12
+	add	w11, w11, w10
+	strb	w9, [x0, #8]
+	stp	w11, w10, [x0]
+	ldr	x10, [x0]
+	strb	w9, [x8, #8]
+	str	x10, [x8]
+	ret
@@ -0,0 +1,9 @@
+records_in_rust::layout::packed::update_record_no_refs:
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	add	w9, w9, #1
+	add	w10, w9, w10
+	stp	w10, w9, [x8]
+	eor	w10, w11, #0x1
+	strb	w10, [x8, #8]
+	ret
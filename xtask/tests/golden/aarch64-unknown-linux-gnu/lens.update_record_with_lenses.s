@@ -0,0 +1,10 @@
+records_in_rust::lens::update_record_with_lenses:
+	ldp	w9, w10, [x0]
+	ldrb	w11, [x0, #8]
+	mov	w8, #1
+	add	w9, w9, #1
+	bic	w8, w8, w11
+	add	w10, w10, w9
+	strb	w8, [x0, #8]
+	stp	w10, w9, [x0]
+	ret
@@ -0,0 +1,14 @@
+records_in_rust::op::update_record_with_ops:
+	ldrb	w10, [x0, #8]
+	mov	w9, #1
+	ldurh	w11, [x0, #9]
+	ldp	w12, w13, [x0]
+	bic	w9, w9, w10
+	sturh	w11, [x8, #9]
+	strb	w9, [x8, #8]
+	ldrb	w9, [x0, #11]
+	add	w10, w12, #1
+	add	w11, w13, w10
+	strb	w9, [x8, #11]
+	stp	w11, w10, [x8]
+	ret
@@ -0,0 +1,135 @@
+records_in_rust::soa::RecordBatch::update_all:
+	ldr	x8, [x0, #64]
+	cbz	x8, .L0
+	lsl	x8, x8, #3
+	ldr	x12, [x0, #56]
+	sub	x9, x8, #8
+	add	x11, x12, x8
+	mov	x8, x12
+	cmp	x9, #23
+	b.ls	.L1
+	lsr	x8, x9, #3
+	add	x9, x8, #1
+	and	x10, x9, #0x3ffffffffffffffc
+	and	x13, x9, #0x3ffffffffffffffc
+	add	x8, x12, x10, lsl #3
+	add	x12, x12, #16
+.L2:
+	ldp	q0, q1, [x12, #-16]
+	subs	x13, x13, #4
+	mvn	v0.16b, v0.16b
+	mvn	v1.16b, v1.16b
+	stp	q0, q1, [x12, #-16]
+	add	x12, x12, #32
+	b.ne	.L2
+	cmp	x9, x10
+	b.eq	.L3
+.L1:
+	ldr	x9, [x8]
+	mvn	x9, x9
+	str	x9, [x8], #8
+	cmp	x8, x11
+	b.ne	.L1
+.L3:
+	ldr	x9, [x0, #16]
+	ands	x8, x9, #0x3f
+	b.eq	.L4
+	mov	x10, #-1
+	ldur	x12, [x11, #-8]
+	lsl	x10, x10, x8
+	ldr	x8, [x0, #8]
+	bic	x12, x12, x10
+	add	x10, x8, x9, lsl #2
+	stur	x12, [x11, #-8]
+	sub	x11, x10, x8
+	sub	x12, x11, #4
+	mov	x11, x8
+	cmp	x12, #28
+	b.lo	.L5
+	b	.L6
+.L0:
+	ldr	x9, [x0, #16]
+.L4:
+	ldr	x8, [x0, #8]
+	cbz	x9, .L7
+	add	x10, x8, x9, lsl #2
+	sub	x11, x10, x8
+	sub	x12, x11, #4
+	mov	x11, x8
+	cmp	x12, #28
+	b.lo	.L5
+.L6:
+	lsr	x11, x12, #2
+	movi	v0.4s, #1
+	add	x14, x8, #16
+	add	x12, x11, #1
+	and	x13, x12, #0x7ffffffffffffff8
+	and	x15, x12, #0x7ffffffffffffff8
+	add	x11, x8, x13, lsl #2
+.L8:
+	ldp	q1, q2, [x14, #-16]
+	subs	x15, x15, #8
+	add	v1.4s, v1.4s, v0.4s
+	add	v2.4s, v2.4s, v0.4s
+	stp	q1, q2, [x14, #-16]
+	add	x14, x14, #32
+	b.ne	.L8
+	cmp	x12, x13
+	b.eq	.L7
+.L5:
+	ldr	w12, [x11]
+	add	w12, w12, #1
+	str	w12, [x11], #4
+	cmp	x11, x10
+	b.ne	.L5
+.L7:
+	ldr	x10, [x0, #40]
+	cmp	x10, x9
+	csel	x9, x10, x9, lo
+	cbz	x9, .L9
+	ldr	x10, [x0, #32]
+	cmp	x9, #8
+	b.lo	.L10
+	lsl	x11, x9, #2
+	add	x12, x10, x11
+	cmp	x8, x12
+	b.hs	.L11
+	add	x11, x8, x11
+	cmp	x10, x11
+	b.hs	.L11
+.L10:
+	mov	x11, xzr
+.L12:
+	lsl	x12, x11, #2
+	sub	x9, x9, x11
+	add	x10, x10, x12
+	add	x8, x8, x12
+.L13:
+	ldr	w11, [x8]
+	ldr	w12, [x10]
+	subs	x9, x9, #1
+	add	w12, w12, w11
+	str	w12, [x8], #4
+	str	w11, [x10], #4
+	b.ne	.L13
+.L9:
+	ret
+.L11:
+	and	x11, x9, #0xfffffffffffffff8
+	add	x12, x10, #16
+	add	x13, x8, #16
+	and	x14, x9, #0xfffffffffffffff8
+.L14:
+	ldp	q0, q3, [x12, #-16]
+	subs	x14, x14, #8
+	ldp	q1, q2, [x13, #-16]
+	add	v0.4s, v0.4s, v1.4s
+	add	v3.4s, v3.4s, v2.4s
+	stp	q1, q2, [x12, #-16]
+	add	x12, x12, #32
+	stp	q0, q3, [x13, #-16]
+	add	x13, x13, #32
+	b.ne	.L14
+	cmp	x9, x11
+	b.eq	.L9
+	b	.L12
@@ -0,0 +1,129 @@
+records_in_rust::soa::RecordBatch::updated:
+	ldp	x10, x9, [x0, #56]
+	cbz	x9, .L0
+	cmp	x9, #4
+	b.hs	.L1
+	mov	x11, xzr
+	b	.L2
+.L1:
+	and	x11, x9, #0xffffffffffffffc
+	add	x12, x10, #16
+	and	x13, x9, #0xffffffffffffffc
+.L3:
+	ldp	q0, q1, [x12, #-16]
+	subs	x13, x13, #4
+	mvn	v0.16b, v0.16b
+	mvn	v1.16b, v1.16b
+	stp	q0, q1, [x12, #-16]
+	add	x12, x12, #32
+	b.ne	.L3
+	cmp	x9, x11
+	b.eq	.L0
+.L2:
+	add	x12, x10, x11, lsl #3
+	sub	x11, x9, x11
+.L4:
+	ldr	x13, [x12]
+	subs	x11, x11, #1
+	mvn	x13, x13
+	str	x13, [x12], #8
+	b.ne	.L4
+.L0:
+	ldp	x11, x12, [x0, #8]
+	ldp	x13, x16, [x0, #32]
+	cbz	x9, .L5
+	and	x14, x12, #0x3f
+	cbz	x14, .L5
+	add	x15, x10, x9, lsl #3
+	mov	x17, #-1
+	lsl	x14, x17, x14
+	ldur	x17, [x15, #-8]
+	bic	x14, x17, x14
+	stur	x14, [x15, #-8]
+	b	.L6
+.L5:
+	cbz	x12, .L7
+.L6:
+	cmp	x12, #8
+	b.hs	.L8
+	mov	x14, xzr
+	b	.L9
+.L8:
+	movi	v0.4s, #1
+	and	x14, x12, #0x1ffffffffffffff8
+	add	x15, x11, #16
+	and	x17, x12, #0x1ffffffffffffff8
+.L10:
+	ldp	q1, q2, [x15, #-16]
+	subs	x17, x17, #8
+	add	v1.4s, v1.4s, v0.4s
+	add	v2.4s, v2.4s, v0.4s
+	stp	q1, q2, [x15, #-16]
+	add	x15, x15, #32
+	b.ne	.L10
+	cmp	x12, x14
+	b.eq	.L7
+.L9:
+	add	x15, x11, x14, lsl #2
+	sub	x14, x12, x14
+.L11:
+	ldr	w17, [x15]
+	subs	x14, x14, #1
+	add	w17, w17, #1
+	str	w17, [x15], #4
+	b.ne	.L11
+.L7:
+	ldr	x14, [x0, #48]
+	cmp	x12, x16
+	ldr	x15, [x0]
+	ldr	x17, [x0, #24]
+	csel	x16, x12, x16, lo
+	cbz	x16, .L12
+	cmp	x16, #8
+	b.lo	.L13
+	lsl	x18, x16, #2
+	add	x0, x11, x18
+	cmp	x13, x0
+	b.hs	.L14
+	add	x18, x13, x18
+	cmp	x11, x18
+	b.hs	.L14
+.L13:
+	mov	x18, xzr
+.L15:
+	lsl	x1, x18, #2
+	sub	x18, x16, x18
+	add	x0, x11, x1
+	add	x1, x13, x1
+.L16:
+	ldr	w2, [x1]
+	ldr	w3, [x0], #4
+	subs	x18, x18, #1
+	add	w2, w3, w2
+	str	w2, [x1], #4
+	b.ne	.L16
+.L12:
+	stp	x17, x13, [x8]
+	stp	x16, x15, [x8, #16]
+	stp	x11, x12, [x8, #32]
+	stp	x14, x10, [x8, #48]
+	str	x9, [x8, #64]
+	ret
+.L14:
+	and	x18, x16, #0x1ffffffffffffff8
+	add	x0, x11, #16
+	add	x1, x13, #16
+	and	x2, x16, #0x1ffffffffffffff8
+.L17:
+	ldp	q0, q3, [x0, #-16]
+	subs	x2, x2, #8
+	ldp	q1, q2, [x1, #-16]
+	add	x0, x0, #32
+	add	v0.4s, v0.4s, v1.4s
+	add	v1.4s, v3.4s, v2.4s
+	stp	q0, q1, [x1, #-16]
+	add	x1, x1, #32
+	b.ne	.L17
+	cmp	x16, x18
+	b.eq	.L12
+	b	.L15
@@ -0,0 +1,14 @@
+records_in_rust::update_mut_record_mut:
+# same code as records_in_rust::update_record_mut
+	ldp	w10, w11, [x0]
+	ldrb	w9, [x0, #8]
+	eor	w9, w9, #0x1
+	add	w10, w10, #1
+	strb	w9, [x0, #8]
+	add	w9, w11, w10
+	stp	w9, w10, [x0]
+	ldr	w9, [x0, #8]
+	ldr	x10, [x0]
+	str	w9, [x8, #8]
+	str	x10, [x8]
+	ret
@@ -0,0 +1,9 @@
+records_in_rust::update_record_with_ptrs:
+	ldp	w8, w9, [x0]
+	ldrb	w10, [x0, #8]
+	add	w8, w8, #1
+	add	w9, w8, w9
+	stp	w9, w8, [x0]
+	eor	w9, w10, #0x1
+	strb	w9, [x0, #8]
+	ret
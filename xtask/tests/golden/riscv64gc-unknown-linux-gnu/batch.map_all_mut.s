@@ -0,0 +1,28 @@
+records_in_rust::batch::map_all_mut:
+	ld	a6, 8(a1)
+	ld	t0, 16(a1)
+	ld	a7, 0(a1)
+	beqz	t0, .L0
+	addi	a4, a6, 8
+	slli	a5, t0, 2
+	slli	a3, t0, 3
+	add	a3, a3, a5
+	add	a5, a4, a3
+.L1:
+	lbu	a3, 0(a4)
+	lw	a1, -8(a4)
+	lw	a2, -4(a4)
+	not	a3, a3
+	addi	a1, a1, 1
+	andi	a3, a3, 1
+	add	a2, a2, a1
+	sw	a2, -8(a4)
+	sw	a1, -4(a4)
+	sb	a3, 0(a4)
+	addi	a4, a4, 12
+	bne	a4, a5, .L1
+.L0:
+	sd	a7, 0(a0)
+	sd	a6, 8(a0)
+	sd	t0, 16(a0)
+	ret
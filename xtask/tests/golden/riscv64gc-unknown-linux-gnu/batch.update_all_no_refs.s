@@ -0,0 +1,20 @@
+records_in_rust::batch::update_all_no_refs:
+	beqz	a1, .L0
+	slli	a2, a1, 2
+	slli	a1, a1, 3
+	add	a1, a1, a2
+	add	a1, a1, a0
+.L1:
+	lbu	a2, 8(a0)
+	lw	a3, 0(a0)
+	lw	a4, 4(a0)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sw	a4, 0(a0)
+	sw	a3, 4(a0)
+	sb	a2, 8(a0)
+	addi	a0, a0, 12
+	bne	a0, a1, .L1
+.L0:
+	ret
@@ -0,0 +1,17 @@
+records_in_rust::big::n1::update_mut_record_mut:
+	lw	a2, 8(a1)
+	lw	a3, 12(a1)
+	ld	a4, 0(a1)
+	addi	a2, a2, 1
+	lbu	a5, 16(a1)
+	add	a3, a3, a2
+	sw	a3, 8(a1)
+	sw	a2, 12(a1)
+	ld	a2, 8(a1)
+	xori	a3, a5, 1
+	sb	a3, 16(a1)
+	ld	a1, 16(a1)
+	sd	a4, 0(a0)
+	sd	a2, 8(a0)
+	sd	a1, 16(a0)
+	ret
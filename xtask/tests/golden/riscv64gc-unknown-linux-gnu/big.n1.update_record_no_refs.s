@@ -0,0 +1,13 @@
+records_in_rust::big::n1::update_record_no_refs:
+	lbu	a2, 16(a1)
+	lw	a3, 8(a1)
+	lw	a4, 12(a1)
+	ld	a1, 0(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sd	a1, 0(a0)
+	sw	a4, 8(a0)
+	sw	a3, 12(a0)
+	sb	a2, 16(a0)
+	ret
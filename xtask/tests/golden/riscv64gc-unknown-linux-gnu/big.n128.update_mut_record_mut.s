@@ -0,0 +1,17 @@
+records_in_rust::big::n128::update_mut_record_mut:
+	addi	sp, sp, -16
+	sd	ra, 8(sp)
+	lbu	a2, 1032(a1)
+	lw	a3, 1024(a1)
+	lw	a4, 1028(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sw	a4, 1024(a1)
+	sw	a3, 1028(a1)
+	sb	a2, 1032(a1)
+	li	a2, 1040
+	call	memcpy
+	ld	ra, 8(sp)
+	addi	sp, sp, 16
+	ret
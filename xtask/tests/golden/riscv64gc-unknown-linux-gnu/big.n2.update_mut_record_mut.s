@@ -0,0 +1,19 @@
+records_in_rust::big::n2::update_mut_record_mut:
+	lw	a2, 16(a1)
+	lw	a3, 20(a1)
+	ld	a6, 0(a1)
+	ld	a5, 8(a1)
+	addi	a2, a2, 1
+	lbu	a4, 24(a1)
+	add	a3, a3, a2
+	sw	a3, 16(a1)
+	sw	a2, 20(a1)
+	ld	a2, 16(a1)
+	xori	a3, a4, 1
+	sb	a3, 24(a1)
+	ld	a1, 24(a1)
+	sd	a6, 0(a0)
+	sd	a5, 8(a0)
+	sd	a2, 16(a0)
+	sd	a1, 24(a0)
+	ret
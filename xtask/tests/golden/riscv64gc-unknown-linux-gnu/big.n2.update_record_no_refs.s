@@ -0,0 +1,15 @@
+records_in_rust::big::n2::update_record_no_refs:
+	lbu	a2, 24(a1)
+	lw	a3, 16(a1)
+	lw	a4, 20(a1)
+	ld	a5, 0(a1)
+	ld	a1, 8(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sd	a5, 0(a0)
+	sd	a1, 8(a0)
+	sw	a4, 16(a0)
+	sw	a3, 20(a0)
+	sb	a2, 24(a0)
+	ret
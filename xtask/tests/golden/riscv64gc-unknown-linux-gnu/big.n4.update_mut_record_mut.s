@@ -0,0 +1,23 @@
+records_in_rust::big::n4::update_mut_record_mut:
+	lw	a2, 32(a1)
+	lw	a3, 36(a1)
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	lbu	a4, 40(a1)
+	sw	a3, 32(a1)
+	sw	a2, 36(a1)
+	ld	a6, 32(a1)
+	xori	a3, a4, 1
+	sb	a3, 40(a1)
+	ld	a3, 0(a1)
+	ld	a4, 8(a1)
+	ld	a5, 16(a1)
+	ld	a2, 24(a1)
+	ld	a1, 40(a1)
+	sd	a3, 0(a0)
+	sd	a4, 8(a0)
+	sd	a5, 16(a0)
+	sd	a2, 24(a0)
+	sd	a6, 32(a0)
+	sd	a1, 40(a0)
+	ret
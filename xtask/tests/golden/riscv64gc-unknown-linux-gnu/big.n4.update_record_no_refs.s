@@ -0,0 +1,19 @@
+records_in_rust::big::n4::update_record_no_refs:
+	lw	a7, 32(a1)
+	lw	a6, 36(a1)
+	lbu	a4, 40(a1)
+	ld	a5, 0(a1)
+	ld	a3, 8(a1)
+	ld	a2, 16(a1)
+	ld	a1, 24(a1)
+	xori	a4, a4, 1
+	addi	a7, a7, 1
+	sd	a5, 0(a0)
+	sd	a3, 8(a0)
+	sd	a2, 16(a0)
+	sd	a1, 24(a0)
+	add	a6, a6, a7
+	sw	a6, 32(a0)
+	sw	a7, 36(a0)
+	sb	a4, 40(a0)
+	ret
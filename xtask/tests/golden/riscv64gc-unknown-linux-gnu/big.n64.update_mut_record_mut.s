@@ -0,0 +1,17 @@
+records_in_rust::big::n64::update_mut_record_mut:
+	addi	sp, sp, -16
+	sd	ra, 8(sp)
+	lbu	a2, 520(a1)
+	lw	a3, 512(a1)
+	lw	a4, 516(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sw	a4, 512(a1)
+	sw	a3, 516(a1)
+	sb	a2, 520(a1)
+	li	a2, 528
+	call	memcpy
+	ld	ra, 8(sp)
+	addi	sp, sp, 16
+	ret
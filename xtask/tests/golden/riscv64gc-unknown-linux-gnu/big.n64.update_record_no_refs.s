@@ -0,0 +1,27 @@
+records_in_rust::big::n64::update_record_no_refs:
+	addi	sp, sp, -48
+	sd	ra, 40(sp)
+	sd	s0, 32(sp)
+	sd	s1, 24(sp)
+	sd	s2, 16(sp)
+	sd	s3, 8(sp)
+	mv	s0, a0
+	lbu	a0, 520(a1)
+	lw	a2, 512(a1)
+	lw	s3, 516(a1)
+	xori	s2, a0, 1
+	addi	s1, a2, 1
+	li	a2, 512
+	mv	a0, s0
+	call	memcpy
+	add	s3, s3, s1
+	sw	s3, 512(s0)
+	sw	s1, 516(s0)
+	sb	s2, 520(s0)
+	ld	ra, 40(sp)
+	ld	s0, 32(sp)
+	ld	s1, 24(sp)
+	ld	s2, 16(sp)
+	ld	s3, 8(sp)
+	addi	sp, sp, 48
+	ret
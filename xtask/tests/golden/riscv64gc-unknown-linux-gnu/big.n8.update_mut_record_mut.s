@@ -0,0 +1,17 @@
+records_in_rust::big::n8::update_mut_record_mut:
+	addi	sp, sp, -16
+	sd	ra, 8(sp)
+	lbu	a2, 72(a1)
+	lw	a3, 64(a1)
+	lw	a4, 68(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sw	a4, 64(a1)
+	sw	a3, 68(a1)
+	sb	a2, 72(a1)
+	li	a2, 80
+	call	memcpy
+	ld	ra, 8(sp)
+	addi	sp, sp, 16
+	ret
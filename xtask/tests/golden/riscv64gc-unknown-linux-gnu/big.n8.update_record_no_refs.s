@@ -0,0 +1,27 @@
+records_in_rust::big::n8::update_record_no_refs:
+	lw	a7, 64(a1)
+	lw	a6, 68(a1)
+	lbu	t0, 72(a1)
+	ld	a5, 0(a1)
+	ld	a3, 8(a1)
+	ld	a2, 16(a1)
+	ld	a4, 24(a1)
+	sd	a5, 0(a0)
+	sd	a3, 8(a0)
+	sd	a2, 16(a0)
+	sd	a4, 24(a0)
+	ld	a2, 32(a1)
+	ld	a3, 40(a1)
+	ld	a4, 48(a1)
+	ld	a1, 56(a1)
+	xori	a5, t0, 1
+	addi	a7, a7, 1
+	sd	a2, 32(a0)
+	sd	a3, 40(a0)
+	sd	a4, 48(a0)
+	sd	a1, 56(a0)
+	add	a6, a6, a7
+	sw	a6, 64(a0)
+	sw	a7, 68(a0)
+	sb	a5, 72(a0)
+	ret
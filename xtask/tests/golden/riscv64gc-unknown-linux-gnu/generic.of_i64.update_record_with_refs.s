@@ -0,0 +1,12 @@
+records_in_rust::generic::of_i64::update_record_with_refs:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_with_refs
+	lbu	a1, 16(a0)
+	ld	a2, 0(a0)
+	ld	a3, 8(a0)
+	xori	a1, a1, 1
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	sd	a3, 0(a0)
+	sd	a2, 8(a0)
+	sb	a1, 16(a0)
+	ret
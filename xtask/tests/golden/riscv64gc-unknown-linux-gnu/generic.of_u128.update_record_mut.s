@@ -0,0 +1,28 @@
+records_in_rust::generic::of_u128::update_record_mut:
+	lbu	a6, 32(a1)
+	ld	a3, 0(a1)
+	ld	t0, 8(a1)
+	ld	a2, 16(a1)
+	ld	t1, 24(a1)
+	ld	a7, 40(a1)
+	xori	a4, a6, 1
+	addi	a3, a3, 1
+	sb	a4, 32(a1)
+	seqz	a4, a3
+	add	a4, a4, t0
+	add	a5, a2, a3
+	sltu	a2, a5, a2
+	add	t1, t1, a4
+	add	a2, a2, t1
+	ld	a6, 32(a1)
+	sd	a5, 0(a1)
+	sd	a2, 8(a1)
+	sd	a3, 16(a1)
+	sd	a4, 24(a1)
+	sd	a6, 32(a0)
+	sd	a7, 40(a0)
+	sd	a5, 0(a0)
+	sd	a2, 8(a0)
+	sd	a3, 16(a0)
+	sd	a4, 24(a0)
+	ret
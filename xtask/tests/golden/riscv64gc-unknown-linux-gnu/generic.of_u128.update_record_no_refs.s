@@ -0,0 +1,20 @@
+records_in_rust::generic::of_u128::update_record_no_refs:
+	lbu	a2, 32(a1)
+	ld	a3, 0(a1)
+	ld	a4, 8(a1)
+	ld	a5, 16(a1)
+	ld	a1, 24(a1)
+	xori	a6, a2, 1
+	addi	a3, a3, 1
+	seqz	a2, a3
+	add	a5, a5, a3
+	add	a2, a2, a4
+	sltu	a4, a5, a3
+	add	a1, a1, a2
+	add	a1, a1, a4
+	sd	a5, 0(a0)
+	sd	a1, 8(a0)
+	sd	a3, 16(a0)
+	sd	a2, 24(a0)
+	sb	a6, 32(a0)
+	ret
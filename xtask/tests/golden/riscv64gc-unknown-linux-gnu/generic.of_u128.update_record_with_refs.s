@@ -0,0 +1,20 @@
+records_in_rust::generic::of_u128::update_record_with_refs:
+	lbu	a1, 32(a0)
+	ld	a2, 0(a0)
+	ld	a3, 8(a0)
+	ld	a4, 16(a0)
+	ld	a5, 24(a0)
+	xori	a1, a1, 1
+	addi	a2, a2, 1
+	sb	a1, 32(a0)
+	seqz	a1, a2
+	add	a1, a1, a3
+	add	a3, a4, a2
+	sltu	a4, a3, a4
+	add	a5, a5, a1
+	add	a4, a4, a5
+	sd	a3, 0(a0)
+	sd	a4, 8(a0)
+	sd	a2, 16(a0)
+	sd	a1, 24(a0)
+	ret
@@ -0,0 +1,16 @@
+records_in_rust::generic::of_u64::update_record_mut:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_mut
+	lbu	a2, 16(a1)
+	ld	a3, 0(a1)
+	ld	a4, 8(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sb	a2, 16(a1)
+	ld	a2, 16(a1)
+	sd	a4, 0(a1)
+	sd	a3, 8(a1)
+	sd	a4, 0(a0)
+	sd	a3, 8(a0)
+	sd	a2, 16(a0)
+	ret
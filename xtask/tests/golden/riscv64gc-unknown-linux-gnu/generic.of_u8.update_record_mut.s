@@ -0,0 +1,14 @@
+records_in_rust::generic::of_u8::update_record_mut:
+	slli	a1, a0, 40
+	srli	a2, a0, 16
+	not	a0, a0
+	srli	a1, a1, 48
+	andi	a0, a0, 1
+	addi	a1, a1, 1
+	add	a2, a2, a1
+	slli	a1, a1, 16
+	zext.b	a2, a2
+	slli	a2, a2, 8
+	or	a0, a0, a1
+	or	a0, a0, a2
+	ret
@@ -0,0 +1,11 @@
+records_in_rust::generic::of_u8::update_record_with_refs:
+	lbu	a1, 0(a0)
+	lbu	a2, 1(a0)
+	lbu	a3, 2(a0)
+	xori	a1, a1, 1
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	sb	a1, 0(a0)
+	sb	a3, 1(a0)
+	sb	a2, 2(a0)
+	ret
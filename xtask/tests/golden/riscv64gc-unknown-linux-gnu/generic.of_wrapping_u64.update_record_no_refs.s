@@ -0,0 +1,11 @@
+records_in_rust::generic::of_wrapping_u64::update_record_no_refs:
+	lbu	a2, 16(a1)
+	ld	a3, 0(a1)
+	ld	a1, 8(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	sd	a1, 0(a0)
+	sd	a3, 8(a0)
+	sb	a2, 16(a0)
+	ret
@@ -0,0 +1,56 @@
+records_in_rust::heap::owned::update_record_from_ref:
+	addi	sp, sp, -112
+	sd	ra, 104(sp)
+	sd	s0, 96(sp)
+	sd	s1, 88(sp)
+	sd	s2, 80(sp)
+	sd	s3, 72(sp)
+	sd	s4, 64(sp)
+	sd	s5, 56(sp)
+	sd	s6, 48(sp)
+	sd	s7, 40(sp)
+	ld	s0, 16(a1)
+	lw	s3, 24(a1)
+	lw	s4, 28(a1)
+	lbu	s5, 32(a1)
+	beqz	s0, .L0
+	mv	s7, a0
+	ld	s2, 8(a1)
+	slli	s6, s0, 2
+	call	__rustc::__rust_no_alloc_shim_is_unstable_v2
+	li	a1, 4
+	mv	a0, s6
+	call	__rustc::__rust_alloc
+	beqz	a0, .L1
+	mv	s1, a0
+	mv	a1, s2
+	mv	a2, s6
+	call	memcpy
+	mv	a0, s7
+	j	.L2
+.L0:
+	li	s1, 4
+.L2:
+	sd	s0, 0(sp)
+	sd	s1, 8(sp)
+	sd	s0, 16(sp)
+	sw	s3, 24(sp)
+	sw	s4, 28(sp)
+	sb	s5, 32(sp)
+	mv	a1, sp
+	call	records_in_rust::heap::owned::update_record_no_refs
+	ld	ra, 104(sp)
+	ld	s0, 96(sp)
+	ld	s1, 88(sp)
+	ld	s2, 80(sp)
+	ld	s3, 72(sp)
+	ld	s4, 64(sp)
+	ld	s5, 56(sp)
+	ld	s6, 48(sp)
+	ld	s7, 40(sp)
+	addi	sp, sp, 112
+	ret
+.L1:
+	li	a0, 4
+	mv	a1, s6
+	call	alloc::raw_vec::handle_error
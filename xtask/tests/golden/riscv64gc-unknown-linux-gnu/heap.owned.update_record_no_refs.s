@@ -0,0 +1,70 @@
+records_in_rust::heap::owned::update_record_no_refs:
+.L0:
+	addi	sp, sp, -80
+	sd	ra, 72(sp)
+	sd	s0, 64(sp)
+	sd	s1, 56(sp)
+	sd	s2, 48(sp)
+	sd	s3, 40(sp)
+	sd	s4, 32(sp)
+	mv	s0, a0
+	ld	a0, 16(a1)
+	ld	s1, 16(a1)
+	lw	s4, 24(a1)
+	lw	s2, 28(a1)
+	lbu	a2, 32(a1)
+	ld	a3, 0(a1)
+	ld	a4, 0(a1)
+	ld	a5, 8(a1)
+	ld	a1, 8(a1)
+	not	a2, a2
+	addi	s4, s4, 1
+	andi	s3, a2, 1
+	sd	a3, 0(s0)
+	sd	a5, 8(s0)
+	sd	a0, 16(s0)
+	sw	s4, 24(s0)
+	sw	s2, 28(s0)
+	sb	s3, 32(s0)
+	sd	a4, 8(sp)
+	sd	a1, 16(sp)
+	sd	s1, 24(sp)
+	bne	s1, a4, .L1
+.L2:
+	addi	a0, sp, 8
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+.L3:
+.L1:
+	ld	a0, 8(sp)
+	ld	a1, 16(sp)
+	slli	a2, s1, 2
+	addi	s1, s1, 1
+	add	a3, s4, s2
+	add	a2, a2, a1
+	sd	a0, 0(s0)
+	sd	a1, 8(s0)
+	sd	s1, 16(s0)
+	sw	a3, 24(s0)
+	sw	s2, 0(a2)
+	sw	s4, 28(s0)
+	sb	s3, 32(s0)
+	ld	ra, 72(sp)
+	ld	s0, 64(sp)
+	ld	s1, 56(sp)
+	ld	s2, 48(sp)
+	ld	s3, 40(sp)
+	ld	s4, 32(sp)
+	addi	sp, sp, 80
+	ret
+.L4:
+.L5:
+	ld	a1, 8(sp)
+	mv	s0, a0
+	beqz	a1, .L6
+	ld	a0, 16(sp)
+	slli	a1, a1, 2
+	li	a2, 4
+	call	__rustc::__rust_dealloc
+.L6:
+	mv	a0, s0
+	call	_Unwind_Resume
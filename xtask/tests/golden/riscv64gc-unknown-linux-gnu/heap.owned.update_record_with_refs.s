@@ -0,0 +1,43 @@
+records_in_rust::heap::owned::update_record_with_refs:
+	addi	sp, sp, -48
+	sd	ra, 40(sp)
+	sd	s0, 32(sp)
+	sd	s1, 24(sp)
+	sd	s2, 16(sp)
+	sd	s3, 8(sp)
+	lbu	a1, 32(a0)
+	ld	s0, 16(a0)
+	lw	s1, 24(a0)
+	ld	a3, 0(a0)
+	lw	s3, 28(a0)
+	xori	a1, a1, 1
+	addi	s1, s1, 1
+	sw	s1, 24(a0)
+	sb	a1, 32(a0)
+	mv	a1, s3
+	mv	a2, s1
+	beq	s0, a3, .L0
+.L1:
+	ld	a3, 8(a0)
+	slli	a4, s0, 2
+	addi	s0, s0, 1
+	add	a1, a1, a2
+	add	a3, a3, a4
+	sw	s3, 0(a3)
+	sd	s0, 16(a0)
+	sw	a1, 24(a0)
+	sw	s1, 28(a0)
+	ld	ra, 40(sp)
+	ld	s0, 32(sp)
+	ld	s1, 24(sp)
+	ld	s2, 16(sp)
+	ld	s3, 8(sp)
+	addi	sp, sp, 48
+	ret
+.L0:
+	mv	s2, a0
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+	mv	a0, s2
+	lw	a2, 24(s2)
+	lw	a1, 28(s2)
+	j	.L1
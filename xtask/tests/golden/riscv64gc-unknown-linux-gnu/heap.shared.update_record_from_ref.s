@@ -0,0 +1,21 @@
+records_in_rust::heap::shared::update_record_from_ref:
+	ld	a4, 0(a1)
+	lw	a2, 8(a1)
+	lw	a3, 12(a1)
+	lbu	a1, 16(a1)
+	li	a5, 1
+	amoadd.d	a5, a5, (a4)
+	bltz	a5, .L0
+	addi	sp, sp, -32
+	sd	ra, 24(sp)
+	sd	a4, 0(sp)
+	sw	a2, 8(sp)
+	sw	a3, 12(sp)
+	sb	a1, 16(sp)
+	mv	a1, sp
+	call	records_in_rust::heap::shared::update_record_no_refs
+	ld	ra, 24(sp)
+	addi	sp, sp, 32
+	ret
+.L0:
+	unimp
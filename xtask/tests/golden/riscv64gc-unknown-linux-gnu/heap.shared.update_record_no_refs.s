@@ -0,0 +1,71 @@
+records_in_rust::heap::shared::update_record_no_refs:
+.L0:
+	addi	sp, sp, -64
+	sd	ra, 56(sp)
+	sd	s0, 48(sp)
+	sd	s1, 40(sp)
+	sd	s2, 32(sp)
+	sd	s3, 24(sp)
+	sd	s4, 16(sp)
+	sd	s5, 8(sp)
+	mv	s0, a0
+	lbu	a0, 16(a1)
+	lw	s4, 8(a1)
+	lw	s2, 12(a1)
+	ld	a1, 0(a1)
+	not	a0, a0
+	addi	s4, s4, 1
+	andi	s3, a0, 1
+	sd	a1, 0(s0)
+	sw	s4, 8(s0)
+	sw	s2, 12(s0)
+	sb	s3, 16(s0)
+	sd	a1, 0(sp)
+.L1:
+	mv	a0, sp
+	call	alloc::sync::Arc<T,A>::make_mut
+.L2:
+	mv	s1, a0
+	ld	a0, 0(a0)
+	ld	s5, 16(s1)
+	bne	s5, a0, .L3
+.L4:
+	mv	a0, s1
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+.L5:
+.L3:
+	ld	a0, 8(s1)
+	slli	a1, s5, 2
+	addi	s5, s5, 1
+	sd	s5, 16(s1)
+	ld	a2, 0(sp)
+	add	a3, s4, s2
+	add	a0, a0, a1
+	sw	s2, 0(a0)
+	sd	a2, 0(s0)
+	sw	a3, 8(s0)
+	sw	s4, 12(s0)
+	sb	s3, 16(s0)
+	ld	ra, 56(sp)
+	ld	s0, 48(sp)
+	ld	s1, 40(sp)
+	ld	s2, 32(sp)
+	ld	s3, 24(sp)
+	ld	s4, 16(sp)
+	ld	s5, 8(sp)
+	addi	sp, sp, 64
+	ret
+.L6:
+.L7:
+	ld	a1, 0(sp)
+	li	a2, -1
+	amoadd.d.rl	a1, a2, (a1)
+	li	a2, 1
+	mv	s0, a0
+	bne	a1, a2, .L8
+	fence	r, rw
+	mv	a0, sp
+	call	alloc::sync::Arc<T,A>::drop_slow
+.L8:
+	mv	a0, s0
+	call	_Unwind_Resume
@@ -0,0 +1,45 @@
+records_in_rust::heap::shared::update_record_with_refs:
+	addi	sp, sp, -48
+	sd	ra, 40(sp)
+	sd	s0, 32(sp)
+	sd	s1, 24(sp)
+	sd	s2, 16(sp)
+	sd	s3, 8(sp)
+	sd	s4, 0(sp)
+	mv	s0, a0
+	lbu	a0, 16(a0)
+	lw	s3, 8(s0)
+	xori	a0, a0, 1
+	addi	s3, s3, 1
+	sw	s3, 8(s0)
+	sb	a0, 16(s0)
+	mv	a0, s0
+	call	alloc::sync::Arc<T,A>::make_mut
+	ld	a1, 0(a0)
+	ld	s1, 16(a0)
+	lw	s4, 12(s0)
+	beq	s1, a1, .L0
+.L1:
+	ld	a1, 8(a0)
+	addi	a2, s1, 1
+	lw	a3, 8(s0)
+	sd	a2, 16(a0)
+	slli	s1, s1, 2
+	add	a1, a1, s1
+	add	a3, a3, s4
+	sw	s4, 0(a1)
+	sw	a3, 8(s0)
+	sw	s3, 12(s0)
+	ld	ra, 40(sp)
+	ld	s0, 32(sp)
+	ld	s1, 24(sp)
+	ld	s2, 16(sp)
+	ld	s3, 8(sp)
+	ld	s4, 0(sp)
+	addi	sp, sp, 48
+	ret
+.L0:
+	mv	s2, a0
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+	mv	a0, s2
+	j	.L1
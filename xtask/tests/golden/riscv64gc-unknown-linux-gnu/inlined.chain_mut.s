@@ -0,0 +1,30 @@
+records_in_rust::inlined::chain_mut:
+# same code as records_in_rust::inlined::chain_with_ptrs
+	lw	a2, 0(a1)
+	lw	a6, 4(a1)
+	lbu	a4, 8(a1)
+	lbu	a5, 9(a1)
+	lbu	a3, 10(a1)
+	lbu	a1, 11(a1)
+	addi	a2, a2, 2
+	andi	a4, a4, 1
+	add	a6, a6, a2
+	add	a2, a2, a6
+	add	a6, a6, a2
+	addi	a6, a6, 1
+	add	a2, a2, a6
+	addi	a2, a2, 1
+	add	a6, a6, a2
+	addi	a6, a6, 1
+	add	a2, a2, a6
+	addi	a2, a2, 1
+	add	a6, a6, a2
+	addi	a6, a6, 1
+	add	a2, a2, a6
+	sw	a2, 0(a0)
+	sw	a6, 4(a0)
+	sb	a4, 8(a0)
+	sb	a5, 9(a0)
+	sb	a3, 10(a0)
+	sb	a1, 11(a0)
+	ret
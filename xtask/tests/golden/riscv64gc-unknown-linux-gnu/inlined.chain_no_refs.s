@@ -0,0 +1,29 @@
+records_in_rust::inlined::chain_no_refs:
+	lw	a2, 0(a1)
+	lw	a3, 4(a1)
+	lbu	a4, 8(a1)
+	lbu	a6, 9(a1)
+	lbu	a5, 10(a1)
+	lbu	a1, 11(a1)
+	addi	a2, a2, 2
+	andi	a4, a4, 1
+	add	a3, a3, a2
+	add	a2, a2, a3
+	add	a3, a3, a2
+	addi	a3, a3, 1
+	add	a2, a2, a3
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	addi	a3, a3, 1
+	add	a2, a2, a3
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	addi	a3, a3, 1
+	add	a2, a2, a3
+	sw	a2, 0(a0)
+	sw	a3, 4(a0)
+	sb	a4, 8(a0)
+	sb	a6, 9(a0)
+	sb	a5, 10(a0)
+	sb	a1, 11(a0)
+	ret
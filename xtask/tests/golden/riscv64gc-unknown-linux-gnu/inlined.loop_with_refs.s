@@ -0,0 +1,25 @@
+records_in_rust::inlined::loop_with_refs:
+	lw	a1, 0(a0)
+	lbu	a2, 8(a0)
+	lw	a3, 4(a0)
+	addi	a1, a1, 1
+	andi	a2, a2, 1
+	add	a3, a3, a1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	addi	a1, a1, 1
+	add	a3, a3, a1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	addi	a1, a1, 1
+	add	a3, a3, a1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	addi	a1, a1, 1
+	add	a3, a3, a1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	sw	a1, 0(a0)
+	sw	a3, 4(a0)
+	sb	a2, 8(a0)
+	ret
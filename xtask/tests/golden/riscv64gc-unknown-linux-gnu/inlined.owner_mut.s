@@ -0,0 +1,16 @@
+records_in_rust::inlined::owner_mut:
+# same code as records_in_rust::inlined::owner_mut_record_mut
+	lbu	a1, 40(a0)
+	lw	a2, 32(a0)
+	ld	a3, 24(a0)
+	lw	a4, 36(a0)
+	not	a1, a1
+	addi	a2, a2, 1
+	addi	a3, a3, 1
+	andi	a1, a1, 1
+	add	a4, a4, a2
+	sd	a3, 24(a0)
+	sw	a4, 32(a0)
+	sw	a2, 36(a0)
+	sb	a1, 40(a0)
+	ret
@@ -0,0 +1,15 @@
+records_in_rust::layout::aligned::update_record_mut:
+	lw	a2, 0(a1)
+	lw	a3, 4(a1)
+	addi	a2, a2, 1
+	lbu	a4, 8(a1)
+	add	a3, a3, a2
+	sw	a3, 0(a1)
+	sw	a2, 4(a1)
+	ld	a2, 0(a1)
+	xori	a3, a4, 1
+	sb	a3, 8(a1)
+	ld	a1, 8(a1)
+	sd	a2, 0(a0)
+	sd	a1, 8(a0)
+	ret
@@ -0,0 +1,11 @@
+records_in_rust::layout::aligned::update_record_no_refs:
+	lbu	a2, 8(a1)
+	lw	a3, 0(a1)
+	lw	a1, 4(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	sw	a1, 0(a0)
+	sw	a3, 4(a0)
+	sb	a2, 8(a0)
+	ret
@@ -0,0 +1,16 @@
+records_in_rust::layout::bits::update_record_no_refs:
+# same code as records_in_rust::layout::bits::update_record_mut
+	addi	a1, a0, 1
+	li	a2, 1
+	srli	a3, a0, 32
+	slli	a2, a2, 31
+	add	a3, a3, a1
+	slli	a1, a1, 33
+	and	a0, a0, a2
+	addi	a4, a2, -1
+	srli	a1, a1, 1
+	and	a3, a3, a4
+	or	a0, a0, a1
+	or	a0, a0, a3
+	xor	a0, a0, a2
+	ret
@@ -0,0 +1,18 @@
+records_in_rust::layout::bits::update_record_with_refs:
+# same code as records_in_rust::layout::bits::update_record_with_ptrs
+	ld	a1, 0(a0)
+	li	a2, 1
+	slli	a2, a2, 31
+	addi	a3, a2, -1
+	addi	a4, a1, 1
+	and	a5, a1, a2
+	srli	a1, a1, 32
+	add	a1, a1, a4
+	slli	a4, a4, 33
+	and	a1, a1, a3
+	or	a1, a1, a5
+	srli	a4, a4, 1
+	or	a1, a1, a4
+	xor	a1, a1, a2
+	sd	a1, 0(a0)
+	ret
@@ -0,0 +1,16 @@
+records_in_rust::layout::c::update_record_mut:
+# same code as records_in_rust::update_record_mut
+	lbu	a2, 8(a1)
+	lw	a3, 0(a1)
+	lw	a4, 4(a1)
+	xori	a2, a2, 1
+	addi	a3, a3, 1
+	add	a4, a4, a3
+	sb	a2, 8(a1)
+	lw	a2, 8(a1)
+	sw	a4, 0(a1)
+	sw	a3, 4(a1)
+	sw	a4, 0(a0)
+	sw	a3, 4(a0)
+	sw	a2, 8(a0)
+	ret
@@ -0,0 +1,47 @@
+records_in_rust::layout::packed::update_record_mut:
+	addi	sp, sp, -16
+	sd	ra, 8(sp)
+	lbu	a2, 8(a1)
+	lbu	a3, 0(a1)
+	lbu	a4, 1(a1)
+	lbu	a6, 2(a1)
+	lb	t0, 3(a1)
+	xori	a2, a2, 1
+	slli	a4, a4, 8
+	sb	a2, 8(a1)
+	or	a7, a4, a3
+	lbu	t1, 4(a1)
+	lbu	a4, 5(a1)
+	lbu	a3, 6(a1)
+	lb	a5, 7(a1)
+	slli	a6, a6, 16
+	slli	t0, t0, 24
+	slli	a4, a4, 8
+	slli	a3, a3, 16
+	slli	a5, a5, 24
+	or	a2, t0, a6
+	or	a4, a4, t1
+	or	a3, a3, a5
+	or	a2, a2, a7
+	or	a3, a3, a4
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	srli	a6, a2, 16
+	srli	a7, a2, 24
+	srli	t0, a3, 16
+	srli	a5, a3, 24
+	srli	a4, a3, 8
+	sb	a3, 0(a1)
+	sb	a4, 1(a1)
+	sb	t0, 2(a1)
+	sb	a5, 3(a1)
+	srli	a3, a2, 8
+	sb	a2, 4(a1)
+	sb	a3, 5(a1)
+	sb	a6, 6(a1)
+	sb	a7, 7(a1)
+	li	a2, 9
+	call	memcpy
+	ld	ra, 8(sp)
+	addi	sp, sp, 16
+	ret
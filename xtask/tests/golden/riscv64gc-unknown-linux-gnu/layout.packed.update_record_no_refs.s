@@ -0,0 +1,41 @@
+records_in_rust::layout::packed::update_record_no_refs:
+	lbu	a2, 1(a1)
+	lbu	a3, 0(a1)
+	lbu	a7, 2(a1)
+	lb	a5, 3(a1)
+	slli	a2, a2, 8
+	or	a6, a2, a3
+	lbu	t0, 4(a1)
+	lbu	a2, 5(a1)
+	lbu	a4, 6(a1)
+	lb	a3, 7(a1)
+	lbu	a1, 8(a1)
+	slli	a7, a7, 16
+	slli	a5, a5, 24
+	slli	a2, a2, 8
+	slli	a4, a4, 16
+	slli	a3, a3, 24
+	xori	t1, a1, 1
+	or	a5, a5, a7
+	or	a2, a2, t0
+	or	a3, a3, a4
+	or	a4, a5, a6
+	or	a2, a2, a3
+	addi	a4, a4, 1
+	add	a2, a2, a4
+	srli	a7, a4, 24
+	srli	a5, a4, 16
+	srli	a1, a4, 8
+	srli	a6, a2, 24
+	srli	a3, a2, 16
+	sb	a4, 4(a0)
+	sb	a1, 5(a0)
+	sb	a5, 6(a0)
+	sb	a7, 7(a0)
+	srli	a1, a2, 8
+	sb	a2, 0(a0)
+	sb	a1, 1(a0)
+	sb	a3, 2(a0)
+	sb	a6, 3(a0)
+	sb	t1, 8(a0)
+	ret
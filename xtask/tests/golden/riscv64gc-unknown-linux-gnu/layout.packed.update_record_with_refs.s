@@ -0,0 +1,41 @@
+records_in_rust::layout::packed::update_record_with_refs:
+	lbu	a1, 8(a0)
+	lbu	a2, 0(a0)
+	lbu	a3, 1(a0)
+	lbu	a7, 2(a0)
+	lb	a5, 3(a0)
+	xori	a1, a1, 1
+	slli	a3, a3, 8
+	sb	a1, 8(a0)
+	or	a6, a3, a2
+	lbu	a1, 4(a0)
+	lbu	a3, 5(a0)
+	lbu	a2, 6(a0)
+	lb	a4, 7(a0)
+	slli	a7, a7, 16
+	slli	a5, a5, 24
+	slli	a3, a3, 8
+	slli	a2, a2, 16
+	slli	a4, a4, 24
+	or	a5, a5, a7
+	or	a1, a1, a3
+	or	a2, a2, a4
+	or	a3, a5, a6
+	or	a1, a1, a2
+	addi	a3, a3, 1
+	add	a1, a1, a3
+	srli	a6, a3, 16
+	srli	a7, a3, 24
+	srli	a5, a1, 16
+	srli	a2, a1, 24
+	srli	a4, a1, 8
+	sb	a1, 0(a0)
+	sb	a4, 1(a0)
+	sb	a5, 2(a0)
+	sb	a2, 3(a0)
+	srli	a1, a3, 8
+	sb	a3, 4(a0)
+	sb	a1, 5(a0)
+	sb	a6, 6(a0)
+	sb	a7, 7(a0)
+	ret
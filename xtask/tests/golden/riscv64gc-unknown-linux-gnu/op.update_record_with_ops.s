@@ -0,0 +1,18 @@
+records_in_rust::op::update_record_with_ops:
+	lw	a2, 0(a1)
+	lw	a3, 4(a1)
+	lbu	a4, 8(a1)
+	lbu	a6, 9(a1)
+	lbu	a5, 10(a1)
+	lbu	a1, 11(a1)
+	addi	a2, a2, 1
+	not	a4, a4
+	add	a3, a3, a2
+	andi	a4, a4, 1
+	sw	a3, 0(a0)
+	sw	a2, 4(a0)
+	sb	a4, 8(a0)
+	sb	a6, 9(a0)
+	sb	a5, 10(a0)
+	sb	a1, 11(a0)
+	ret
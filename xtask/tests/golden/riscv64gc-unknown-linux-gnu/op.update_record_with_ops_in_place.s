@@ -0,0 +1,11 @@
+records_in_rust::op::update_record_with_ops_in_place:
+	lbu	a1, 8(a0)
+	lw	a2, 0(a0)
+	lw	a3, 4(a0)
+	xori	a1, a1, 1
+	addi	a2, a2, 1
+	add	a3, a3, a2
+	sw	a3, 0(a0)
+	sw	a2, 4(a0)
+	sb	a1, 8(a0)
+	ret
@@ -0,0 +1,61 @@
+records_in_rust::soa::RecordBatch::update_all:
+	ld	a1, 64(a0)
+	beqz	a1, .L0
+	ld	a2, 56(a0)
+	slli	a1, a1, 3
+	add	a3, a2, a1
+.L1:
+	ld	a4, 0(a2)
+	not	a4, a4
+	addi	a5, a2, 8
+	addi	a1, a1, -8
+	sd	a4, 0(a2)
+	mv	a2, a5
+	bnez	a1, .L1
+	ld	a6, 16(a0)
+	andi	a1, a6, 63
+	beqz	a1, .L2
+	li	a4, -1
+	ld	a5, -8(a3)
+	sll	a4, a4, a1
+	ld	a1, 8(a0)
+	not	a4, a4
+	and	a4, a4, a5
+	sd	a4, -8(a3)
+	j	.L3
+.L0:
+	ld	a6, 16(a0)
+.L2:
+	ld	a1, 8(a0)
+	beqz	a6, .L4
+.L3:
+	slli	a3, a6, 2
+	add	a3, a3, a1
+	mv	a4, a1
+.L5:
+	lw	a5, 0(a4)
+	addi	a2, a4, 4
+	addi	a5, a5, 1
+	sw	a5, 0(a4)
+	mv	a4, a2
+	bne	a2, a3, .L5
+.L4:
+	ld	a3, 40(a0)
+	bltu	a3, a6, .L6
+	mv	a3, a6
+.L6:
+	beqz	a3, .L7
+	ld	a0, 32(a0)
+	slli	a2, a3, 2
+	add	a2, a2, a0
+.L8:
+	lw	a3, 0(a1)
+	lw	a4, 0(a0)
+	add	a4, a4, a3
+	sw	a4, 0(a1)
+	sw	a3, 0(a0)
+	addi	a0, a0, 4
+	addi	a1, a1, 4
+	bne	a0, a2, .L8
+.L7:
+	ret
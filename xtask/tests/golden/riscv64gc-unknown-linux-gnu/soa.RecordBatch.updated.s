@@ -0,0 +1,73 @@
+records_in_rust::soa::RecordBatch::updated:
+	ld	t1, 64(a1)
+	ld	a6, 56(a1)
+	slli	a2, t1, 3
+	beqz	t1, .L0
+	add	a3, a6, a2
+	mv	a4, a6
+.L1:
+	ld	a5, 0(a4)
+	not	a5, a5
+	sd	a5, 0(a4)
+	addi	a4, a4, 8
+	bne	a4, a3, .L1
+.L0:
+	ld	t2, 8(a1)
+	ld	t5, 16(a1)
+	ld	a7, 40(a1)
+	beqz	t1, .L2
+	andi	a4, t5, 63
+	beqz	a4, .L2
+	add	a2, a2, a6
+	ld	t0, -8(a2)
+	li	a5, -1
+	sll	a4, a5, a4
+	not	a4, a4
+	and	a4, t0, a4
+	sd	a4, -8(a2)
+	j	.L3
+.L2:
+	beqz	t5, .L4
+.L3:
+	slli	a2, t5, 2
+	add	a2, a2, t2
+	mv	a4, t2
+.L5:
+	lw	a5, 0(a4)
+	addi	a5, a5, 1
+	sw	a5, 0(a4)
+	addi	a4, a4, 4
+	bne	a4, a2, .L5
+.L4:
+	mv	t6, t5
+	bltu	t5, a7, .L6
+	mv	t6, a7
+.L6:
+	ld	a7, 0(a1)
+	ld	t0, 24(a1)
+	ld	t3, 32(a1)
+	ld	t4, 48(a1)
+	beqz	t6, .L7
+	slli	a4, t6, 2
+	add	a4, a4, t2
+	mv	a2, t3
+	mv	a1, t2
+.L8:
+	lw	a3, 0(a2)
+	lw	a5, 0(a1)
+	addi	a1, a1, 4
+	add	a3, a3, a5
+	sw	a3, 0(a2)
+	addi	a2, a2, 4
+	bne	a1, a4, .L8
+.L7:
+	sd	t0, 0(a0)
+	sd	t3, 8(a0)
+	sd	t6, 16(a0)
+	sd	a7, 24(a0)
+	sd	t2, 32(a0)
+	sd	t5, 40(a0)
+	sd	t4, 48(a0)
+	sd	a6, 56(a0)
+	sd	t1, 64(a0)
+	ret
@@ -0,0 +1,12 @@
+records_in_rust::update_record_with_mut_tmp_var:
+	lbu	a1, 8(a0)
+	lw	a2, 0(a0)
+	lw	a3, 4(a0)
+	not	a1, a1
+	addi	a2, a2, 1
+	andi	a1, a1, 1
+	add	a3, a3, a2
+	sw	a3, 0(a0)
+	sw	a2, 4(a0)
+	sb	a1, 8(a0)
+	ret
@@ -0,0 +1,151 @@
+records_in_rust::batch::map_all_with_minimal_vars:
+	local.get	1
+	i32.load	0
+	local.set	2
+	local.get	1
+	i32.load	4
+	local.set	3
+	block
+	local.get	1
+	i32.load	8
+	local.tee	4
+	i32.eqz
+	br_if   	0
+	i32.const	0
+	local.set	5
+	block
+	local.get	4
+	i32.const	1
+	i32.eq
+	br_if   	0
+	local.get	4
+	i32.const	1
+	i32.and
+	local.set	6
+	local.get	4
+	i32.const	268435454
+	i32.and
+	local.set	7
+	i32.const	0
+	local.set	5
+	local.get	3
+	local.set	1
+.L0:
+	loop
+	local.get	1
+	i32.const	4
+	i32.add
+	local.tee	8
+	i32.load	0
+	local.set	9
+	local.get	8
+	local.get	1
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	10
+	i32.store	0
+	local.get	1
+	local.get	10
+	local.get	9
+	i32.add
+	i32.store	0
+	local.get	1
+	i32.const	16
+	i32.add
+	local.tee	8
+	i32.load	0
+	local.set	9
+	local.get	8
+	local.get	1
+	i32.const	12
+	i32.add
+	local.tee	10
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	11
+	i32.store	0
+	local.get	1
+	i32.const	8
+	i32.add
+	local.tee	8
+	local.get	8
+	i32.load8_u	0
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	0
+	local.get	10
+	local.get	11
+	local.get	9
+	i32.add
+	i32.store	0
+	local.get	1
+	i32.const	20
+	i32.add
+	local.tee	8
+	local.get	8
+	i32.load8_u	0
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	0
+	local.get	1
+	i32.const	24
+	i32.add
+	local.set	1
+	local.get	7
+	local.get	5
+	i32.const	2
+	i32.add
+	local.tee	5
+	i32.ne
+	br_if   	0
+	end_loop
+	local.get	6
+	i32.eqz
+	br_if   	1
+.L1:
+	end_block
+	local.get	3
+	local.get	5
+	i32.const	12
+	i32.mul
+	i32.add
+	local.tee	1
+	i32.load	4
+	local.set	5
+	local.get	1
+	local.get	1
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	8
+	i32.store	4
+	local.get	1
+	local.get	1
+	i32.load8_u	8
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	8
+	local.get	1
+	local.get	8
+	local.get	5
+	i32.add
+	i32.store	0
+.L2:
+	end_block
+	local.get	0
+	local.get	4
+	i32.store	8
+	local.get	0
+	local.get	3
+	i32.store	4
+	local.get	0
+	local.get	2
+	i32.store	0
@@ -0,0 +1,131 @@
+records_in_rust::batch::update_all_with_mut_tmp_var:
+# same code as records_in_rust::batch::update_all_mut
+	block
+	local.get	1
+	i32.eqz
+	br_if   	0
+	local.get	0
+	local.set	2
+	block
+	local.get	1
+	i32.const	12
+	i32.mul
+	local.tee	3
+	i32.const	-12
+	i32.add
+	local.tee	1
+	i32.const	12
+	i32.div_u
+	i32.const	1
+	i32.and
+	br_if   	0
+	local.get	0
+	i32.load	4
+	local.set	2
+	local.get	0
+	local.get	0
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	4
+	i32.store	4
+	local.get	0
+	local.get	0
+	i32.load8_u	8
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	8
+	local.get	0
+	local.get	4
+	local.get	2
+	i32.add
+	i32.store	0
+	local.get	0
+	i32.const	12
+	i32.add
+	local.set	2
+.L0:
+	end_block
+	local.get	1
+	i32.const	12
+	i32.lt_u
+	br_if   	0
+	local.get	0
+	local.get	3
+	i32.add
+	local.set	5
+.L1:
+	loop
+	local.get	2
+	i32.const	4
+	i32.add
+	local.tee	0
+	i32.load	0
+	local.set	1
+	local.get	0
+	local.get	2
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	0
+	local.get	2
+	local.get	3
+	local.get	1
+	i32.add
+	i32.store	0
+	local.get	2
+	i32.const	16
+	i32.add
+	local.tee	0
+	i32.load	0
+	local.set	1
+	local.get	0
+	local.get	2
+	i32.const	12
+	i32.add
+	local.tee	3
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	4
+	i32.store	0
+	local.get	2
+	i32.const	8
+	i32.add
+	local.tee	0
+	local.get	0
+	i32.load8_u	0
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	0
+	local.get	3
+	local.get	4
+	local.get	1
+	i32.add
+	i32.store	0
+	local.get	2
+	i32.const	20
+	i32.add
+	local.tee	0
+	local.get	0
+	i32.load8_u	0
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	0
+	local.get	2
+	i32.const	24
+	i32.add
+	local.tee	2
+	local.get	5
+	i32.ne
+	br_if   	0
+.L2:
+	end_loop
+	end_block
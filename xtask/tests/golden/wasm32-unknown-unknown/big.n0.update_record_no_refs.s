@@ -0,0 +1,20 @@
+records_in_rust::big::n0::update_record_no_refs:
+	local.get	0
+	local.get	1
+	i32.load8_u	8
+	i32.const	1
+	i32.xor
+	i32.store8	8
+	local.get	0
+	local.get	1
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store	4
+	local.get	0
+	local.get	2
+	local.get	1
+	i32.load	4
+	i32.add
+	i32.store	0
@@ -0,0 +1,24 @@
+records_in_rust::big::n1::update_record_no_refs:
+	local.get	0
+	local.get	1
+	i64.load	0
+	i64.store	0
+	local.get	0
+	local.get	1
+	i32.load8_u	16
+	i32.const	1
+	i32.xor
+	i32.store8	16
+	local.get	0
+	local.get	1
+	i32.load	8
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store	12
+	local.get	0
+	local.get	2
+	local.get	1
+	i32.load	12
+	i32.add
+	i32.store	8
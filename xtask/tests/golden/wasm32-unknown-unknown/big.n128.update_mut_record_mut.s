@@ -0,0 +1,26 @@
+records_in_rust::big::n128::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	1032
+	i32.const	1
+	i32.xor
+	i32.store8	1032
+	local.get	1
+	i32.load	1028
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	1024
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	1028
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	1024
+	local.get	0
+	local.get	1
+	i32.const	1040
+	memory.copy	0, 0
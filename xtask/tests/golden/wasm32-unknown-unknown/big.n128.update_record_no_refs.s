@@ -0,0 +1,30 @@
+records_in_rust::big::n128::update_record_no_refs:
+	local.get	1
+	i32.load	1028
+	local.set	2
+	local.get	1
+	i32.load	1024
+	local.set	3
+	local.get	1
+	i32.load8_u	1032
+	local.set	4
+	local.get	0
+	local.get	1
+	i32.const	1024
+	memory.copy	0, 0
+	local.get	0
+	local.get	4
+	i32.const	1
+	i32.xor
+	i32.store8	1032
+	local.get	0
+	local.get	3
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	1028
+	local.get	0
+	local.get	1
+	local.get	2
+	i32.add
+	i32.store	1024
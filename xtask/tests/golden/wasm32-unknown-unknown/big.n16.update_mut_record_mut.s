@@ -0,0 +1,26 @@
+records_in_rust::big::n16::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	136
+	i32.const	1
+	i32.xor
+	i32.store8	136
+	local.get	1
+	i32.load	132
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	128
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	132
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	128
+	local.get	0
+	local.get	1
+	i32.const	144
+	memory.copy	0, 0
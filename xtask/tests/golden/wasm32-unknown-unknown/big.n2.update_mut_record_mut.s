@@ -0,0 +1,38 @@
+records_in_rust::big::n2::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	24
+	i32.const	1
+	i32.xor
+	i32.store8	24
+	local.get	1
+	i32.load	20
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	16
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	20
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	16
+	local.get	0
+	local.get	1
+	i64.load	0
+	i64.store	0
+	local.get	0
+	local.get	1
+	i64.load	8
+	i64.store	8
+	local.get	0
+	local.get	1
+	i64.load	24
+	i64.store	24
+	local.get	0
+	local.get	1
+	i64.load	16
+	i64.store	16
@@ -0,0 +1,26 @@
+records_in_rust::big::n32::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	264
+	i32.const	1
+	i32.xor
+	i32.store8	264
+	local.get	1
+	i32.load	260
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	256
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	260
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	256
+	local.get	0
+	local.get	1
+	i32.const	272
+	memory.copy	0, 0
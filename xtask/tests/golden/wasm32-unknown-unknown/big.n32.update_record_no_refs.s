@@ -0,0 +1,30 @@
+records_in_rust::big::n32::update_record_no_refs:
+	local.get	1
+	i32.load	260
+	local.set	2
+	local.get	1
+	i32.load	256
+	local.set	3
+	local.get	1
+	i32.load8_u	264
+	local.set	4
+	local.get	0
+	local.get	1
+	i32.const	256
+	memory.copy	0, 0
+	local.get	0
+	local.get	4
+	i32.const	1
+	i32.xor
+	i32.store8	264
+	local.get	0
+	local.get	3
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	260
+	local.get	0
+	local.get	1
+	local.get	2
+	i32.add
+	i32.store	256
@@ -0,0 +1,36 @@
+records_in_rust::big::n4::update_record_no_refs:
+	local.get	0
+	local.get	1
+	i64.load	0
+	i64.store	0
+	local.get	0
+	local.get	1
+	i64.load	8
+	i64.store	8
+	local.get	0
+	local.get	1
+	i64.load	16
+	i64.store	16
+	local.get	0
+	local.get	1
+	i64.load	24
+	i64.store	24
+	local.get	0
+	local.get	1
+	i32.load8_u	40
+	i32.const	1
+	i32.xor
+	i32.store8	40
+	local.get	0
+	local.get	1
+	i32.load	32
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store	36
+	local.get	0
+	local.get	2
+	local.get	1
+	i32.load	36
+	i32.add
+	i32.store	32
@@ -0,0 +1,26 @@
+records_in_rust::big::n64::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	520
+	i32.const	1
+	i32.xor
+	i32.store8	520
+	local.get	1
+	i32.load	516
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	512
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	516
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	512
+	local.get	0
+	local.get	1
+	i32.const	528
+	memory.copy	0, 0
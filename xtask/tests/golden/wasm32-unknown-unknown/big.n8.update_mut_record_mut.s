@@ -0,0 +1,26 @@
+records_in_rust::big::n8::update_mut_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	72
+	i32.const	1
+	i32.xor
+	i32.store8	72
+	local.get	1
+	i32.load	68
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	64
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	68
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	64
+	local.get	0
+	local.get	1
+	i32.const	80
+	memory.copy	0, 0
@@ -0,0 +1,56 @@
+records_in_rust::big::n8::update_record_no_refs:
+	local.get	0
+	local.get	1
+	i64.load	0
+	i64.store	0
+	local.get	0
+	local.get	1
+	i64.load	8
+	i64.store	8
+	local.get	0
+	local.get	1
+	i64.load	16
+	i64.store	16
+	local.get	0
+	local.get	1
+	i64.load	24
+	i64.store	24
+	local.get	0
+	local.get	1
+	i64.load	32
+	i64.store	32
+	local.get	0
+	local.get	1
+	i64.load	40
+	i64.store	40
+	local.get	0
+	local.get	1
+	i64.load	48
+	i64.store	48
+	local.get	0
+	local.get	1
+	i64.load	56
+	i64.store	56
+	local.get	1
+	i32.load	68
+	local.set	2
+	local.get	1
+	i32.load	64
+	local.set	3
+	local.get	0
+	local.get	1
+	i32.load8_u	72
+	i32.const	1
+	i32.xor
+	i32.store8	72
+	local.get	0
+	local.get	3
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	68
+	local.get	0
+	local.get	1
+	local.get	2
+	i32.add
+	i32.store	64
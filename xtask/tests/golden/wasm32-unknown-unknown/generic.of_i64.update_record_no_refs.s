@@ -0,0 +1,21 @@
+records_in_rust::generic::of_i64::update_record_no_refs:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_no_refs
+	local.get	0
+	local.get	1
+	i32.load8_u	16
+	i32.const	1
+	i32.xor
+	i32.store8	16
+	local.get	0
+	local.get	1
+	i64.load	0
+	i64.const	1
+	i64.add
+	local.tee	2
+	i64.store	8
+	local.get	0
+	local.get	2
+	local.get	1
+	i64.load	8
+	i64.add
+	i64.store	0
@@ -0,0 +1,66 @@
+records_in_rust::generic::of_u128::update_record_mut:
+	local.get	1
+	local.get	1
+	i32.load8_u	32
+	i32.const	1
+	i32.xor
+	i32.store8	32
+	local.get	0
+	local.get	1
+	i64.load	40
+	i64.store	40
+	local.get	1
+	i64.load	16
+	local.set	2
+	local.get	1
+	local.get	1
+	i64.load	0
+	i64.const	1
+	i64.add
+	local.tee	3
+	i64.store	16
+	local.get	0
+	local.get	3
+	i64.store	16
+	local.get	1
+	local.get	2
+	local.get	3
+	i64.add
+	local.tee	4
+	i64.store	0
+	local.get	0
+	local.get	4
+	i64.store	0
+	local.get	0
+	local.get	1
+	i64.load	32
+	i64.store	32
+	local.get	1
+	i64.load	24
+	local.set	5
+	local.get	1
+	local.get	1
+	i64.load	8
+	local.get	3
+	i64.eqz
+	i64.extend_i32_u
+	i64.add
+	local.tee	3
+	i64.store	24
+	local.get	0
+	local.get	3
+	i64.store	24
+	local.get	1
+	local.get	5
+	local.get	3
+	i64.add
+	local.get	4
+	local.get	2
+	i64.lt_u
+	i64.extend_i32_u
+	i64.add
+	local.tee	3
+	i64.store	8
+	local.get	0
+	local.get	3
+	i64.store	8
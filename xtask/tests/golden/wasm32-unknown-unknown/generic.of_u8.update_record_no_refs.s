@@ -0,0 +1,28 @@
+records_in_rust::generic::of_u8::update_record_no_refs:
+# same code as records_in_rust::generic::of_u8::update_record_mut
+	local.get	0
+	i32.const	16776960
+	i32.and
+	i32.const	8
+	i32.shr_u
+	i32.const	1
+	i32.add
+	local.tee	1
+	local.get	0
+	i32.const	16
+	i32.shr_u
+	i32.add
+	i32.const	255
+	i32.and
+	i32.const	8
+	i32.shl
+	local.get	1
+	i32.const	16
+	i32.shl
+	i32.or
+	local.get	0
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.or
@@ -0,0 +1,22 @@
+records_in_rust::generic::of_u8::update_record_with_refs:
+	local.get	0
+	local.get	0
+	i32.load8_u	0
+	i32.const	1
+	i32.xor
+	i32.store8	0
+	local.get	0
+	i32.load8_u	2
+	local.set	1
+	local.get	0
+	local.get	0
+	i32.load8_u	1
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store8	2
+	local.get	0
+	local.get	1
+	local.get	2
+	i32.add
+	i32.store8	1
@@ -0,0 +1,83 @@
+records_in_rust::heap::owned::update_record_from_ref:
+	global.get	__stack_pointer
+	i32.const	32
+	i32.sub
+	local.tee	2
+	global.set	__stack_pointer
+	local.get	1
+	i32.load8_u	20
+	local.set	3
+	local.get	1
+	i32.load	16
+	local.set	4
+	local.get	1
+	i32.load	12
+	local.set	5
+	block
+	block
+	block
+	local.get	1
+	i32.load	8
+	local.tee	6
+	br_if   	0
+	i32.const	4
+	local.set	1
+	br      	1
+.L0:
+	end_block
+	local.get	1
+	i32.load	4
+	local.set	7
+	call	__rustc::__rust_no_alloc_shim_is_unstable_v2
+	local.get	6
+	i32.const	2
+	i32.shl
+	local.tee	8
+	i32.const	4
+	call	__rustc::__rust_alloc
+	local.tee	1
+	i32.eqz
+	br_if   	1
+	local.get	8
+	i32.eqz
+	br_if   	0
+	local.get	1
+	local.get	7
+	local.get	8
+	memory.copy	0, 0
+.L1:
+	end_block
+	local.get	2
+	local.get	3
+	i32.store8	28
+	local.get	2
+	local.get	4
+	i32.store	24
+	local.get	2
+	local.get	5
+	i32.store	20
+	local.get	2
+	local.get	6
+	i32.store	16
+	local.get	2
+	local.get	1
+	i32.store	12
+	local.get	2
+	local.get	6
+	i32.store	8
+	local.get	0
+	local.get	2
+	i32.const	8
+	i32.add
+	call	records_in_rust::heap::owned::update_record_no_refs
+	local.get	2
+	i32.const	32
+	i32.add
+	global.set	__stack_pointer
+	return
+.L2:
+	end_block
+	i32.const	4
+	local.get	8
+	call	alloc::raw_vec::handle_error
+	unreachable
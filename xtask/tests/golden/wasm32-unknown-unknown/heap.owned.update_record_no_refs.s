@@ -0,0 +1,78 @@
+records_in_rust::heap::owned::update_record_no_refs:
+	global.get	__stack_pointer
+	i32.const	16
+	i32.sub
+	local.tee	2
+	global.set	__stack_pointer
+	local.get	2
+	local.get	1
+	i64.load	0:p2align=2
+	local.tee	3
+	i64.store	0
+	local.get	2
+	local.get	1
+	i32.load	8
+	local.tee	4
+	i32.store	8
+	local.get	1
+	i32.load8_u	20
+	local.set	5
+	local.get	1
+	i32.load	16
+	local.set	6
+	local.get	1
+	i32.load	12
+	local.set	1
+	block
+	local.get	4
+	local.get	3
+	i32.wrap_i64
+	i32.ne
+	br_if   	0
+	local.get	2
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+.L0:
+	end_block
+	local.get	0
+	local.get	2
+	i64.load	0
+	i64.store	0:p2align=2
+	local.get	0
+	local.get	4
+	i32.const	1
+	i32.add
+	local.tee	7
+	i32.store	8
+	local.get	0
+	local.get	1
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	16
+	local.get	2
+	i32.load	4
+	local.get	4
+	i32.const	2
+	i32.shl
+	i32.add
+	local.get	6
+	i32.store	0
+	local.get	0
+	local.get	5
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	20
+	local.get	0
+	local.get	1
+	local.get	6
+	i32.add
+	i32.store	12
+	local.get	2
+	local.get	7
+	i32.store	8
+	local.get	2
+	i32.const	16
+	i32.add
+	global.set	__stack_pointer
@@ -0,0 +1,59 @@
+records_in_rust::heap::owned::update_record_with_refs:
+	local.get	0
+	local.get	0
+	i32.load8_u	20
+	i32.const	1
+	i32.xor
+	i32.store8	20
+	local.get	0
+	local.get	0
+	i32.load	12
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	12
+	local.get	0
+	i32.load	16
+	local.tee	2
+	local.set	3
+	local.get	1
+	local.set	4
+	block
+	local.get	0
+	i32.load	8
+	local.tee	5
+	local.get	0
+	i32.load	0
+	i32.ne
+	br_if   	0
+	local.get	0
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+	local.get	0
+	i32.load	16
+	local.set	3
+	local.get	0
+	i32.load	12
+	local.set	4
+.L0:
+	end_block
+	local.get	0
+	local.get	1
+	i32.store	16
+	local.get	0
+	local.get	4
+	local.get	3
+	i32.add
+	i32.store	12
+	local.get	0
+	local.get	5
+	i32.const	1
+	i32.add
+	i32.store	8
+	local.get	0
+	i32.load	4
+	local.get	5
+	i32.const	2
+	i32.shl
+	i32.add
+	local.get	2
+	i32.store	0
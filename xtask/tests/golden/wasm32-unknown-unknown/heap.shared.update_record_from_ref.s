@@ -0,0 +1,50 @@
+records_in_rust::heap::shared::update_record_from_ref:
+	global.get	__stack_pointer
+	i32.const	16
+	i32.sub
+	local.tee	2
+	global.set	__stack_pointer
+	local.get	1
+	i32.load	0
+	local.tee	3
+	local.get	3
+	i32.load	0
+	local.tee	4
+	i32.const	1
+	i32.add
+	i32.store	0
+	block
+	local.get	4
+	i32.const	0
+	i32.lt_s
+	br_if   	0
+	local.get	1
+	i32.load	8
+	local.set	4
+	local.get	1
+	i32.load	4
+	local.set	5
+	local.get	2
+	local.get	1
+	i32.load8_u	12
+	i32.store8	12
+	local.get	2
+	local.get	4
+	i32.store	8
+	local.get	2
+	local.get	5
+	i32.store	4
+	local.get	2
+	local.get	3
+	i32.store	0
+	local.get	0
+	local.get	2
+	call	records_in_rust::heap::shared::update_record_no_refs
+	local.get	2
+	i32.const	16
+	i32.add
+	global.set	__stack_pointer
+	return
+.L0:
+	end_block
+	unreachable
@@ -0,0 +1,76 @@
+records_in_rust::heap::shared::update_record_no_refs:
+	global.get	__stack_pointer
+	i32.const	16
+	i32.sub
+	local.tee	2
+	global.set	__stack_pointer
+	local.get	1
+	i32.load8_u	12
+	local.set	3
+	local.get	1
+	i32.load	8
+	local.set	4
+	local.get	1
+	i32.load	4
+	local.set	5
+	local.get	2
+	local.get	1
+	i32.load	0
+	i32.store	12
+	block
+	local.get	2
+	i32.const	12
+	i32.add
+	call	alloc::sync::Arc<T,A>::make_mut
+	local.tee	1
+	i32.load	8
+	local.tee	6
+	local.get	1
+	i32.load	0
+	i32.ne
+	br_if   	0
+	local.get	1
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+.L0:
+	end_block
+	local.get	1
+	i32.load	4
+	local.get	6
+	i32.const	2
+	i32.shl
+	i32.add
+	local.get	4
+	i32.store	0
+	local.get	1
+	local.get	6
+	i32.const	1
+	i32.add
+	i32.store	8
+	local.get	2
+	i32.load	12
+	local.set	1
+	local.get	0
+	local.get	3
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	12
+	local.get	0
+	local.get	5
+	i32.const	1
+	i32.add
+	local.tee	6
+	i32.store	8
+	local.get	0
+	local.get	6
+	local.get	4
+	i32.add
+	i32.store	4
+	local.get	0
+	local.get	1
+	i32.store	0
+	local.get	2
+	i32.const	16
+	i32.add
+	global.set	__stack_pointer
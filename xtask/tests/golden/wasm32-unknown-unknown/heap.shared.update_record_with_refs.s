@@ -0,0 +1,54 @@
+records_in_rust::heap::shared::update_record_with_refs:
+	local.get	0
+	local.get	0
+	i32.load8_u	12
+	i32.const	1
+	i32.xor
+	i32.store8	12
+	local.get	0
+	local.get	0
+	i32.load	4
+	i32.const	1
+	i32.add
+	local.tee	1
+	i32.store	4
+	local.get	0
+	call	alloc::sync::Arc<T,A>::make_mut
+	local.set	2
+	local.get	0
+	i32.load	8
+	local.set	3
+	block
+	local.get	2
+	i32.load	8
+	local.tee	4
+	local.get	2
+	i32.load	0
+	i32.ne
+	br_if   	0
+	local.get	2
+	call	alloc::raw_vec::RawVec<T,A>::grow_one
+.L0:
+	end_block
+	local.get	2
+	i32.load	4
+	local.get	4
+	i32.const	2
+	i32.shl
+	i32.add
+	local.get	3
+	i32.store	0
+	local.get	0
+	local.get	1
+	i32.store	8
+	local.get	0
+	local.get	0
+	i32.load	4
+	local.get	3
+	i32.add
+	i32.store	4
+	local.get	2
+	local.get	4
+	i32.const	1
+	i32.add
+	i32.store	8
@@ -0,0 +1,31 @@
+records_in_rust::inlined::owner_mut:
+# same code as records_in_rust::inlined::owner_mut_record_mut
+	local.get	0
+	i32.load	36
+	local.set	1
+	local.get	0
+	local.get	0
+	i32.load	32
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store	36
+	local.get	0
+	local.get	0
+	i64.load	24
+	i64.const	1
+	i64.add
+	i64.store	24
+	local.get	0
+	local.get	0
+	i32.load8_u	40
+	i32.const	-1
+	i32.xor
+	i32.const	1
+	i32.and
+	i32.store8	40
+	local.get	0
+	local.get	2
+	local.get	1
+	i32.add
+	i32.store	32
@@ -0,0 +1,24 @@
+records_in_rust::layout::bits::update_record_no_refs:
+# same code as records_in_rust::layout::bits::update_record_mut
+	local.get	0
+	i64.const	1
+	i64.add
+	local.tee	1
+	local.get	0
+	i64.const	32
+	i64.shr_u
+	i64.add
+	i64.const	2147483647
+	i64.and
+	local.get	0
+	i64.const	2147483648
+	i64.and
+	i64.or
+	local.get	1
+	i64.const	32
+	i64.shl
+	i64.const	9223372032559808512
+	i64.and
+	i64.or
+	i64.const	2147483648
+	i64.xor
@@ -0,0 +1,31 @@
+records_in_rust::layout::c::update_record_mut:
+# same code as records_in_rust::update_record_mut
+	local.get	1
+	local.get	1
+	i32.load8_u	8
+	i32.const	1
+	i32.xor
+	i32.store8	8
+	local.get	1
+	i32.load	4
+	local.set	2
+	local.get	1
+	local.get	1
+	i32.load	0
+	i32.const	1
+	i32.add
+	local.tee	3
+	i32.store	4
+	local.get	1
+	local.get	2
+	local.get	3
+	i32.add
+	i32.store	0
+	local.get	0
+	local.get	1
+	i32.load	8
+	i32.store	8
+	local.get	0
+	local.get	1
+	i64.load	0:p2align=2
+	i64.store	0:p2align=2
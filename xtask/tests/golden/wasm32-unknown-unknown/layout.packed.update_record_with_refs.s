@@ -0,0 +1,22 @@
+records_in_rust::layout::packed::update_record_with_refs:
+	local.get	0
+	local.get	0
+	i32.load8_u	8
+	i32.const	1
+	i32.xor
+	i32.store8	8
+	local.get	0
+	i32.load	4:p2align=0
+	local.set	1
+	local.get	0
+	local.get	0
+	i32.load	0:p2align=0
+	i32.const	1
+	i32.add
+	local.tee	2
+	i32.store	4:p2align=0
+	local.get	0
+	local.get	1
+	local.get	2
+	i32.add
+	i32.store	0:p2align=0
@@ -0,0 +1,336 @@
+records_in_rust::soa::RecordBatch::update_all:
+	block
+	block
+	block
+	block
+	block
+	local.get	0
+	i32.load	32
+	local.tee	1
+	i32.eqz
+	br_if   	0
+	local.get	0
+	i32.load	28
+	local.tee	2
+	local.set	3
+	block
+	local.get	1
+	i32.const	3
+	i32.shl
+	local.tee	4
+	i32.const	-8
+	i32.add
+	local.tee	5
+	i32.const	56
+	i32.and
+	i32.const	56
+	i32.eq
+	br_if   	0
+	i32.const	0
+	local.get	5
+	i32.const	3
+	i32.shr_u
+	i32.const	1
+	i32.add
+	i32.const	7
+	i32.and
+	i32.sub
+	local.set	1
+	local.get	2
+	local.set	3
+.L0:
+	loop
+	local.get	3
+	local.get	3
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	8
+	i32.add
+	local.set	3
+	local.get	1
+	i32.const	1
+	i32.add
+	local.tee	1
+	br_if   	0
+.L1:
+	end_loop
+	end_block
+	local.get	2
+	local.get	4
+	i32.add
+	local.set	2
+	block
+	local.get	5
+	i32.const	56
+	i32.lt_u
+	br_if   	0
+.L2:
+	loop
+	local.get	3
+	local.get	3
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	8
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	16
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	24
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	32
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	40
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	48
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	56
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	3
+	i32.const	64
+	i32.add
+	local.tee	3
+	local.get	2
+	i32.ne
+	br_if   	0
+.L3:
+	end_loop
+	end_block
+	local.get	0
+	i32.load	8
+	local.tee	5
+	i32.const	63
+	i32.and
+	local.tee	3
+	i32.eqz
+	br_if   	1
+	local.get	2
+	i32.const	-8
+	i32.add
+	local.tee	1
+	local.get	1
+	i64.load	0
+	i64.const	-1
+	local.get	3
+	i64.extend_i32_u
+	i64.shl
+	i64.const	-1
+	i64.xor
+	i64.and
+	i64.store	0
+	local.get	0
+	i32.load	4
+	local.tee	6
+	local.get	5
+	i32.const	2
+	i32.shl
+	i32.add
+	local.set	1
+	br      	2
+.L4:
+	end_block
+	local.get	0
+	i32.load	8
+	local.set	5
+.L5:
+	end_block
+	local.get	0
+	i32.load	4
+	local.set	6
+	local.get	5
+	i32.eqz
+	br_if   	1
+	local.get	6
+	local.get	5
+	i32.const	2
+	i32.shl
+	i32.add
+	local.set	1
+.L6:
+	end_block
+	local.get	6
+	local.set	3
+.L7:
+	loop
+	local.get	3
+	local.get	3
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	3
+	i32.const	4
+	i32.add
+	local.tee	3
+	local.get	1
+	i32.ne
+	br_if   	0
+	br      	2
+.L8:
+	end_loop
+	end_block
+	i32.const	0
+	local.set	5
+.L9:
+	end_block
+	block
+	local.get	0
+	i32.load	20
+	local.tee	3
+	local.get	5
+	local.get	3
+	local.get	5
+	i32.lt_u
+	i32.select
+	local.tee	3
+	i32.eqz
+	br_if   	0
+	local.get	0
+	i32.load	16
+	local.set	7
+	i32.const	0
+	local.set	2
+	block
+	local.get	3
+	i32.const	1
+	i32.eq
+	br_if   	0
+	local.get	3
+	i32.const	1
+	i32.and
+	local.set	8
+	local.get	3
+	i32.const	-2
+	i32.and
+	local.set	4
+	i32.const	0
+	local.set	2
+	local.get	6
+	local.set	3
+	local.get	7
+	local.set	1
+.L10:
+	loop
+	local.get	3
+	local.get	1
+	i32.load	0
+	local.get	3
+	i32.load	0
+	local.tee	0
+	i32.add
+	i32.store	0
+	local.get	1
+	local.get	0
+	i32.store	0
+	local.get	3
+	i32.const	4
+	i32.add
+	local.tee	0
+	local.get	1
+	i32.const	4
+	i32.add
+	local.tee	5
+	i32.load	0
+	local.get	0
+	i32.load	0
+	local.tee	0
+	i32.add
+	i32.store	0
+	local.get	5
+	local.get	0
+	i32.store	0
+	local.get	3
+	i32.const	8
+	i32.add
+	local.set	3
+	local.get	1
+	i32.const	8
+	i32.add
+	local.set	1
+	local.get	4
+	local.get	2
+	i32.const	2
+	i32.add
+	local.tee	2
+	i32.ne
+	br_if   	0
+	end_loop
+	local.get	8
+	i32.eqz
+	br_if   	1
+.L11:
+	end_block
+	local.get	6
+	local.get	2
+	i32.const	2
+	i32.shl
+	local.tee	3
+	i32.add
+	local.tee	1
+	local.get	7
+	local.get	3
+	i32.add
+	local.tee	3
+	i32.load	0
+	local.get	1
+	i32.load	0
+	local.tee	1
+	i32.add
+	i32.store	0
+	local.get	3
+	local.get	1
+	i32.store	0
+.L12:
+	end_block
@@ -0,0 +1,425 @@
+records_in_rust::soa::RecordBatch::updated:
+	local.get	1
+	i32.load	28
+	local.set	2
+	block
+	local.get	1
+	i32.load	32
+	local.tee	3
+	i32.eqz
+	br_if   	0
+	local.get	3
+	i32.const	3
+	i32.and
+	local.set	4
+	i32.const	0
+	local.set	5
+	block
+	local.get	3
+	i32.const	4
+	i32.lt_u
+	br_if   	0
+	local.get	3
+	i32.const	268435452
+	i32.and
+	local.set	6
+	i32.const	0
+	local.set	5
+	local.get	2
+	local.set	7
+.L0:
+	loop
+	local.get	7
+	local.get	7
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	7
+	i32.const	8
+	i32.add
+	local.tee	8
+	local.get	8
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	7
+	i32.const	16
+	i32.add
+	local.tee	8
+	local.get	8
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	7
+	i32.const	24
+	i32.add
+	local.tee	8
+	local.get	8
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	7
+	i32.const	32
+	i32.add
+	local.set	7
+	local.get	6
+	local.get	5
+	i32.const	4
+	i32.add
+	local.tee	5
+	i32.ne
+	br_if   	0
+	end_loop
+	local.get	4
+	i32.eqz
+	br_if   	1
+.L1:
+	end_block
+	local.get	2
+	local.get	5
+	i32.const	3
+	i32.shl
+	i32.add
+	local.set	7
+.L2:
+	loop
+	local.get	7
+	local.get	7
+	i64.load	0
+	i64.const	-1
+	i64.xor
+	i64.store	0
+	local.get	7
+	i32.const	8
+	i32.add
+	local.set	7
+	local.get	4
+	i32.const	-1
+	i32.add
+	local.tee	4
+	br_if   	0
+.L3:
+	end_loop
+	end_block
+	local.get	1
+	i32.load	8
+	local.set	9
+	local.get	1
+	i32.load	20
+	local.set	10
+	local.get	1
+	i32.load	4
+	local.set	11
+	block
+	block
+	block
+	local.get	3
+	i32.eqz
+	br_if   	0
+	local.get	9
+	i32.const	63
+	i32.and
+	local.tee	7
+	i32.eqz
+	br_if   	0
+	local.get	2
+	local.get	3
+	i32.const	3
+	i32.shl
+	i32.add
+	i32.const	-8
+	i32.add
+	local.tee	4
+	local.get	4
+	i64.load	0
+	i64.const	-1
+	local.get	7
+	i64.extend_i32_u
+	i64.shl
+	i64.const	-1
+	i64.xor
+	i64.and
+	i64.store	0
+	br      	1
+.L4:
+	end_block
+	local.get	9
+	i32.eqz
+	br_if   	1
+.L5:
+	end_block
+	local.get	9
+	i32.const	3
+	i32.and
+	local.set	4
+	i32.const	0
+	local.set	5
+	block
+	local.get	9
+	i32.const	-1
+	i32.add
+	i32.const	3
+	i32.lt_u
+	br_if   	0
+	local.get	9
+	i32.const	536870908
+	i32.and
+	local.set	6
+	i32.const	0
+	local.set	5
+	local.get	11
+	local.set	7
+.L6:
+	loop
+	local.get	7
+	local.get	7
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	4
+	i32.add
+	local.tee	8
+	local.get	8
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	8
+	i32.add
+	local.tee	8
+	local.get	8
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	12
+	i32.add
+	local.tee	8
+	local.get	8
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	16
+	i32.add
+	local.set	7
+	local.get	6
+	local.get	5
+	i32.const	4
+	i32.add
+	local.tee	5
+	i32.ne
+	br_if   	0
+	end_loop
+	local.get	4
+	i32.eqz
+	br_if   	1
+.L7:
+	end_block
+	local.get	11
+	local.get	5
+	i32.const	2
+	i32.shl
+	i32.add
+	local.set	7
+.L8:
+	loop
+	local.get	7
+	local.get	7
+	i32.load	0
+	i32.const	1
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	4
+	i32.add
+	local.set	7
+	local.get	4
+	i32.const	-1
+	i32.add
+	local.tee	4
+	br_if   	0
+.L9:
+	end_loop
+	end_block
+	local.get	1
+	i32.load	24
+	local.set	12
+	local.get	1
+	i32.load	16
+	local.set	13
+	local.get	1
+	i32.load	12
+	local.set	14
+	local.get	1
+	i32.load	0
+	local.set	15
+	block
+	local.get	9
+	local.get	10
+	local.get	9
+	local.get	10
+	i32.lt_u
+	i32.select
+	local.tee	10
+	i32.eqz
+	br_if   	0
+	local.get	10
+	i32.const	3
+	i32.and
+	local.set	8
+	i32.const	0
+	local.set	16
+	block
+	local.get	10
+	i32.const	4
+	i32.lt_u
+	br_if   	0
+	local.get	10
+	i32.const	-4
+	i32.and
+	local.set	16
+	local.get	10
+	i32.const	2
+	i32.shl
+	i32.const	-16
+	i32.and
+	local.set	1
+	i32.const	0
+	local.set	4
+.L10:
+	loop
+	local.get	13
+	local.get	4
+	i32.add
+	local.tee	7
+	local.get	11
+	local.get	4
+	i32.add
+	local.tee	5
+	i32.load	0
+	local.get	7
+	i32.load	0
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	4
+	i32.add
+	local.tee	6
+	local.get	5
+	i32.const	4
+	i32.add
+	i32.load	0
+	local.get	6
+	i32.load	0
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	8
+	i32.add
+	local.tee	6
+	local.get	5
+	i32.const	8
+	i32.add
+	i32.load	0
+	local.get	6
+	i32.load	0
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	12
+	i32.add
+	local.tee	7
+	local.get	5
+	i32.const	12
+	i32.add
+	i32.load	0
+	local.get	7
+	i32.load	0
+	i32.add
+	i32.store	0
+	local.get	1
+	local.get	4
+	i32.const	16
+	i32.add
+	local.tee	4
+	i32.ne
+	br_if   	0
+	end_loop
+	local.get	8
+	i32.eqz
+	br_if   	1
+.L11:
+	end_block
+	local.get	13
+	local.get	16
+	i32.const	2
+	i32.shl
+	local.tee	4
+	i32.add
+	local.set	7
+	local.get	11
+	local.get	4
+	i32.add
+	local.set	4
+.L12:
+	loop
+	local.get	7
+	local.get	4
+	i32.load	0
+	local.get	7
+	i32.load	0
+	i32.add
+	i32.store	0
+	local.get	7
+	i32.const	4
+	i32.add
+	local.set	7
+	local.get	4
+	i32.const	4
+	i32.add
+	local.set	4
+	local.get	8
+	i32.const	-1
+	i32.add
+	local.tee	8
+	br_if   	0
+.L13:
+	end_loop
+	end_block
+	local.get	0
+	local.get	3
+	i32.store	32
+	local.get	0
+	local.get	2
+	i32.store	28
+	local.get	0
+	local.get	12
+	i32.store	24
+	local.get	0
+	local.get	9
+	i32.store	20
+	local.get	0
+	local.get	11
+	i32.store	16
+	local.get	0
+	local.get	15
+	i32.store	12
+	local.get	0
+	local.get	10
+	i32.store	8
+	local.get	0
+	local.get	13
+	i32.store	4
+	local.get	0
+	local.get	14
+	i32.store	0
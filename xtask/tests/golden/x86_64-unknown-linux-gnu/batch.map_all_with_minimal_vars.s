@@ -0,0 +1,55 @@
+records_in_rust::batch::map_all_with_minimal_vars:
+# same code as records_in_rust::batch::map_all_no_refs
+	movq	%rdi, %rax
+	movq	(%rsi), %rdi
+	movq	8(%rsi), %rcx
+	movq	16(%rsi), %rdx
+	testq	%rdx, %rdx
+	je	.L0
+	pushq	%rbx
+	cmpq	$1, %rdx
+	jne	.L1
+	xorl	%esi, %esi
+	jmp	.L2
+.L1:
+	movabsq	$1152921504606846974, %r8
+	andq	%rdx, %r8
+	leaq	20(%rcx), %r9
+	xorl	%esi, %esi
+.L3:
+	movl	-20(%r9), %r10d
+	movl	-8(%r9), %r11d
+	incl	%r10d
+	movl	-16(%r9), %ebx
+	addl	%r10d, %ebx
+	movl	%ebx, -20(%r9)
+	movl	%r10d, -16(%r9)
+	xorb	$1, -12(%r9)
+	incl	%r11d
+	movl	-4(%r9), %r10d
+	addl	%r11d, %r10d
+	movl	%r10d, -8(%r9)
+	movl	%r11d, -4(%r9)
+	xorb	$1, (%r9)
+	addq	$2, %rsi
+	addq	$24, %r9
+	cmpq	%rsi, %r8
+	jne	.L3
+	testb	$1, %dl
+	je	.L4
+.L2:
+	leaq	(%rsi,%rsi,2), %rsi
+	movl	(%rcx,%rsi,4), %r8d
+	incl	%r8d
+	movl	4(%rcx,%rsi,4), %r9d
+	addl	%r8d, %r9d
+	movl	%r9d, (%rcx,%rsi,4)
+	movl	%r8d, 4(%rcx,%rsi,4)
+	xorb	$1, 8(%rcx,%rsi,4)
+.L4:
+	popq	%rbx
+.L0:
+	movq	%rdi, (%rax)
+	movq	%rcx, 8(%rax)
+	movq	%rdx, 16(%rax)
+	retq
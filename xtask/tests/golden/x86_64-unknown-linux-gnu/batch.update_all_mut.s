@@ -0,0 +1,53 @@
+records_in_rust::batch::update_all_mut:
+	testq	%rsi, %rsi
+	je	.L0
+	shlq	$2, %rsi
+	leaq	(%rsi,%rsi,2), %rsi
+	leaq	-12(%rsi), %rcx
+	movabsq	$-6148914691236517205, %rdx
+	movq	%rcx, %rax
+	mulq	%rdx
+	btl	$3, %edx
+	movq	%rdi, %rax
+	jb	.L1
+	leaq	12(%rdi), %rax
+	movl	(%rdi), %edx
+	movzbl	8(%rdi), %r8d
+	notb	%r8b
+	andb	$1, %r8b
+	incl	%edx
+	movl	4(%rdi), %r9d
+	addl	%edx, %r9d
+	movl	%r9d, (%rdi)
+	movl	%edx, 4(%rdi)
+	movb	%r8b, 8(%rdi)
+.L1:
+	cmpq	$12, %rcx
+	jb	.L0
+	addq	%rsi, %rdi
+.L2:
+	movl	(%rax), %ecx
+	movzbl	8(%rax), %edx
+	notb	%dl
+	andb	$1, %dl
+	incl	%ecx
+	movl	4(%rax), %esi
+	addl	%ecx, %esi
+	movl	%esi, (%rax)
+	movl	%ecx, 4(%rax)
+	movb	%dl, 8(%rax)
+	movl	12(%rax), %ecx
+	movzbl	20(%rax), %edx
+	notb	%dl
+	andb	$1, %dl
+	incl	%ecx
+	movl	16(%rax), %esi
+	addl	%ecx, %esi
+	movl	%esi, 12(%rax)
+	movl	%ecx, 16(%rax)
+	movb	%dl, 20(%rax)
+	addq	$24, %rax
+	cmpq	%rdi, %rax
+	jne	.L2
+.L0:
+	retq
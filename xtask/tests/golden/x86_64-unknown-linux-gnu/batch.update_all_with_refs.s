@@ -0,0 +1,44 @@
+records_in_rust::batch::update_all_with_refs:
+	testq	%rsi, %rsi
+	je	.L0
+	shlq	$2, %rsi
+	leaq	(%rsi,%rsi,2), %rsi
+	leaq	-12(%rsi), %rcx
+	movabsq	$-6148914691236517205, %rdx
+	movq	%rcx, %rax
+	mulq	%rdx
+	btl	$3, %edx
+	movq	%rdi, %rax
+	jb	.L1
+	xorb	$1, 8(%rdi)
+	leaq	12(%rdi), %rax
+	movl	(%rdi), %edx
+	incl	%edx
+	movl	4(%rdi), %r8d
+	addl	%edx, %r8d
+	movl	%r8d, (%rdi)
+	movl	%edx, 4(%rdi)
+.L1:
+	cmpq	$12, %rcx
+	jb	.L0
+	addq	%rsi, %rdi
+.L2:
+	xorb	$1, 8(%rax)
+	movl	(%rax), %ecx
+	movl	12(%rax), %edx
+	incl	%ecx
+	movl	4(%rax), %esi
+	addl	%ecx, %esi
+	movl	%esi, (%rax)
+	movl	%ecx, 4(%rax)
+	xorb	$1, 20(%rax)
+	incl	%edx
+	movl	16(%rax), %ecx
+	addl	%edx, %ecx
+	movl	%ecx, 12(%rax)
+	movl	%edx, 16(%rax)
+	addq	$24, %rax
+	cmpq	%rdi, %rax
+	jne	.L2
+.L0:
+	retq
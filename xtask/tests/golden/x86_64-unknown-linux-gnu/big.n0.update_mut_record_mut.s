@@ -0,0 +1,16 @@
+records_in_rust::big::n0::update_mut_record_mut:
+	movq	%rdi, %rax
+	xorb	$1, 8(%rsi)
+	movl	(%rsi), %ecx
+	incl	%ecx
+	movl	4(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, (%rsi)
+	movl	%ecx, 4(%rsi)
+	movl	(%rsi), %ecx
+	movl	%ecx, (%rdi)
+	movl	4(%rsi), %ecx
+	movl	%ecx, 4(%rdi)
+	movq	8(%rsi), %rcx
+	movq	%rcx, 8(%rdi)
+	retq
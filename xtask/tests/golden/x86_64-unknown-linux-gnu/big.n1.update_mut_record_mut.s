@@ -0,0 +1,18 @@
+records_in_rust::big::n1::update_mut_record_mut:
+	xorb	$1, 16(%rsi)
+	movq	%rdi, %rax
+	movl	8(%rsi), %ecx
+	incl	%ecx
+	movl	12(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, 8(%rsi)
+	movl	%ecx, 12(%rsi)
+	movq	16(%rsi), %rcx
+	movq	%rcx, 16(%rdi)
+	movq	(%rsi), %rcx
+	movq	%rcx, (%rdi)
+	movl	8(%rsi), %ecx
+	movl	%ecx, 8(%rdi)
+	movl	12(%rsi), %ecx
+	movl	%ecx, 12(%rdi)
+	retq
@@ -0,0 +1,14 @@
+records_in_rust::big::n1::update_record_no_refs:
+	movq	%rdi, %rax
+	movl	8(%rsi), %ecx
+	movzbl	16(%rsi), %edx
+	movq	(%rsi), %rdi
+	xorb	$1, %dl
+	incl	%ecx
+	movl	12(%rsi), %esi
+	addl	%ecx, %esi
+	movl	%esi, 8(%rax)
+	movl	%ecx, 12(%rax)
+	movb	%dl, 16(%rax)
+	movq	%rdi, (%rax)
+	retq
@@ -0,0 +1,15 @@
+records_in_rust::big::n128::update_mut_record_mut:
+	pushq	%rbx
+	xorb	$1, 1032(%rsi)
+	movq	%rdi, %rbx
+	movl	1024(%rsi), %eax
+	incl	%eax
+	movl	1028(%rsi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, 1024(%rsi)
+	movl	%eax, 1028(%rsi)
+	movl	$1040, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	popq	%rbx
+	retq
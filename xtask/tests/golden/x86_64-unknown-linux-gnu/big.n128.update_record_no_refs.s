@@ -0,0 +1,25 @@
+records_in_rust::big::n128::update_record_no_refs:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	pushq	%rax
+	movq	%rdi, %rbx
+	movl	1024(%rsi), %ebp
+	movzbl	1032(%rsi), %r14d
+	xorb	$1, %r14b
+	incl	%ebp
+	movl	1028(%rsi), %r15d
+	addl	%ebp, %r15d
+	movl	$1024, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movl	%r15d, 1024(%rbx)
+	movl	%ebp, 1028(%rbx)
+	movb	%r14b, 1032(%rbx)
+	movq	%rbx, %rax
+	addq	$8, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
@@ -0,0 +1,15 @@
+records_in_rust::big::n16::update_mut_record_mut:
+	pushq	%rbx
+	xorb	$1, 136(%rsi)
+	movq	%rdi, %rbx
+	movl	128(%rsi), %eax
+	incl	%eax
+	movl	132(%rsi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, 128(%rsi)
+	movl	%eax, 132(%rsi)
+	movl	$144, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	popq	%rbx
+	retq
@@ -0,0 +1,28 @@
+records_in_rust::big::n16::update_record_no_refs:
+	movq	%rdi, %rax
+	movl	128(%rsi), %ecx
+	movzbl	136(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movups	112(%rsi), %xmm0
+	movl	132(%rsi), %edi
+	addl	%ecx, %edi
+	movups	%xmm0, 112(%rax)
+	movups	96(%rsi), %xmm0
+	movups	%xmm0, 96(%rax)
+	movups	80(%rsi), %xmm0
+	movups	%xmm0, 80(%rax)
+	movups	64(%rsi), %xmm0
+	movups	%xmm0, 64(%rax)
+	movups	(%rsi), %xmm0
+	movups	16(%rsi), %xmm1
+	movups	32(%rsi), %xmm2
+	movups	48(%rsi), %xmm3
+	movups	%xmm3, 48(%rax)
+	movups	%xmm2, 32(%rax)
+	movups	%xmm1, 16(%rax)
+	movups	%xmm0, (%rax)
+	movl	%edi, 128(%rax)
+	movl	%ecx, 132(%rax)
+	movb	%dl, 136(%rax)
+	retq
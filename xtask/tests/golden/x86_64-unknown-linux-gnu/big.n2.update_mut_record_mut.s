@@ -0,0 +1,18 @@
+records_in_rust::big::n2::update_mut_record_mut:
+	xorb	$1, 24(%rsi)
+	movq	%rdi, %rax
+	movl	16(%rsi), %ecx
+	incl	%ecx
+	movl	20(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, 16(%rsi)
+	movl	%ecx, 20(%rsi)
+	movups	(%rsi), %xmm0
+	movups	%xmm0, (%rdi)
+	movl	16(%rsi), %ecx
+	movl	%ecx, 16(%rdi)
+	movl	20(%rsi), %ecx
+	movl	%ecx, 20(%rdi)
+	movq	24(%rsi), %rcx
+	movq	%rcx, 24(%rdi)
+	retq
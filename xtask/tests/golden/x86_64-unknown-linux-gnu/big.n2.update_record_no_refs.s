@@ -0,0 +1,14 @@
+records_in_rust::big::n2::update_record_no_refs:
+	movq	%rdi, %rax
+	movl	16(%rsi), %ecx
+	movzbl	24(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movups	(%rsi), %xmm0
+	movl	20(%rsi), %esi
+	addl	%ecx, %esi
+	movups	%xmm0, (%rdi)
+	movl	%esi, 16(%rdi)
+	movl	%ecx, 20(%rdi)
+	movb	%dl, 24(%rdi)
+	retq
@@ -0,0 +1,15 @@
+records_in_rust::big::n32::update_mut_record_mut:
+	pushq	%rbx
+	xorb	$1, 264(%rsi)
+	movq	%rdi, %rbx
+	movl	256(%rsi), %eax
+	incl	%eax
+	movl	260(%rsi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, 256(%rsi)
+	movl	%eax, 260(%rsi)
+	movl	$272, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	popq	%rbx
+	retq
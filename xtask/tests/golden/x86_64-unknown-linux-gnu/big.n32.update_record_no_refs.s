@@ -0,0 +1,25 @@
+records_in_rust::big::n32::update_record_no_refs:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	pushq	%rax
+	movq	%rdi, %rbx
+	movl	256(%rsi), %ebp
+	movzbl	264(%rsi), %r14d
+	xorb	$1, %r14b
+	incl	%ebp
+	movl	260(%rsi), %r15d
+	addl	%ebp, %r15d
+	movl	$256, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movl	%r15d, 256(%rbx)
+	movl	%ebp, 260(%rbx)
+	movb	%r14b, 264(%rbx)
+	movq	%rbx, %rax
+	addq	$8, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
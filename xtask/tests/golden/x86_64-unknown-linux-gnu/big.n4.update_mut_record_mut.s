@@ -0,0 +1,20 @@
+records_in_rust::big::n4::update_mut_record_mut:
+	movq	%rdi, %rax
+	xorb	$1, 40(%rsi)
+	movl	32(%rsi), %ecx
+	incl	%ecx
+	movl	36(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, 32(%rsi)
+	movl	%ecx, 36(%rsi)
+	movups	(%rsi), %xmm0
+	movups	16(%rsi), %xmm1
+	movups	%xmm1, 16(%rdi)
+	movups	%xmm0, (%rdi)
+	movl	32(%rsi), %ecx
+	movl	%ecx, 32(%rdi)
+	movl	36(%rsi), %ecx
+	movl	%ecx, 36(%rdi)
+	movq	40(%rsi), %rcx
+	movq	%rcx, 40(%rdi)
+	retq
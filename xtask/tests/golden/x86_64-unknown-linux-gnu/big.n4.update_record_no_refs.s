@@ -0,0 +1,16 @@
+records_in_rust::big::n4::update_record_no_refs:
+	movq	%rdi, %rax
+	movl	32(%rsi), %ecx
+	movzbl	40(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movups	(%rsi), %xmm0
+	movups	16(%rsi), %xmm1
+	movl	36(%rsi), %esi
+	addl	%ecx, %esi
+	movups	%xmm1, 16(%rdi)
+	movups	%xmm0, (%rdi)
+	movl	%esi, 32(%rdi)
+	movl	%ecx, 36(%rdi)
+	movb	%dl, 40(%rdi)
+	retq
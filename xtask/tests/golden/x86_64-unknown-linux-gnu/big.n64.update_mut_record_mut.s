@@ -0,0 +1,15 @@
+records_in_rust::big::n64::update_mut_record_mut:
+	pushq	%rbx
+	xorb	$1, 520(%rsi)
+	movq	%rdi, %rbx
+	movl	512(%rsi), %eax
+	incl	%eax
+	movl	516(%rsi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, 512(%rsi)
+	movl	%eax, 516(%rsi)
+	movl	$528, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	popq	%rbx
+	retq
@@ -0,0 +1,25 @@
+records_in_rust::big::n64::update_record_no_refs:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	pushq	%rax
+	movq	%rdi, %rbx
+	movl	512(%rsi), %ebp
+	movzbl	520(%rsi), %r14d
+	xorb	$1, %r14b
+	incl	%ebp
+	movl	516(%rsi), %r15d
+	addl	%ebp, %r15d
+	movl	$512, %edx
+	callq	*memcpy@GOTPCREL(%rip)
+	movl	%r15d, 512(%rbx)
+	movl	%ebp, 516(%rbx)
+	movb	%r14b, 520(%rbx)
+	movq	%rbx, %rax
+	addq	$8, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
@@ -0,0 +1,24 @@
+records_in_rust::big::n8::update_mut_record_mut:
+	movq	%rdi, %rax
+	xorb	$1, 72(%rsi)
+	movl	64(%rsi), %ecx
+	incl	%ecx
+	movl	68(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, 64(%rsi)
+	movl	%ecx, 68(%rsi)
+	movups	(%rsi), %xmm0
+	movups	16(%rsi), %xmm1
+	movups	32(%rsi), %xmm2
+	movups	48(%rsi), %xmm3
+	movups	%xmm3, 48(%rdi)
+	movups	%xmm2, 32(%rdi)
+	movups	%xmm1, 16(%rdi)
+	movups	%xmm0, (%rdi)
+	movl	64(%rsi), %ecx
+	movl	%ecx, 64(%rdi)
+	movl	68(%rsi), %ecx
+	movl	%ecx, 68(%rdi)
+	movq	72(%rsi), %rcx
+	movq	%rcx, 72(%rdi)
+	retq
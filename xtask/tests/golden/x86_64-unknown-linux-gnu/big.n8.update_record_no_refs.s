@@ -0,0 +1,20 @@
+records_in_rust::big::n8::update_record_no_refs:
+	movq	%rdi, %rax
+	movl	64(%rsi), %ecx
+	movzbl	72(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movups	(%rsi), %xmm0
+	movups	16(%rsi), %xmm1
+	movups	32(%rsi), %xmm2
+	movups	48(%rsi), %xmm3
+	movl	68(%rsi), %esi
+	addl	%ecx, %esi
+	movups	%xmm3, 48(%rdi)
+	movups	%xmm2, 32(%rdi)
+	movups	%xmm1, 16(%rdi)
+	movups	%xmm0, (%rdi)
+	movl	%esi, 64(%rdi)
+	movl	%ecx, 68(%rdi)
+	movb	%dl, 72(%rdi)
+	retq
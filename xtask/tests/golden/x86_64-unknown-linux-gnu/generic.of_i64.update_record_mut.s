@@ -0,0 +1,17 @@
+records_in_rust::generic::of_i64::update_record_mut:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_mut
+	movq	%rdi, %rax
+	xorb	$1, 16(%rsi)
+	movq	(%rsi), %rcx
+	incq	%rcx
+	movq	8(%rsi), %rdx
+	addq	%rcx, %rdx
+	movq	%rdx, (%rsi)
+	movq	%rcx, 8(%rsi)
+	movq	16(%rsi), %rcx
+	movq	%rcx, 16(%rdi)
+	movq	(%rsi), %rcx
+	movq	%rcx, (%rdi)
+	movq	8(%rsi), %rcx
+	movq	%rcx, 8(%rdi)
+	retq
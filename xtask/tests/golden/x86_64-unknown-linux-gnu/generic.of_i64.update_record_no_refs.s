@@ -0,0 +1,13 @@
+records_in_rust::generic::of_i64::update_record_no_refs:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_no_refs
+	movq	%rdi, %rax
+	movq	(%rsi), %rcx
+	movzbl	16(%rsi), %edx
+	xorb	$1, %dl
+	incq	%rcx
+	movq	8(%rsi), %rsi
+	addq	%rcx, %rsi
+	movq	%rsi, (%rdi)
+	movq	%rcx, 8(%rdi)
+	movb	%dl, 16(%rdi)
+	retq
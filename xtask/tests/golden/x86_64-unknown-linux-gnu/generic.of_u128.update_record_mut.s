@@ -0,0 +1,26 @@
+records_in_rust::generic::of_u128::update_record_mut:
+	xorb	$1, 32(%rsi)
+	movq	(%rsi), %rcx
+	movq	8(%rsi), %rdx
+	addq	$1, %rcx
+	adcq	$0, %rdx
+	movq	16(%rsi), %r8
+	addq	%rcx, %r8
+	movq	24(%rsi), %r9
+	adcq	%rdx, %r9
+	movq	%rdi, %rax
+	movq	%r8, (%rsi)
+	movq	%r9, 8(%rsi)
+	movq	%rcx, 16(%rsi)
+	movq	%rdx, 24(%rsi)
+	movaps	32(%rsi), %xmm0
+	movaps	%xmm0, 32(%rdi)
+	movq	16(%rsi), %rcx
+	movq	%rcx, 16(%rdi)
+	movq	24(%rsi), %rcx
+	movq	%rcx, 24(%rdi)
+	movq	(%rsi), %rcx
+	movq	%rcx, (%rdi)
+	movq	8(%rsi), %rcx
+	movq	%rcx, 8(%rdi)
+	retq
@@ -0,0 +1,18 @@
+records_in_rust::generic::of_u128::update_record_no_refs:
+	movq	(%rsi), %rcx
+	movq	8(%rsi), %rdx
+	movzbl	32(%rsi), %r8d
+	xorb	$1, %r8b
+	addq	$1, %rcx
+	adcq	$0, %rdx
+	movq	%rdi, %rax
+	movq	16(%rsi), %rdi
+	addq	%rcx, %rdi
+	movq	24(%rsi), %rsi
+	adcq	%rdx, %rsi
+	movq	%rdi, (%rax)
+	movq	%rsi, 8(%rax)
+	movq	%rcx, 16(%rax)
+	movq	%rdx, 24(%rax)
+	movb	%r8b, 32(%rax)
+	retq
@@ -0,0 +1,15 @@
+records_in_rust::generic::of_u128::update_record_with_refs:
+	xorb	$1, 32(%rdi)
+	movq	(%rdi), %rax
+	movq	8(%rdi), %rcx
+	addq	$1, %rax
+	adcq	$0, %rcx
+	movq	16(%rdi), %rdx
+	addq	%rax, %rdx
+	movq	24(%rdi), %rsi
+	adcq	%rcx, %rsi
+	movq	%rdx, (%rdi)
+	movq	%rsi, 8(%rdi)
+	movq	%rax, 16(%rdi)
+	movq	%rcx, 24(%rdi)
+	retq
@@ -0,0 +1,13 @@
+records_in_rust::generic::of_u32::update_record_no_refs:
+# same code as records_in_rust::update_record_no_refs
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	movzbl	8(%rsi), %edx
+	xorb	$1, %dl
+	incl	%ecx
+	movl	4(%rsi), %esi
+	addl	%ecx, %esi
+	movl	%esi, (%rdi)
+	movl	%ecx, 4(%rdi)
+	movb	%dl, 8(%rdi)
+	retq
@@ -0,0 +1,10 @@
+records_in_rust::generic::of_u32::update_record_with_refs:
+# same code as records_in_rust::update_record_with_refs
+	xorb	$1, 8(%rdi)
+	movl	(%rdi), %eax
+	incl	%eax
+	movl	4(%rdi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, (%rdi)
+	movl	%eax, 4(%rdi)
+	retq
@@ -0,0 +1,10 @@
+records_in_rust::generic::of_u64::update_record_with_refs:
+# same code as records_in_rust::generic::of_wrapping_u64::update_record_with_refs
+	xorb	$1, 16(%rdi)
+	movq	(%rdi), %rax
+	incq	%rax
+	movq	8(%rdi), %rcx
+	addq	%rax, %rcx
+	movq	%rcx, (%rdi)
+	movq	%rax, 8(%rdi)
+	retq
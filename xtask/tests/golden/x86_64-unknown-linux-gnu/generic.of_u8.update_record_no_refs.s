@@ -0,0 +1,17 @@
+records_in_rust::generic::of_u8::update_record_no_refs:
+# same code as records_in_rust::generic::of_u8::update_record_mut
+	movl	%edi, %eax
+	shrl	$8, %eax
+	movl	%edi, %ecx
+	shrl	$16, %ecx
+	incb	%al
+	addb	%al, %cl
+	movzbl	%al, %edx
+	shll	$16, %edx
+	movzbl	%cl, %eax
+	shll	$8, %eax
+	orl	%edx, %eax
+	andl	$1, %edi
+	orl	%edi, %eax
+	xorl	$1, %eax
+	retq
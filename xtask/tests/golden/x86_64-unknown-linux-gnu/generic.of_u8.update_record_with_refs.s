@@ -0,0 +1,9 @@
+records_in_rust::generic::of_u8::update_record_with_refs:
+	xorb	$1, (%rdi)
+	movzbl	1(%rdi), %eax
+	incb	%al
+	movzbl	2(%rdi), %ecx
+	addb	%al, %cl
+	movb	%cl, 1(%rdi)
+	movb	%al, 2(%rdi)
+	retq
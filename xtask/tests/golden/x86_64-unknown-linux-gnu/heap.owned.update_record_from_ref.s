@@ -0,0 +1,54 @@
+records_in_rust::heap::owned::update_record_from_ref:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	subq	$72, %rsp
+	movq	%rdi, %rbx
+	movsd	24(%rsi), %xmm0
+	movzbl	32(%rsi), %ebp
+	movq	16(%rsi), %r13
+	testq	%r13, %r13
+	je	.L0
+	movaps	%xmm0, 48(%rsp)
+	movq	8(%rsi), %r12
+	leaq	(,%r13,4), %r14
+	callq	*__rustc::__rust_no_alloc_shim_is_unstable_v2@GOTPCREL(%rip)
+	movl	$4, %esi
+	movq	%r14, %rdi
+	callq	*__rustc::__rust_alloc@GOTPCREL(%rip)
+	testq	%rax, %rax
+	je	.L1
+	movq	%rax, %r15
+	movq	%rax, %rdi
+	movq	%r12, %rsi
+	movq	%r14, %rdx
+	callq	*memcpy@GOTPCREL(%rip)
+	movaps	48(%rsp), %xmm0
+	jmp	.L2
+.L0:
+	movl	$4, %r15d
+.L2:
+	movlps	%xmm0, 32(%rsp)
+	movb	%bpl, 40(%rsp)
+	movq	%r13, 8(%rsp)
+	movq	%r15, 16(%rsp)
+	movq	%r13, 24(%rsp)
+	leaq	8(%rsp), %rsi
+	movq	%rbx, %rdi
+	callq	*records_in_rust::heap::owned::update_record_no_refs@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	addq	$72, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.L1:
+	movl	$4, %edi
+	movq	%r14, %rsi
+	callq	*alloc::raw_vec::handle_error@GOTPCREL(%rip)
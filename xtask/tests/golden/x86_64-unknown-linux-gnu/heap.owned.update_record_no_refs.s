@@ -0,0 +1,64 @@
+records_in_rust::heap::owned::update_record_no_refs:
+.L0:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r12
+	pushq	%rbx
+	subq	$32, %rsp
+	movq	%rdi, %rbx
+	movl	24(%rsi), %ebp
+	movl	28(%rsi), %r15d
+	movzbl	32(%rsi), %r14d
+	xorb	$1, %r14b
+	movq	16(%rsi), %rax
+	movq	%rax, 16(%rdi)
+	movups	(%rsi), %xmm0
+	movups	%xmm0, (%rdi)
+	incl	%ebp
+	movl	%ebp, 24(%rdi)
+	movl	%r15d, 28(%rdi)
+	movb	%r14b, 32(%rdi)
+	movups	(%rsi), %xmm0
+	movaps	%xmm0, (%rsp)
+	movq	16(%rsi), %r12
+	movq	%r12, 16(%rsp)
+	cmpq	(%rsp), %r12
+	jne	.L1
+.L2:
+	movq	%rsp, %rdi
+	callq	*alloc::raw_vec::RawVec<T,A>::grow_one@GOTPCREL(%rip)
+.L3:
+.L1:
+	movq	8(%rsp), %rax
+	movl	%r15d, (%rax,%r12,4)
+	incq	%r12
+	movq	%r12, 16(%rsp)
+	addl	%ebp, %r15d
+	movq	%r12, 16(%rbx)
+	movaps	(%rsp), %xmm0
+	movups	%xmm0, (%rbx)
+	movl	%r15d, 24(%rbx)
+	movl	%ebp, 28(%rbx)
+	movb	%r14b, 32(%rbx)
+	movq	%rbx, %rax
+	addq	$32, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.L4:
+.L5:
+	movq	%rax, %rbx
+	movq	(%rsp), %rsi
+	testq	%rsi, %rsi
+	je	.L6
+	movq	8(%rsp), %rdi
+	shlq	$2, %rsi
+	movl	$4, %edx
+	callq	*__rustc::__rust_dealloc@GOTPCREL(%rip)
+.L6:
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
@@ -0,0 +1,37 @@
+records_in_rust::heap::owned::update_record_with_refs:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%rbx
+	pushq	%rax
+	xorb	$1, 32(%rdi)
+	movl	24(%rdi), %ebp
+	incl	%ebp
+	movl	%ebp, 24(%rdi)
+	movl	28(%rdi), %r15d
+	movq	16(%rdi), %r14
+	movl	%r15d, %eax
+	movl	%ebp, %ecx
+	cmpq	(%rdi), %r14
+	je	.L0
+.L1:
+	addl	%ecx, %eax
+	movq	8(%rdi), %rcx
+	movl	%r15d, (%rcx,%r14,4)
+	incq	%r14
+	movq	%r14, 16(%rdi)
+	movl	%eax, 24(%rdi)
+	movl	%ebp, 28(%rdi)
+	addq	$8, %rsp
+	popq	%rbx
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.L0:
+	movq	%rdi, %rbx
+	callq	*alloc::raw_vec::RawVec<T,A>::grow_one@GOTPCREL(%rip)
+	movq	%rbx, %rdi
+	movl	24(%rbx), %ecx
+	movl	28(%rbx), %eax
+	jmp	.L1
@@ -0,0 +1,20 @@
+records_in_rust::heap::shared::update_record_from_ref:
+	movsd	8(%rsi), %xmm0
+	movzbl	16(%rsi), %eax
+	movq	(%rsi), %rcx
+	lock		incq	(%rcx)
+	jle	.L0
+	pushq	%rbx
+	subq	$32, %rsp
+	movq	%rdi, %rbx
+	movlps	%xmm0, 16(%rsp)
+	movb	%al, 24(%rsp)
+	movq	%rcx, 8(%rsp)
+	leaq	8(%rsp), %rsi
+	callq	*records_in_rust::heap::shared::update_record_no_refs@GOTPCREL(%rip)
+	movq	%rbx, %rax
+	addq	$32, %rsp
+	popq	%rbx
+	retq
+.L0:
+	ud2
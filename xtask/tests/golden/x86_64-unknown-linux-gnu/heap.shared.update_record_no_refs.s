@@ -0,0 +1,64 @@
+records_in_rust::heap::shared::update_record_no_refs:
+.L0:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r13
+	pushq	%r12
+	pushq	%rbx
+	pushq	%rax
+	movq	%rdi, %rbx
+	movl	8(%rsi), %ebp
+	movl	12(%rsi), %r12d
+	movzbl	16(%rsi), %r15d
+	movq	(%rsi), %rax
+	xorb	$1, %r15b
+	incl	%ebp
+	movl	%ebp, 8(%rdi)
+	movl	%r12d, 12(%rdi)
+	movb	%r15b, 16(%rdi)
+	movq	%rax, (%rdi)
+	movq	%rax, (%rsp)
+.L1:
+	movq	%rsp, %rdi
+	callq	alloc::sync::Arc<T,A>::make_mut
+.L2:
+	movq	%rax, %r14
+	movq	16(%rax), %r13
+	cmpq	(%rax), %r13
+	jne	.L3
+.L4:
+	movq	%r14, %rdi
+	callq	*alloc::raw_vec::RawVec<T,A>::grow_one@GOTPCREL(%rip)
+.L5:
+.L3:
+	movq	8(%r14), %rax
+	movl	%r12d, (%rax,%r13,4)
+	incq	%r13
+	movq	%r13, 16(%r14)
+	addl	%ebp, %r12d
+	movq	(%rsp), %rax
+	movq	%rax, (%rbx)
+	movl	%r12d, 8(%rbx)
+	movl	%ebp, 12(%rbx)
+	movb	%r15b, 16(%rbx)
+	movq	%rbx, %rax
+	addq	$8, %rsp
+	popq	%rbx
+	popq	%r12
+	popq	%r13
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.L6:
+.L7:
+	movq	%rax, %rbx
+	movq	(%rsp), %rax
+	lock		decq	(%rax)
+	jne	.L8
+	movq	%rsp, %rdi
+	callq	*alloc::sync::Arc<T,A>::drop_slow@GOTPCREL(%rip)
+.L8:
+	movq	%rbx, %rdi
+	callq	_Unwind_Resume@PLT
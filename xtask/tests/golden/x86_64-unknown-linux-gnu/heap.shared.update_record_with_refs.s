@@ -0,0 +1,35 @@
+records_in_rust::heap::shared::update_record_with_refs:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r12
+	pushq	%rbx
+	movq	%rdi, %rbx
+	xorb	$1, 16(%rdi)
+	movl	8(%rdi), %ebp
+	incl	%ebp
+	movl	%ebp, 8(%rdi)
+	callq	alloc::sync::Arc<T,A>::make_mut
+	movl	12(%rbx), %r14d
+	movq	16(%rax), %r15
+	cmpq	(%rax), %r15
+	je	.L0
+.L1:
+	movq	8(%rax), %rcx
+	movl	%r14d, (%rcx,%r15,4)
+	incq	%r15
+	movq	%r15, 16(%rax)
+	addl	%r14d, 8(%rbx)
+	movl	%ebp, 12(%rbx)
+	popq	%rbx
+	popq	%r12
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
+.L0:
+	movq	%rax, %rdi
+	movq	%rax, %r12
+	callq	*alloc::raw_vec::RawVec<T,A>::grow_one@GOTPCREL(%rip)
+	movq	%r12, %rax
+	jmp	.L1
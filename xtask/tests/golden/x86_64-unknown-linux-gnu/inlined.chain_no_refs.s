@@ -0,0 +1,25 @@
+records_in_rust::inlined::chain_no_refs:
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	movzbl	8(%rsi), %edx
+	movzbl	11(%rsi), %edi
+	leal	2(%rcx), %r8d
+	addl	4(%rsi), %r8d
+	movb	%dil, 11(%rax)
+	movzwl	9(%rsi), %esi
+	movw	%si, 9(%rax)
+	addl	%r8d, %ecx
+	addl	$2, %ecx
+	leal	1(%r8,%rcx), %esi
+	leal	1(%rcx,%rsi), %ecx
+	addl	%ecx, %esi
+	incl	%esi
+	addl	%esi, %ecx
+	incl	%ecx
+	addl	%ecx, %esi
+	incl	%esi
+	addl	%esi, %ecx
+	movl	%ecx, (%rax)
+	movl	%esi, 4(%rax)
+	movb	%dl, 8(%rax)
+	retq
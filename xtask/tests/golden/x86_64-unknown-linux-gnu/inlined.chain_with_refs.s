@@ -0,0 +1,26 @@
+records_in_rust::inlined::chain_with_refs:
+# same code as records_in_rust::inlined::chain_with_ptrs
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	leal	2(%rcx), %edx
+	addl	4(%rsi), %edx
+	movzbl	8(%rsi), %edi
+	addl	%edx, %ecx
+	addl	$2, %ecx
+	leal	1(%rdx,%rcx), %edx
+	leal	1(%rcx,%rdx), %ecx
+	addl	%ecx, %edx
+	incl	%edx
+	addl	%edx, %ecx
+	incl	%ecx
+	addl	%ecx, %edx
+	incl	%edx
+	addl	%edx, %ecx
+	movl	%ecx, (%rax)
+	movl	%edx, 4(%rax)
+	movb	%dil, 8(%rax)
+	movzwl	9(%rsi), %ecx
+	movw	%cx, 9(%rax)
+	movzbl	11(%rsi), %ecx
+	movb	%cl, 11(%rax)
+	retq
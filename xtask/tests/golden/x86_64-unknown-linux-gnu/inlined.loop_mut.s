@@ -0,0 +1,21 @@
+records_in_rust::inlined::loop_mut:
+# same code as records_in_rust::inlined::loop_mut_record_mut
+	movl	(%rdi), %eax
+	movl	4(%rdi), %ecx
+	leal	(%rax,%rcx), %edx
+	incl	%edx
+	leal	2(%rax,%rcx), %ecx
+	leal	3(%rax,%rdx), %eax
+	leal	1(%rax,%rcx), %ecx
+	leal	1(%rcx,%rax), %eax
+	addl	%eax, %ecx
+	incl	%ecx
+	addl	%ecx, %eax
+	incl	%eax
+	addl	%eax, %ecx
+	incl	%ecx
+	addl	%ecx, %eax
+	movl	%eax, (%rdi)
+	movl	%ecx, 4(%rdi)
+	andb	$1, 8(%rdi)
+	retq
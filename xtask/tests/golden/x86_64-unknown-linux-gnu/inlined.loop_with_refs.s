@@ -0,0 +1,19 @@
+records_in_rust::inlined::loop_with_refs:
+	movl	(%rdi), %eax
+	movl	4(%rdi), %ecx
+	leal	(%rcx,%rax), %edx
+	incl	%edx
+	leal	2(%rcx,%rax), %ecx
+	leal	3(%rax,%rdx), %eax
+	leal	1(%rcx,%rax), %ecx
+	leal	1(%rax,%rcx), %eax
+	addl	%eax, %ecx
+	incl	%ecx
+	addl	%ecx, %eax
+	incl	%eax
+	addl	%eax, %ecx
+	incl	%ecx
+	addl	%ecx, %eax
+	movl	%eax, (%rdi)
+	movl	%ecx, 4(%rdi)
+	retq
@@ -0,0 +1,14 @@
+records_in_rust::inlined::owner_mut:
+# same code as records_in_rust::inlined::owner_mut_record_mut
+	movl	32(%rdi), %eax
+	movzbl	40(%rdi), %ecx
+	notb	%cl
+	andb	$1, %cl
+	incl	%eax
+	movl	36(%rdi), %edx
+	addl	%eax, %edx
+	movl	%edx, 32(%rdi)
+	movl	%eax, 36(%rdi)
+	movb	%cl, 40(%rdi)
+	incq	24(%rdi)
+	retq
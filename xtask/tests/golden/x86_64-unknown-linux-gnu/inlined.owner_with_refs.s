@@ -0,0 +1,10 @@
+records_in_rust::inlined::owner_with_refs:
+	xorb	$1, 40(%rdi)
+	movl	32(%rdi), %eax
+	incl	%eax
+	movl	36(%rdi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, 32(%rdi)
+	movl	%eax, 36(%rdi)
+	incq	24(%rdi)
+	retq
@@ -0,0 +1,15 @@
+records_in_rust::layout::bits::update_record_no_refs:
+# same code as records_in_rust::layout::bits::update_record_mut
+	leaq	1(%rdi), %rcx
+	movl	%edi, %eax
+	andl	$-2147483648, %eax
+	shrq	$32, %rdi
+	leal	(%rdi,%rcx), %edx
+	andl	$2147483647, %edx
+	orq	%rax, %rdx
+	andl	$2147483647, %ecx
+	shlq	$32, %rcx
+	orq	%rdx, %rcx
+	movl	$2147483648, %eax
+	xorq	%rcx, %rax
+	retq
@@ -0,0 +1,17 @@
+records_in_rust::layout::bits::update_record_with_refs:
+# same code as records_in_rust::layout::bits::update_record_with_ptrs
+	movq	(%rdi), %rax
+	leaq	1(%rax), %rcx
+	movl	%eax, %edx
+	andl	$-2147483648, %edx
+	shrq	$32, %rax
+	addl	%ecx, %eax
+	andl	$2147483647, %eax
+	orq	%rdx, %rax
+	andl	$2147483647, %ecx
+	shlq	$32, %rcx
+	orq	%rax, %rcx
+	movl	$2147483648, %eax
+	xorq	%rcx, %rax
+	movq	%rax, (%rdi)
+	retq
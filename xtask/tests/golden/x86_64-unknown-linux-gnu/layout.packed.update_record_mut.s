@@ -0,0 +1,15 @@
+records_in_rust::layout::packed::update_record_mut:
+	movq	%rdi, %rax
+	movzbl	8(%rsi), %ecx
+	xorb	$1, %cl
+	movb	%cl, 8(%rsi)
+	movl	(%rsi), %edx
+	incl	%edx
+	movl	4(%rsi), %edi
+	addl	%edx, %edi
+	movl	%edi, (%rsi)
+	movl	%edx, 4(%rsi)
+	movb	%cl, 8(%rax)
+	movq	(%rsi), %rcx
+	movq	%rcx, (%rax)
+	retq
@@ -0,0 +1,9 @@
+records_in_rust::lens::update_record_with_lenses:
+	movl	(%rdi), %eax
+	incl	%eax
+	movl	4(%rdi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, (%rdi)
+	movl	%eax, 4(%rdi)
+	xorb	$1, 8(%rdi)
+	retq
@@ -0,0 +1,12 @@
+records_in_rust::lens::update_record_with_lenses_no_refs:
+	movq	%rdi, %rax
+	movzbl	8(%rsi), %ecx
+	movl	(%rsi), %edx
+	xorb	$1, %cl
+	incl	%edx
+	movl	4(%rsi), %esi
+	addl	%edx, %esi
+	movl	%esi, (%rdi)
+	movl	%edx, 4(%rdi)
+	movb	%cl, 8(%rdi)
+	retq
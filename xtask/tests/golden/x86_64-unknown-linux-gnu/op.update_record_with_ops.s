@@ -0,0 +1,16 @@
+records_in_rust::op::update_record_with_ops:
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	movzbl	8(%rsi), %edx
+	movzbl	11(%rsi), %edi
+	incl	%ecx
+	movl	4(%rsi), %r8d
+	addl	%ecx, %r8d
+	movb	%dil, 11(%rax)
+	movzwl	9(%rsi), %esi
+	movw	%si, 9(%rax)
+	xorb	$1, %dl
+	movl	%r8d, (%rax)
+	movl	%ecx, 4(%rax)
+	movb	%dl, 8(%rax)
+	retq
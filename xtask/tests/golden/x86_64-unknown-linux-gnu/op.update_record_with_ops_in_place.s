@@ -0,0 +1,9 @@
+records_in_rust::op::update_record_with_ops_in_place:
+	xorb	$1, 8(%rdi)
+	movl	(%rdi), %eax
+	incl	%eax
+	movl	4(%rdi), %ecx
+	addl	%eax, %ecx
+	movl	%ecx, (%rdi)
+	movl	%eax, 4(%rdi)
+	retq
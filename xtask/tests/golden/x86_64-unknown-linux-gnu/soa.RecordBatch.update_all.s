@@ -0,0 +1,162 @@
+records_in_rust::soa::RecordBatch::update_all:
+	movq	64(%rdi), %rax
+	testq	%rax, %rax
+	je	.L0
+	movq	56(%rdi), %rsi
+	leaq	(%rsi,%rax,8), %rdx
+	shlq	$3, %rax
+	addq	$-8, %rax
+	cmpq	$24, %rax
+	jae	.L1
+	movq	%rsi, %rcx
+	jmp	.L2
+.L0:
+	movq	16(%rdi), %rax
+	jmp	.L3
+.L1:
+	shrq	$3, %rax
+	incq	%rax
+	movq	%rax, %r8
+	andq	$-4, %r8
+	leaq	(%rsi,%r8,8), %rcx
+	xorl	%r9d, %r9d
+	pcmpeqd	%xmm0, %xmm0
+.L4:
+	movdqu	(%rsi,%r9,8), %xmm1
+	movdqu	16(%rsi,%r9,8), %xmm2
+	pxor	%xmm0, %xmm1
+	pxor	%xmm0, %xmm2
+	movdqu	%xmm1, (%rsi,%r9,8)
+	movdqu	%xmm2, 16(%rsi,%r9,8)
+	addq	$4, %r9
+	cmpq	%r9, %r8
+	jne	.L4
+	cmpq	%r8, %rax
+	je	.L5
+.L2:
+	notq	(%rcx)
+	addq	$8, %rcx
+	cmpq	%rdx, %rcx
+	jne	.L2
+.L5:
+	movq	16(%rdi), %rax
+	movq	%rax, %rcx
+	andq	$63, %rcx
+	je	.L3
+	movq	$-1, %rsi
+	shlq	%cl, %rsi
+	notq	%rsi
+	andq	%rsi, -8(%rdx)
+	movq	8(%rdi), %rcx
+	jmp	.L6
+.L3:
+	movq	8(%rdi), %rcx
+	testq	%rax, %rax
+	je	.L7
+.L6:
+	leaq	(%rcx,%rax,4), %rdx
+	movq	%rdx, %r8
+	subq	%rcx, %r8
+	addq	$-4, %r8
+	movq	%rcx, %rsi
+	cmpq	$28, %r8
+	jb	.L8
+	shrq	$2, %r8
+	incq	%r8
+	movq	%r8, %r9
+	andq	$-8, %r9
+	leaq	(%rcx,%r9,4), %rsi
+	xorl	%r10d, %r10d
+	pcmpeqd	%xmm0, %xmm0
+.L9:
+	movdqu	(%rcx,%r10,4), %xmm1
+	movdqu	16(%rcx,%r10,4), %xmm2
+	psubd	%xmm0, %xmm1
+	psubd	%xmm0, %xmm2
+	movdqu	%xmm1, (%rcx,%r10,4)
+	movdqu	%xmm2, 16(%rcx,%r10,4)
+	addq	$8, %r10
+	cmpq	%r10, %r9
+	jne	.L9
+	cmpq	%r9, %r8
+	je	.L10
+.L8:
+	incl	(%rsi)
+	addq	$4, %rsi
+	cmpq	%rdx, %rsi
+	jne	.L8
+	jmp	.L10
+.L7:
+	xorl	%eax, %eax
+.L10:
+	movq	40(%rdi), %rdx
+	cmpq	%rax, %rdx
+	cmovbq	%rdx, %rax
+	testq	%rax, %rax
+	je	.L11
+	movq	32(%rdi), %rdx
+	cmpq	$8, %rax
+	jb	.L12
+	leaq	(%rcx,%rax,4), %rsi
+	leaq	(%rdx,%rax,4), %rdi
+	cmpq	%rdi, %rcx
+	setb	%dil
+	cmpq	%rsi, %rdx
+	setb	%sil
+	testb	%sil, %dil
+	je	.L13
+.L12:
+	xorl	%esi, %esi
+.L14:
+	movq	%rsi, %rdi
+	orq	$1, %rdi
+	testb	$1, %al
+	je	.L15
+	movl	(%rcx,%rsi,4), %r8d
+	movl	(%rdx,%rsi,4), %r9d
+	addl	%r8d, %r9d
+	movl	%r9d, (%rcx,%rsi,4)
+	movl	%r8d, (%rdx,%rsi,4)
+	movq	%rdi, %rsi
+.L15:
+	cmpq	%rdi, %rax
+	je	.L11
+.L16:
+	movl	(%rcx,%rsi,4), %edi
+	movl	(%rdx,%rsi,4), %r8d
+	addl	%edi, %r8d
+	movl	%r8d, (%rcx,%rsi,4)
+	movl	%edi, (%rdx,%rsi,4)
+	movl	4(%rcx,%rsi,4), %edi
+	movl	4(%rdx,%rsi,4), %r8d
+	addl	%edi, %r8d
+	movl	%r8d, 4(%rcx,%rsi,4)
+	movl	%edi, 4(%rdx,%rsi,4)
+	addq	$2, %rsi
+	cmpq	%rsi, %rax
+	jne	.L16
+	jmp	.L11
+.L13:
+	movq	%rax, %rsi
+	andq	$-8, %rsi
+	leaq	(,%rax,4), %rdi
+	andq	$-32, %rdi
+	xorl	%r8d, %r8d
+.L17:
+	movdqu	(%rcx,%r8), %xmm0
+	movdqu	16(%rcx,%r8), %xmm1
+	movdqu	(%rdx,%r8), %xmm2
+	movdqu	16(%rdx,%r8), %xmm3
+	paddd	%xmm0, %xmm2
+	paddd	%xmm1, %xmm3
+	movdqu	%xmm2, (%rcx,%r8)
+	movdqu	%xmm3, 16(%rcx,%r8)
+	movdqu	%xmm0, (%rdx,%r8)
+	movdqu	%xmm1, 16(%rdx,%r8)
+	addq	$32, %r8
+	cmpq	%r8, %rdi
+	jne	.L17
+	cmpq	%rsi, %rax
+	jne	.L14
+.L11:
+	retq
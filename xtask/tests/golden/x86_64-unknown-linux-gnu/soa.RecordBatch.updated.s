@@ -0,0 +1,171 @@
+records_in_rust::soa::RecordBatch::updated:
+	pushq	%rbp
+	pushq	%r15
+	pushq	%r14
+	pushq	%r12
+	pushq	%rbx
+	movq	%rdi, %rax
+	movq	56(%rsi), %rdx
+	movq	64(%rsi), %rdi
+	testq	%rdi, %rdi
+	je	.L0
+	cmpq	$4, %rdi
+	jae	.L1
+	xorl	%ecx, %ecx
+	jmp	.L2
+.L1:
+	movabsq	$1152921504606846972, %rcx
+	andq	%rdi, %rcx
+	leaq	(,%rdi,8), %r8
+	andq	$-32, %r8
+	xorl	%r9d, %r9d
+	pcmpeqd	%xmm0, %xmm0
+.L3:
+	movdqu	(%rdx,%r9), %xmm1
+	movdqu	16(%rdx,%r9), %xmm2
+	pxor	%xmm0, %xmm1
+	pxor	%xmm0, %xmm2
+	movdqu	%xmm1, (%rdx,%r9)
+	movdqu	%xmm2, 16(%rdx,%r9)
+	addq	$32, %r9
+	cmpq	%r9, %r8
+	jne	.L3
+	jmp	.L4
+.L2:
+	notq	(%rdx,%rcx,8)
+	incq	%rcx
+.L4:
+	cmpq	%rcx, %rdi
+	jne	.L2
+.L0:
+	movq	16(%rsi), %r8
+	movabsq	$2305843009213693944, %r11
+	movq	8(%rsi), %r9
+	movq	40(%rsi), %r10
+	testq	%rdi, %rdi
+	je	.L5
+	movl	%r8d, %ecx
+	andl	$63, %ecx
+	je	.L5
+	movq	$-1, %rbx
+	shlq	%cl, %rbx
+	notq	%rbx
+	andq	%rbx, -8(%rdx,%rdi,8)
+	jmp	.L6
+.L5:
+	testq	%r8, %r8
+	je	.L7
+.L6:
+	cmpq	$8, %r8
+	jae	.L8
+	xorl	%ecx, %ecx
+	jmp	.L9
+.L8:
+	movq	%r8, %rcx
+	andq	%r11, %rcx
+	leaq	(,%r8,4), %rbx
+	andq	$-32, %rbx
+	xorl	%r14d, %r14d
+	pcmpeqd	%xmm0, %xmm0
+.L10:
+	movdqu	(%r9,%r14), %xmm1
+	movdqu	16(%r9,%r14), %xmm2
+	psubd	%xmm0, %xmm1
+	psubd	%xmm0, %xmm2
+	movdqu	%xmm1, (%r9,%r14)
+	movdqu	%xmm2, 16(%r9,%r14)
+	addq	$32, %r14
+	cmpq	%r14, %rbx
+	jne	.L10
+	jmp	.L11
+.L9:
+	incl	(%r9,%rcx,4)
+	incq	%rcx
+.L11:
+	cmpq	%rcx, %r8
+	jne	.L9
+.L7:
+	movq	48(%rsi), %rcx
+	movq	(%rsi), %rbx
+	movq	24(%rsi), %r14
+	movq	32(%rsi), %rsi
+	cmpq	%r10, %r8
+	cmovbq	%r8, %r10
+	testq	%r10, %r10
+	je	.L12
+	cmpq	$8, %r10
+	jb	.L13
+	leaq	(%rsi,%r10,4), %r15
+	leaq	(%r9,%r10,4), %r12
+	cmpq	%r12, %rsi
+	setb	%bpl
+	cmpq	%r15, %r9
+	setb	%r15b
+	testb	%r15b, %bpl
+	je	.L14
+.L13:
+	xorl	%r11d, %r11d
+.L15:
+	movq	%r10, %r12
+	movq	%r11, %r15
+	andq	$3, %r12
+	je	.L16
+	movq	%r11, %r15
+.L17:
+	movl	(%r9,%r15,4), %ebp
+	addl	%ebp, (%rsi,%r15,4)
+	incq	%r15
+	decq	%r12
+	jne	.L17
+.L16:
+	subq	%r10, %r11
+	cmpq	$-4, %r11
+	ja	.L12
+.L18:
+	movl	(%r9,%r15,4), %r11d
+	addl	%r11d, (%rsi,%r15,4)
+	movl	4(%r9,%r15,4), %r11d
+	addl	%r11d, 4(%rsi,%r15,4)
+	movl	8(%r9,%r15,4), %r11d
+	addl	%r11d, 8(%rsi,%r15,4)
+	movl	12(%r9,%r15,4), %r11d
+	addl	%r11d, 12(%rsi,%r15,4)
+	addq	$4, %r15
+	cmpq	%r15, %r10
+	jne	.L18
+	jmp	.L12
+.L14:
+	andq	%r10, %r11
+	leaq	(,%r10,4), %r15
+	andq	$-32, %r15
+	xorl	%r12d, %r12d
+.L19:
+	movdqu	(%rsi,%r12), %xmm0
+	movdqu	16(%rsi,%r12), %xmm1
+	movdqu	(%r9,%r12), %xmm2
+	paddd	%xmm0, %xmm2
+	movdqu	16(%r9,%r12), %xmm0
+	paddd	%xmm1, %xmm0
+	movdqu	%xmm2, (%rsi,%r12)
+	movdqu	%xmm0, 16(%rsi,%r12)
+	addq	$32, %r12
+	cmpq	%r12, %r15
+	jne	.L19
+	cmpq	%r11, %r10
+	jne	.L15
+.L12:
+	movq	%r14, (%rax)
+	movq	%rsi, 8(%rax)
+	movq	%r10, 16(%rax)
+	movq	%rbx, 24(%rax)
+	movq	%r9, 32(%rax)
+	movq	%r8, 40(%rax)
+	movq	%rcx, 48(%rax)
+	movq	%rdx, 56(%rax)
+	movq	%rdi, 64(%rax)
+	popq	%rbx
+	popq	%r12
+	popq	%r14
+	popq	%r15
+	popq	%rbp
+	retq
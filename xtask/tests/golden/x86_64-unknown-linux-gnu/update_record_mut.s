@@ -0,0 +1,14 @@
+records_in_rust::update_record_mut:
+	xorb	$1, 8(%rsi)
+	movq	%rdi, %rax
+	movl	(%rsi), %ecx
+	incl	%ecx
+	movl	4(%rsi), %edx
+	addl	%ecx, %edx
+	movl	%edx, (%rsi)
+	movl	%ecx, 4(%rsi)
+	movl	8(%rsi), %ecx
+	movl	%ecx, 8(%rdi)
+	movq	(%rsi), %rcx
+	movq	%rcx, (%rdi)
+	retq
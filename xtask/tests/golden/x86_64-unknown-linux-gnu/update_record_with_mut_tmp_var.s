@@ -0,0 +1,12 @@
+records_in_rust::update_record_with_mut_tmp_var:
+	movl	(%rdi), %eax
+	movzbl	8(%rdi), %ecx
+	notb	%cl
+	andb	$1, %cl
+	incl	%eax
+	movl	4(%rdi), %edx
+	addl	%eax, %edx
+	movl	%edx, (%rdi)
+	movl	%eax, 4(%rdi)
+	movb	%cl, 8(%rdi)
+	retq
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use records_asm::{Build, Verdict};
+use records_in_rust::inlined::FUNCTIONS;
+
+#[test]
+fn inlined_strategies_update_loops_and_fields_in_place() {
+    let build = Build::new(Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap());
+    let listing = build.ir().unwrap();
+    let analyze = |name: &str| {
+        let path = format!("{}::{name}", build.crate_name());
+        listing.function(&path).unwrap().analyze()
+    };
+    for name in FUNCTIONS {
+        let analysis = analyze(name);
+        if name.starts_with("inlined::chain_") {
+            let baseline = analyze("inlined::chain_with_refs");
+            assert_eq!(analysis.verdict, baseline.verdict, "{name}");
+            assert_eq!(analysis.memcpy, baseline.memcpy, "{name}");
+        } else {
+            assert_eq!(analysis.verdict, Verdict::InPlace, "{name}");
+            assert!(!analysis.memcpy, "{name} copies");
+        }
+    }
+}
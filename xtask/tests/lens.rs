@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use records_asm::{Arch, Build, Diff, Line, Similarity};
+
+// each lens-based strategy and the hand-written strategy it mirrors
+const PAIRS: &[(&str, &str)] = &[
+    ("lens::update_record_with_lenses", "update_record_with_ptrs"),
+    (
+        "lens::update_record_with_lenses_no_refs",
+        "update_record_no_refs",
+    ),
+];
+
+fn build() -> Build {
+    Build::new(Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap())
+}
+
+#[test]
+fn lenses_write_memory_like_struct_update_syntax() {
+    let build = build();
+    let listing = build.ir().unwrap();
+    let analyze = |name: &str| {
+        let path = format!("{}::{name}", build.crate_name());
+        listing.function(&path).unwrap().analyze()
+    };
+    for (lens, plain) in PAIRS {
+        let (lens, plain) = (analyze(lens), analyze(plain));
+        assert_eq!(lens.verdict, plain.verdict, "{}", lens.name);
+        assert_eq!(lens.memcpy, plain.memcpy, "{}", lens.name);
+        assert!(
+            lens.calls.is_empty(),
+            "{} calls {:?}",
+            lens.name,
+            lens.calls
+        );
+    }
+}
+
+// How close each pair's assembly is, per target: the first pair, then the
+// second. Going through a lens loses the compiler's knowledge that `c` is 0
+// or 1, so outside x86_64 the toggle is a `not` and a mask (`mov` and `bic`
+// on arm64) instead of one `xor`; wasm32 is compared line by line, so its
+// pairs differ whenever the stack code is ordered differently.
+const EXPECTED: &[(&str, [Similarity; 2])] = &[
+    (
+        "x86_64-unknown-linux-gnu",
+        [Similarity::Identical, Similarity::Reordered],
+    ),
+    (
+        "aarch64-unknown-linux-gnu",
+        [Similarity::Different, Similarity::Renamed],
+    ),
+    (
+        "aarch64-apple-darwin",
+        [Similarity::Different, Similarity::Reordered],
+    ),
+    (
+        "riscv64gc-unknown-linux-gnu",
+        [Similarity::Different, Similarity::Identical],
+    ),
+    (
+        "wasm32-unknown-unknown",
+        [Similarity::Different, Similarity::Different],
+    ),
+];
+
+#[test]
+fn lenses_cost_an_instruction_outside_x86_64() {
+    let names: Vec<_> = PAIRS
+        .iter()
+        .flat_map(|(lens, plain)| [*lens, *plain])
+        .collect();
+    for &(target, expected) in EXPECTED {
+        let build = build().target(target);
+        if !build.target_installed().unwrap() {
+            eprintln!("skipping {target}: not installed");
+            continue;
+        }
+        let arch = Arch::from_triple(target);
+        let report = build.report(names.iter().copied()).unwrap();
+        for (pair, expected) in report.functions.chunks(2).zip(expected) {
+            let (lens, plain) = (&pair[0], &pair[1]);
+            let diff = Diff::new(arch, &lens.function, &plain.function);
+            assert_eq!(diff.similarity, expected, "{target}\n{diff}");
+            assert!(
+                lens.instructions.len() <= plain.instructions.len() + 2,
+                "{target}\n{diff}"
+            );
+            if arch == Arch::Aarch64 && diff.similarity == Similarity::Different {
+                let only = |side: fn(&Line) -> Option<&String>| {
+                    diff.lines
+                        .iter()
+                        .filter_map(side)
+                        .map(|line| opcode(line))
+                        .collect::<Vec<_>>()
+                };
+                let left = only(|line| match line {
+                    Line::Left(line) => Some(line),
+                    _ => None,
+                });
+                let right = only(|line| match line {
+                    Line::Right(line) => Some(line),
+                    _ => None,
+                });
+                assert_eq!(left, ["mov", "bic", "strb"], "{target}\n{diff}");
+                assert_eq!(right, ["eor", "strb"], "{target}\n{diff}");
+            }
+        }
+    }
+}
+
+fn opcode(instruction: &str) -> &str {
+    instruction.split_whitespace().next().unwrap_or_default()
+}
@@ -0,0 +1,72 @@
+use std::process::Command;
+
+use serde_json::Value;
+
+fn matrix(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_xtask"))
+        .arg("matrix")
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// `update_record_with_refs` is the article's in-place strategy and
+// `update_record_no_refs` its by-value one; once optimized, neither copies
+#[test]
+fn every_opt_level_has_a_default_and_a_single_codegen_unit_column() {
+    let names = ["update_record_with_refs", "update_record_no_refs"];
+    let json: Value = serde_json::from_str(&matrix(&[&["--json"], &names[..]].concat())).unwrap();
+    let columns = json.as_array().unwrap();
+    let profiles: Vec<(&str, bool)> = columns
+        .iter()
+        .map(|column| {
+            let profile = &column["profile"];
+            (
+                profile["opt_level"].as_str().unwrap(),
+                profile["single_codegen_unit"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+    let expected: Vec<(&str, bool)> = ["0", "1", "2", "3", "s", "z"]
+        .into_iter()
+        .flat_map(|opt_level| [(opt_level, false), (opt_level, true)])
+        .collect();
+    assert_eq!(profiles, expected);
+
+    for column in columns {
+        let profile = &column["profile"];
+        let cells = column["cells"].as_array().unwrap();
+        let cell_names: Vec<_> = cells.iter().map(|cell| cell["name"].as_str()).collect();
+        assert_eq!(cell_names, names.map(Some), "{profile}");
+        if profile["opt_level"] == "0" {
+            continue;
+        }
+        let verdicts: Vec<_> = cells
+            .iter()
+            .map(|cell| cell["analysis"]["verdict"].as_str())
+            .collect();
+        assert_eq!(
+            verdicts,
+            [Some("in-place"), Some("second-struct")],
+            "{profile}"
+        );
+        for cell in cells {
+            assert_eq!(cell["analysis"]["memcpy"], false, "{profile}");
+            assert!(cell["instructions"].as_u64().unwrap() > 0, "{profile}");
+        }
+    }
+
+    let table = matrix(&names);
+    let header: Vec<_> = table.lines().next().unwrap().split_whitespace().collect();
+    let expected: Vec<_> = ["O0", "O1", "O2", "O3", "Os", "Oz"]
+        .into_iter()
+        .flat_map(|level| [level.to_string(), format!("{level}+cgu1")])
+        .collect();
+    assert_eq!(header, expected);
+}